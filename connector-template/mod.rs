@@ -417,6 +417,65 @@ impl
     }
 }
 
+impl api::Payouts for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutCreate for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutFulfill for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutCancel for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutReverse for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for {{project-name | downcase | pascal_case}} {}
+impl api::AcceptDispute for {{project-name | downcase | pascal_case}} {}
+impl api::SubmitEvidence for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for {{project-name | downcase | pascal_case}} {}
+impl api::UploadFile for {{project-name | downcase | pascal_case}} {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for {{project-name | downcase | pascal_case}} {
     fn get_webhook_object_reference_id(
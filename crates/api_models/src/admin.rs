@@ -176,6 +176,141 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    Rules(RoutingRules),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+    SuccessRate(SuccessRateRouting),
+}
+
+/// Route payments to the connector with the best authorization success rate over a recent
+/// window. Success rates are tracked separately for each payment method and currency.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuccessRateRouting {
+    /// The connectors to choose from, in decreasing order of preference for when there are not
+    /// enough recent payments to compute their success rates
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// The percentage of payments routed to a random connector instead of the best performing
+    /// one, so that the success rates of all the connectors stay current
+    #[serde(default = "default_exploration_percentage")]
+    pub exploration_percentage: u8,
+    /// The length of the window over which success rates are computed, in minutes
+    #[serde(default = "default_success_rate_window_in_mins")]
+    pub window_in_mins: u16,
+    /// The minimum number of payments a connector must have processed within the window for its
+    /// success rate to be used
+    #[serde(default = "default_success_rate_min_volume")]
+    pub min_volume: u32,
+}
+
+fn default_exploration_percentage() -> u8 {
+    10
+}
+
+fn default_success_rate_window_in_mins() -> u16 {
+    60
+}
+
+fn default_success_rate_min_volume() -> u32 {
+    20
+}
+
+/// The percentage of payments to be routed to a connector. The splits of a volume split routing
+/// algorithm must add up to 100.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    pub split: u8,
+}
+
+/// Ordered routing rules evaluated against each payment. The first rule whose conditions match
+/// the payment decides the connector(s), falling back to `default` if no rule matches.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    pub rules: Vec<RoutingRule>,
+    pub default: RoutingRuleOutput,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// A name for the rule, used to identify the rule in logs and validation errors
+    pub name: String,
+    /// All the conditions must match for the rule to be selected. A rule without any conditions
+    /// matches every payment.
+    #[serde(default)]
+    pub conditions: RoutingConditions,
+    pub output: RoutingRuleOutput,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingRuleOutput {
+    Single(api_enums::RoutableConnectors),
+    /// Connectors in decreasing order of preference
+    Priority(Vec<api_enums::RoutableConnectors>),
+}
+
+impl RoutingRuleOutput {
+    pub fn connectors(&self) -> Vec<api_enums::RoutableConnectors> {
+        match self {
+            Self::Single(connector) => vec![*connector],
+            Self::Priority(connectors) => connectors.clone(),
+        }
+    }
+}
+
+/// Conditions of a routing rule. Conditions which are not provided are not checked, and each
+/// list condition matches if the payment matches any of the values in the list.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConditions {
+    pub currency: Option<Vec<api_enums::Currency>>,
+    pub amount: Option<AmountRange>,
+    pub card_network: Option<Vec<api_enums::CardNetwork>>,
+    /// Prefixes of the card number, between 1 and 8 digits long
+    pub card_bin: Option<Vec<String>>,
+    /// Two-letter ISO country codes, matched against the country of the billing address
+    pub billing_country: Option<Vec<String>>,
+    pub payment_method: Option<Vec<api_enums::PaymentMethodType>>,
+    /// Key-value pairs which must all be present in the metadata of the payment
+    pub metadata: Option<std::collections::HashMap<String, serde_json::Value>>,
+}
+
+/// Policy for retrying payments which failed with a retryable error, with the next connector
+/// chosen by the routing algorithm of the merchant
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentRetryPolicy {
+    /// Maximum number of times a payment is retried with another connector
+    pub max_retries: u8,
+    /// Connector error codes which are retried in addition to the errors classified as
+    /// retryable by the connectors
+    #[serde(default)]
+    pub retryable_error_codes:
+        std::collections::HashMap<api_enums::RoutableConnectors, Vec<String>>,
+}
+
+/// Policy for payments authorized with manual capture which the merchant does not capture. Such
+/// payments are captured a fixed number of hours after they are authorized, or voided shortly
+/// before their authorization expires with the connector, whichever happens first.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentCapturePolicy {
+    /// Number of hours after the authorization of a payment at which it is captured
+    pub capture_after_hours: Option<u32>,
+    /// Number of hours before the authorization of a payment expires at which it is voided, so
+    /// that the funds held on the payment method of the customer are released
+    pub void_before_expiry_hours: Option<u32>,
+}
+
+/// An amount range in the lowest denomination of the currency, with both the bounds inclusive
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AmountRange {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -197,6 +332,11 @@ pub struct WebhookDetails {
     #[schema(value_type = Option<String>, example = "www.ekart.com/webhooks")]
     pub webhook_url: Option<Secret<String>>,
 
+    /// The secret used to sign outgoing webhooks with HMAC-SHA512. If not set, the payment
+    /// response hash key of the merchant is used.
+    #[schema(value_type = Option<String>, max_length = 255, example = "whsec_6f1fa3e8b2")]
+    pub webhook_secret: Option<Secret<String>>,
+
     /// If this property is true, a webhook message is posted whenever a new payment is created
     #[schema(example = true)]
     pub payment_created_enabled: Option<bool>,
@@ -208,6 +348,40 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a payment is being processed
+    #[schema(example = true)]
+    pub payment_processing_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a payment is cancelled
+    #[schema(example = true)]
+    pub payment_cancelled_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a payment requires an action
+    /// from the customer
+    #[schema(example = true)]
+    pub action_required_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a refund is successful
+    #[schema(example = true)]
+    pub refund_succeeded_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a refund fails
+    #[schema(example = true)]
+    pub refund_failed_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a mandate becomes active
+    #[schema(example = true)]
+    pub mandate_active_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever a mandate is revoked
+    #[schema(example = true)]
+    pub mandate_revoked_enabled: Option<bool>,
+
+    /// If this property is true, a webhook message is posted whenever the status of a dispute
+    /// changes
+    #[schema(example = true)]
+    pub dispute_status_changed_enabled: Option<bool>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
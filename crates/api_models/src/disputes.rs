@@ -1 +1,120 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount
+    pub amount: String,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// Stage of the dispute
+    #[schema(value_type = DisputeStage)]
+    pub dispute_stage: api_enums::DisputeStage,
+    /// Status of the dispute
+    #[schema(value_type = DisputeStatus)]
+    pub dispute_status: api_enums::DisputeStatus,
+    /// The connector through which the dispute was raised
+    pub connector: String,
+    /// Status of the dispute sent by connector
+    pub connector_status: String,
+    /// Dispute id sent by connector
+    pub connector_dispute_id: String,
+    /// Reason of dispute sent by connector
+    pub connector_reason: Option<String>,
+    /// Reason code of dispute sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Evidence deadline of dispute sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Dispute created time sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    /// Dispute updated time sent by connector
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<PrimitiveDateTime>,
+    /// Time at which dispute is received
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub received_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeListConstraints {
+    /// Limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+    /// Status of the dispute
+    #[schema(value_type = Option<DisputeStatus>)]
+    pub dispute_status: Option<api_enums::DisputeStatus>,
+    /// Stage of the dispute
+    #[schema(value_type = Option<DisputeStage>)]
+    pub dispute_stage: Option<api_enums::DisputeStage>,
+    /// The connector through which the dispute was raised
+    pub connector: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeListResponse {
+    /// The number of disputes included in the list
+    pub size: usize,
+    /// The list of disputes
+    pub data: Vec<DisputeResponse>,
+}
+
+#[derive(Default, Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceRequest {
+    /// A description of the product or service that was sold
+    pub product_description: Option<String>,
+    /// The email address of the customer
+    pub customer_email_address: Option<String>,
+    /// The name of the customer
+    pub customer_name: Option<String>,
+    /// The IP address the customer used when making the purchase
+    pub customer_purchase_ip: Option<String>,
+    /// The address to which the physical product was shipped
+    pub shipping_address: Option<String>,
+    /// The delivery service that shipped the physical product
+    pub shipping_carrier: Option<String>,
+    /// The date on which the physical product began its route to the shipping address
+    pub shipping_date: Option<String>,
+    /// The tracking number for the physical product
+    pub shipping_tracking_number: Option<String>,
+    /// The date on which the customer received or began receiving the purchased service
+    pub service_date: Option<String>,
+    /// An explanation of how and when the customer was shown the refund policy
+    pub refund_policy_disclosure: Option<String>,
+    /// A justification for why the customer is not entitled to a refund
+    pub refund_refusal_explanation: Option<String>,
+    /// An explanation of how and when the customer was shown the cancellation policy
+    pub cancellation_policy_disclosure: Option<String>,
+    /// A justification for why the customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// Any additional evidence or statements
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DisputePayload {
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: api_enums::DisputeStage,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
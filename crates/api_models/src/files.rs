@@ -1 +1,7 @@
+use utoipa::ToSchema;
 
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CreateFileResponse {
+    /// ID of the file created
+    pub file_id: String,
+}
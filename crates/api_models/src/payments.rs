@@ -75,6 +75,11 @@ pub struct PaymentsRequest {
     /// Set to true to indicate that the customer is not in your checkout flow during this payment, and therefore is unable to authenticate. This parameter is intended for scenarios where you collect card details and charge them later. This parameter can only be used with `confirm: true`.
     #[schema(example = true)]
     pub off_session: Option<bool>,
+    /// Set to true to authorize the payment so that its authorized amount can be increased later
+    /// on with incremental authorizations, where the connector supports it. Only applies to
+    /// payments captured manually.
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
     /// A description of the payment
     #[schema(example = "It's my first payment request")]
     pub description: Option<String>,
@@ -87,6 +92,10 @@ pub struct PaymentsRequest {
     /// The transaction authentication can be set to undergo payer authentication.
     #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds", default = "three_ds")]
     pub authentication_type: Option<api_enums::AuthenticationType>,
+    /// The result of a 3DS authentication of the customer performed outside of the router, such
+    /// as by a 3DS server of the merchant. It is forwarded to the connector when the payment is
+    /// confirmed along with this request.
+    pub external_authentication_details: Option<AuthenticationDetails>,
     /// The payment method information provided for making a payment
     #[schema(example = "bank_transfer")]
     pub payment_method_data: Option<PaymentMethod>,
@@ -531,14 +540,19 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureRequest {
     /// The unique identifier for the payment
     pub payment_id: Option<String>,
     /// The unique identifier for the merchant
     pub merchant_id: Option<String>,
-    /// The Amount to be captured/ debited from the user's payment method.
+    /// The Amount to be captured/ debited from the user's payment method. Defaults to the amount
+    /// left to capture from the payment.
     pub amount_to_capture: Option<i64>,
+    /// Whether no more captures will be made from the payment after this one, in which case the
+    /// amount left uncaptured is released. A capture of the whole amount left is always final.
+    #[schema(default = true)]
+    pub final_capture: Option<bool>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
     /// Provides information about a card payment that customers see on their statements.
@@ -547,6 +561,19 @@ pub struct PaymentsCaptureRequest {
     pub statement_descriptor_prefix: Option<String>,
 }
 
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The unique identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The amount to be added to the amount authorized for the payment
+    #[schema(example = 1000)]
+    pub amount: i64,
+    /// The reason for increasing the amount authorized
+    #[schema(max_length = 255, example = "Minibar charges")]
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -562,7 +589,6 @@ pub enum NextActionType {
     RedirectToUrl,
     DisplayQrCode,
     InvokeSdkClient,
-    TriggerApi,
 }
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct NextAction {
@@ -572,6 +598,121 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// Contains the QR code to be displayed to the customer for the display QR code flow
+    pub qr_code_information: Option<QrCodeInformation>,
+    /// Contains the parameters to invoke the SDK of the wallet with for the invoke SDK client flow
+    pub sdk_client_information: Option<SdkClientInformation>,
+}
+
+/// A QR code which the customer scans with their banking or wallet app to complete the payment
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct QrCodeInformation {
+    /// The image of the QR code as a URL or a data URL, which can be used directly as the source of
+    /// an image
+    #[schema(example = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAA")]
+    pub image_data_url: Option<String>,
+    /// The string encoded in the QR code, for customers paying on the device the QR code is
+    /// displayed on, such as a PIX copy and paste code or a UPI payment link
+    #[schema(example = "upi://pay?pa=merchant@bank&pn=Merchant&am=65.40&cu=INR")]
+    pub qr_code_data: Option<String>,
+    /// The time after which the QR code can no longer be used to pay, in ISO 8601 format
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+/// The parameters to invoke the native SDK of a wallet with, so that the customer completes the
+/// payment in the wallet
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SdkClientInformation {
+    /// The name of the SDK to be invoked
+    #[schema(example = "google_pay")]
+    pub sdk_name: String,
+    /// The parameters the SDK is to be invoked with, as provided by the connector
+    #[schema(value_type = Object, example = r#"{ "transaction_id": "txn_5ngq3lQ8" }"#)]
+    pub sdk_parameters: serde_json::Value,
+}
+
+/// The action the customer has to take to complete the payment, other than being redirected,
+/// as returned by the connector
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NextActionData {
+    DisplayQrCode(QrCodeInformation),
+    InvokeSdkClient(SdkClientInformation),
+}
+
+impl From<NextActionData> for NextAction {
+    fn from(next_action_data: NextActionData) -> Self {
+        match next_action_data {
+            NextActionData::DisplayQrCode(qr_code_information) => Self {
+                next_action_type: NextActionType::DisplayQrCode,
+                redirect_to_url: None,
+                qr_code_information: Some(qr_code_information),
+                sdk_client_information: None,
+            },
+            NextActionData::InvokeSdkClient(sdk_client_information) => Self {
+                next_action_type: NextActionType::InvokeSdkClient,
+                redirect_to_url: None,
+                qr_code_information: None,
+                sdk_client_information: Some(sdk_client_information),
+            },
+        }
+    }
+}
+
+/// The result of the 3DS authentication of the customer
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuthenticationDetails {
+    /// The version of the 3DS protocol the customer was authenticated with
+    #[schema(example = "2.2.0")]
+    pub version: Option<String>,
+    /// The Electronic Commerce Indicator, which indicates the outcome of the authentication to
+    /// the issuer
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+    /// The cryptogram generated by the issuer for the authentication, the Cardholder
+    /// Authentication Verification Value (CAVV) or the Accountholder Authentication Value (AAV)
+    #[schema(value_type = Option<String>, example = "AAABBEg0VhI0VniQEjRWAAAAAAA=")]
+    pub cavv: Option<Secret<String>>,
+    /// The identifier of the authentication assigned by the 3DS directory server
+    #[schema(example = "f25084f0-5b16-4c0a-ae5d-b24808a95e4b")]
+    pub ds_transaction_id: Option<String>,
+    /// Whether the liability for fraudulent chargebacks has shifted from the merchant to the
+    /// issuer as a result of the authentication
+    #[schema(example = true)]
+    pub liability_shift: Option<bool>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct CaptureResponse {
+    /// The identifier for the capture
+    #[schema(max_length = 64, example = "cap_fjd8fm3mfn390f9mfn3k")]
+    pub capture_id: String,
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+    /// The amount captured, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    #[schema(example = "USD")]
+    pub currency: String,
+    /// The connector the capture was made with
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The reference of the capture with the connector
+    pub connector_capture_id: Option<String>,
+    /// Whether the amount left uncaptured was released by this capture
+    pub final_capture: bool,
+    /// The error message, if the capture failed
+    pub error_message: Option<String>,
+    /// The error code, if the capture failed
+    pub error_code: Option<String>,
+    /// The timestamp at which the capture was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -622,6 +763,8 @@ pub struct PaymentsResponse {
     /// List of refund that happened on this intent
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
+    /// List of captures made from the payment
+    pub captures: Option<Vec<CaptureResponse>>,
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -674,6 +817,8 @@ pub struct PaymentsResponse {
     /// The transaction authentication can be set to undergo payer authentication. By default, the authentication will be marked as NO_THREE_DS
     #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds", default = "three_ds")]
     pub authentication_type: Option<api_enums::AuthenticationType>,
+    /// The result of the 3DS authentication of the customer, if the customer was authenticated
+    pub authentication_details: Option<AuthenticationDetails>,
     /// For non-card charges, you can use this value as the complete description that appears on your customers’ statements. Must contain at least one letter, maximum 22 characters.
     #[schema(max_length = 255, example = "Juspay Router")]
     pub statement_descriptor_name: Option<String>,
@@ -932,6 +1077,15 @@ impl From<PaymentsCaptureRequest> for PaymentsResponse {
     }
 }
 
+impl From<PaymentsIncrementalAuthorizationRequest> for PaymentsResponse {
+    fn from(item: PaymentsIncrementalAuthorizationRequest) -> Self {
+        Self {
+            payment_id: Some(item.payment_id),
+            ..Self::default()
+        }
+    }
+}
+
 impl From<Card> for CardResponse {
     fn from(card: Card) -> Self {
         let card_number_length = card.card_number.peek().clone().len();
@@ -965,7 +1119,7 @@ pub struct PgRedirectResponse {
     pub amount: Option<i64>,
 }
 
-#[derive(Debug, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq, serde::Deserialize)]
 pub struct RedirectionResponse {
     pub return_url: String,
     pub params: Vec<(String, String)>,
@@ -1351,4 +1505,29 @@ mod tests {
             r#"{"multi_use":null}"#
         )
     }
+
+    #[test]
+    fn test_next_action_data() {
+        let next_action_data = NextActionData::InvokeSdkClient(SdkClientInformation {
+            sdk_name: "google_pay".to_string(),
+            sdk_parameters: serde_json::json!({ "transaction_id": "txn_5ngq3lQ8" }),
+        });
+        let serialized = serde_json::to_string(&next_action_data).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"type":"invoke_sdk_client","sdk_name":"google_pay","sdk_parameters":{"transaction_id":"txn_5ngq3lQ8"}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<NextActionData>(&serialized).unwrap(),
+            next_action_data
+        );
+
+        let next_action = NextAction::from(next_action_data);
+        assert_eq!(
+            next_action.next_action_type,
+            NextActionType::InvokeSdkClient
+        );
+        assert!(next_action.redirect_to_url.is_none());
+        assert!(next_action.sdk_client_information.is_some());
+    }
 }
@@ -1 +1,216 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. If not provided, it is generated and returned in the API response. It is recommended to generate uuid(v4) as the payout_id.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payout_id: Option<String>,
+
+    /// The payout amount in the lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(minimum = 1, example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The connector to be used for the payout. If not provided, the routing algorithm of the merchant account is used.
+    #[schema(value_type = Option<Connector>, example = "adyen")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The identifier for the customer object who receives the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The email of the customer who receives the payout
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// Whether the payout is to be confirmed immediately. If false, the payout must be confirmed with `/payouts/{payout_id}/confirm`.
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+
+    /// The details of the instrument the payout is made to. Required if `confirm` is true.
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Its my first payout request")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// The payout amount in the lowest denomination of the currency
+    #[schema(minimum = 1, example = 1000)]
+    pub amount: Option<i64>,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Its my first payout request")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutConfirmRequest {
+    /// The email of the customer who receives the payout
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// The details of the instrument the payout is made to
+    pub payout_method_data: PayoutMethodData,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(CardPayout),
+    Bank(BankPayout),
+    ConnectedAccount(ConnectedAccountPayout),
+}
+
+impl PayoutMethodData {
+    pub fn get_payout_type(&self) -> api_enums::PayoutType {
+        match self {
+            Self::Card(_) => api_enums::PayoutType::Card,
+            Self::Bank(_) => api_enums::PayoutType::Bank,
+            Self::ConnectedAccount(_) => api_enums::PayoutType::ConnectedAccount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CardPayout {
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: Secret<String, pii::CardNumber>,
+    /// The card's expiry month
+    #[schema(value_type = String, example = "03")]
+    pub card_exp_month: Secret<String>,
+    /// The card's expiry year
+    #[schema(value_type = String, example = "2030")]
+    pub card_exp_year: Secret<String>,
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BankPayout {
+    /// The International Bank Account Number of the beneficiary
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+    /// The name of the owner of the bank account
+    #[schema(value_type = String, example = "John Test")]
+    pub bank_account_holder_name: Secret<String>,
+    /// The two-letter ISO country code of the bank
+    #[schema(example = "NL")]
+    pub bank_country_code: String,
+    /// The name of the bank
+    #[schema(example = "Deutsche Bank")]
+    pub bank_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ConnectedAccountPayout {
+    /// The identifier of the seller's account connected to the merchant's account at the connector
+    #[schema(example = "acct_1032D82eZvKYlo2C")]
+    pub account_id: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    pub payout_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+    /// The payout amount in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// The connector used for the payout
+    pub connector: String,
+    /// The identifier for the payout at the connector
+    pub connector_payout_id: Option<String>,
+    /// The identifier for the customer object who receives the payout
+    pub customer_id: Option<String>,
+    /// The type of the instrument the payout is made to
+    #[schema(value_type = Option<PayoutType>)]
+    pub payout_type: Option<api_enums::PayoutType>,
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus)]
+    pub status: api_enums::PayoutStatus,
+    /// A description of the payout
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The error code returned by the connector
+    pub error_code: Option<String>,
+    /// The error message returned by the connector
+    pub error_message: Option<String>,
+    /// The timestamp at which the payout was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The timestamp at which the payout was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutListConstraints {
+    /// Limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub size: usize,
+    /// The list of payouts
+    pub data: Vec<PayoutResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAccountsResponse {
+    /// The connector accounts of the merchant which payouts can be made with
+    pub accounts: Vec<PayoutAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAccount {
+    /// The connector of the account
+    #[schema(example = "adyen")]
+    pub connector: String,
+    /// The identifier for the merchant connector account
+    pub merchant_connector_id: String,
+    /// Whether the account is a test account at the connector
+    pub test_mode: Option<bool>,
+}
@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 use crate::enums;
 
-#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundRequest {
     /// Unique Identifier for the Refund. This is to ensure idempotency for multiple partial refund initiated against the same payment. If the identifiers is not defined by the merchant, this filed shall be auto generated and provide in the API response. It is recommended to generate uuid(v4) as the refund_id.
@@ -45,7 +45,7 @@ pub struct RefundRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundUpdateRequest {
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
@@ -57,7 +57,7 @@ pub struct RefundUpdateRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Default, Debug, Clone, ToSchema, Deserialize)]
+#[derive(Default, Debug, Clone, ToSchema, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RefundType {
     #[default]
@@ -2,19 +2,30 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as api_enums, payments};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    IncrementalAuthorizationSuccess,
+    IncrementalAuthorizationFailure,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
     Subscription,
+    Dispute,
+    IncrementalAuthorization,
 }
 
 impl From<IncomingWebhookEvent> for WebhookFlow {
@@ -22,6 +33,15 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::DisputeOpened => Self::Dispute,
+            IncomingWebhookEvent::DisputeExpired => Self::Dispute,
+            IncomingWebhookEvent::DisputeAccepted => Self::Dispute,
+            IncomingWebhookEvent::DisputeCancelled => Self::Dispute,
+            IncomingWebhookEvent::DisputeChallenged => Self::Dispute,
+            IncomingWebhookEvent::DisputeWon => Self::Dispute,
+            IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::IncrementalAuthorizationSuccess => Self::IncrementalAuthorization,
+            IncomingWebhookEvent::IncrementalAuthorizationFailure => Self::IncrementalAuthorization,
         }
     }
 }
@@ -47,4 +67,7 @@ pub struct OutgoingWebhook {
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
+    RefundDetails(refunds::RefundResponse),
+    DisputeDetails(disputes::DisputeResponse),
+    MandateDetails(mandates::MandateResponse),
 }
@@ -0,0 +1,119 @@
+//! Utilities for signing outgoing webhooks and verifying their signatures
+//!
+//! Every outgoing webhook carries the UNIX timestamp at which it was sent, and an HMAC-SHA512
+//! signature of `"{timestamp}.{payload}"` encoded as hex. Including the timestamp in the signed
+//! message lets receivers reject replayed webhooks.
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    crypto::{HmacSha512, SignMessage, VerifySignature},
+    errors::{self, CustomResult},
+};
+
+/// Header containing the hex encoded signature of the webhook
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-webhook-signature-512";
+
+/// Header containing the UNIX timestamp at which the webhook was sent
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+
+/// Recommended maximum age of a webhook, in seconds, beyond which it should be rejected
+pub const DEFAULT_WEBHOOK_TOLERANCE_IN_SECS: i64 = 300;
+
+fn get_signed_message(timestamp: i64, payload: &[u8]) -> Vec<u8> {
+    let mut message = format!("{timestamp}.").into_bytes();
+    message.extend_from_slice(payload);
+    message
+}
+
+/// Sign the webhook payload sent at `timestamp`, returning the hex encoded signature
+pub fn sign_webhook(
+    secret: &[u8],
+    timestamp: i64,
+    payload: &[u8],
+) -> CustomResult<String, errors::CryptoError> {
+    HmacSha512
+        .sign_message(secret, &get_signed_message(timestamp, payload))
+        .map(hex::encode)
+}
+
+/// Verify the signature of a received webhook using the values of its signature and timestamp
+/// headers. Webhooks sent more than `tolerance_in_secs` seconds ago are rejected, to prevent
+/// replays of intercepted webhooks.
+pub fn verify_webhook(
+    secret: &[u8],
+    payload: &[u8],
+    signature_header: &str,
+    timestamp_header: &str,
+    tolerance_in_secs: i64,
+) -> CustomResult<bool, errors::CryptoError> {
+    let timestamp = timestamp_header
+        .trim()
+        .parse::<i64>()
+        .into_report()
+        .change_context(errors::CryptoError::SignatureVerificationFailed)
+        .attach_printable("Webhook timestamp is not a valid UNIX timestamp")?;
+
+    let age_in_secs = crate::date_time::now_unix_timestamp().saturating_sub(timestamp);
+    if age_in_secs.saturating_abs() > tolerance_in_secs {
+        return Ok(false);
+    }
+
+    let signature = hex::decode(signature_header.trim())
+        .into_report()
+        .change_context(errors::CryptoError::SignatureVerificationFailed)
+        .attach_printable("Webhook signature is not hex encoded")?;
+
+    HmacSha512.verify_signature(secret, &signature, &get_signed_message(timestamp, payload))
+}
+
+#[cfg(test)]
+mod webhooks_tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_verify_webhook() {
+        let secret = "webhook_secret_1234".as_bytes();
+        let payload = r#"{"event_type":"payment_succeeded"}"#.as_bytes();
+        let timestamp = crate::date_time::now_unix_timestamp();
+        let signature = sign_webhook(secret, timestamp, payload).expect("Webhook signing");
+
+        assert!(verify_webhook(
+            secret,
+            payload,
+            &signature,
+            &timestamp.to_string(),
+            DEFAULT_WEBHOOK_TOLERANCE_IN_SECS,
+        )
+        .expect("Webhook verification"));
+
+        let tampered_payload = r#"{"event_type":"payment_failed"}"#.as_bytes();
+        assert!(!verify_webhook(
+            secret,
+            tampered_payload,
+            &signature,
+            &timestamp.to_string(),
+            DEFAULT_WEBHOOK_TOLERANCE_IN_SECS,
+        )
+        .expect("Webhook verification"));
+    }
+
+    #[test]
+    fn test_verify_webhook_rejects_stale_timestamp() {
+        let secret = "webhook_secret_1234".as_bytes();
+        let payload = r#"{"event_type":"payment_succeeded"}"#.as_bytes();
+        let timestamp =
+            crate::date_time::now_unix_timestamp() - DEFAULT_WEBHOOK_TOLERANCE_IN_SECS - 1;
+        let signature = sign_webhook(secret, timestamp, payload).expect("Webhook signing");
+
+        assert!(!verify_webhook(
+            secret,
+            payload,
+            &signature,
+            &timestamp.to_string(),
+            DEFAULT_WEBHOOK_TOLERANCE_IN_SECS,
+        )
+        .expect("Webhook verification"));
+    }
+}
@@ -0,0 +1,78 @@
+use drainer::{consistency, errors, errors::DrainerResult, services, settings};
+use error_stack::{IntoReport, ResultExt};
+
+/// Compare the payments stored in the Redis KV store with their rows in the database
+#[derive(clap::Parser)]
+#[command(version = router_env::version!())]
+struct ConsistencyCheckerCmd {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<std::path::PathBuf>,
+
+    /// The merchant whose payments are checked
+    #[arg(short, long)]
+    merchant_id: String,
+
+    /// The payments to check. Payments are sampled from the KV store if none are specified.
+    #[arg(short, long = "payment-id")]
+    payment_ids: Vec<String>,
+
+    /// The number of payments to sample from the KV store
+    #[arg(short, long, default_value_t = 100)]
+    sample_size: usize,
+}
+
+#[tokio::main]
+async fn main() -> DrainerResult<()> {
+    let cmd = <ConsistencyCheckerCmd as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = settings::Settings::with_config_path(cmd.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate drainer configuration");
+
+    let store = std::sync::Arc::new(services::Store::new(&conf, false).await);
+
+    let result = check(&store, &cmd.merchant_id, cmd.payment_ids, cmd.sample_size).await;
+
+    store.close().await;
+    result
+}
+
+async fn check(
+    store: &services::Store,
+    merchant_id: &str,
+    payment_ids: Vec<String>,
+    sample_size: usize,
+) -> DrainerResult<()> {
+    let payment_ids = if payment_ids.is_empty() {
+        consistency::sample_payment_ids(store, merchant_id, sample_size).await?
+    } else {
+        payment_ids
+    };
+
+    let mut inconsistent_payments = 0;
+    for payment_id in &payment_ids {
+        let inconsistencies = consistency::check_payment(store, merchant_id, payment_id).await?;
+        if !inconsistencies.is_empty() {
+            inconsistent_payments += 1;
+        }
+        for inconsistency in inconsistencies {
+            let inconsistency = serde_json::to_string(&inconsistency)
+                .into_report()
+                .change_context(errors::DrainerError::UnexpectedError(
+                    "Failed to serialize inconsistency".to_string(),
+                ))?;
+            println!("{inconsistency}");
+        }
+    }
+
+    eprintln!(
+        "Checked {} payments, {inconsistent_payments} inconsistent",
+        payment_ids.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use drainer::{dead_letter, errors, errors::DrainerResult, services, settings};
+use error_stack::{IntoReport, ResultExt};
+
+/// Inspect, replay or discard the dead letters of the drainer streams
+#[derive(clap::Parser)]
+#[command(version = router_env::version!())]
+struct DeadLettersCmd {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<std::path::PathBuf>,
+
+    /// The shard of the drainer stream whose dead letters are managed
+    #[arg(short, long)]
+    shard: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// List the oldest dead letters of the shard
+    List {
+        #[arg(short, long, default_value_t = 10)]
+        count: u64,
+    },
+    /// Apply the database operations of the dead letters, removing the ones that succeed
+    Replay { entry_ids: Vec<String> },
+    /// Remove the dead letters without applying their database operations
+    Discard { entry_ids: Vec<String> },
+}
+
+#[tokio::main]
+async fn main() -> DrainerResult<()> {
+    let cmd = <DeadLettersCmd as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = settings::Settings::with_config_path(cmd.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate drainer configuration");
+
+    let store = std::sync::Arc::new(services::Store::new(&conf, false).await);
+
+    let result = match cmd.command {
+        Command::List { count } => list(&store, cmd.shard, count).await,
+        Command::Replay { entry_ids } => {
+            for entry_id in entry_ids {
+                match dead_letter::replay_dead_letter(&store, cmd.shard, &entry_id).await {
+                    Ok(()) => println!("Replayed {entry_id}"),
+                    Err(error) => eprintln!("Failed to replay {entry_id}: {error:?}"),
+                }
+            }
+            Ok(())
+        }
+        Command::Discard { entry_ids } => {
+            for entry_id in entry_ids {
+                match dead_letter::discard_dead_letter(&store, cmd.shard, &entry_id).await {
+                    Ok(()) => println!("Discarded {entry_id}"),
+                    Err(error) => eprintln!("Failed to discard {entry_id}: {error:?}"),
+                }
+            }
+            Ok(())
+        }
+    };
+
+    store.close().await;
+    result
+}
+
+async fn list(store: &services::Store, shard: u8, count: u64) -> DrainerResult<()> {
+    for (entry_id, dead_letter) in dead_letter::read_dead_letters(store, shard, count).await? {
+        let dead_letter = serde_json::to_string(&dead_letter)
+            .into_report()
+            .change_context(errors::DrainerError::UnexpectedError(
+                "Failed to serialize dead letter".to_string(),
+            ))?;
+        println!("{entry_id} {dead_letter}");
+    }
+    Ok(())
+}
@@ -0,0 +1,253 @@
+//! Compares the payments stored in the Redis KV store with their rows in the database, to detect
+//! stream entries which were lost or applied incorrectly by the drainer.
+
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::errors::RedisError;
+use serde::Serialize;
+use storage_models::{
+    errors::DatabaseError, payment_attempt::PaymentAttempt, payment_intent::PaymentIntent,
+    refund::Refund,
+};
+
+use crate::{
+    connection::pg_connection,
+    errors::{self, DrainerError},
+    services::Store,
+};
+
+/// Fields which are expected to differ between the KV store and the database: the database assigns
+/// its own ID and timestamps when the row is inserted
+const IGNORED_FIELDS: [&str; 3] = ["id", "created_at", "modified_at"];
+
+/// The number of keys scanned per payment to be sampled, as the KV store also holds customers,
+/// mandates and addresses under keys of the same pattern
+const SCAN_KEYS_PER_SAMPLE: usize = 10;
+
+#[derive(Debug, Serialize)]
+pub struct Inconsistency {
+    pub payment_id: String,
+    pub table: &'static str,
+    /// The ID of the row within the payment
+    pub row_id: String,
+    pub kind: InconsistencyKind,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum InconsistencyKind {
+    /// The row was not drained to the database yet, or its insert was lost
+    MissingInDatabase,
+    /// The row was drained, but some of its updates were not applied
+    FieldsDiffer { fields: Vec<String> },
+}
+
+/// Sample up to `sample_size` payments of the merchant stored in the KV store
+pub async fn sample_payment_ids(
+    store: &Store,
+    merchant_id: &str,
+    sample_size: usize,
+) -> errors::DrainerResult<Vec<String>> {
+    let key_prefix = format!("{merchant_id}_");
+    let keys = store
+        .redis_conn
+        .scan(
+            &format!("{key_prefix}*"),
+            None,
+            sample_size.saturating_mul(SCAN_KEYS_PER_SAMPLE),
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    let mut payment_ids = Vec::new();
+    for key in keys {
+        if payment_ids.len() >= sample_size {
+            break;
+        }
+        let payment_intent = store
+            .redis_conn
+            .get_hash_field::<Option<String>>(&key, "pi")
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+        if let (Some(_), Some(payment_id)) = (payment_intent, key.strip_prefix(&key_prefix)) {
+            payment_ids.push(payment_id.to_string());
+        }
+    }
+
+    Ok(payment_ids)
+}
+
+/// Compare the payment intent, payment attempts and refunds of the payment stored in the KV store
+/// with their rows in the database
+pub async fn check_payment(
+    store: &Store,
+    merchant_id: &str,
+    payment_id: &str,
+) -> errors::DrainerResult<Vec<Inconsistency>> {
+    let key = format!("{merchant_id}_{payment_id}");
+    let conn = pg_connection(&store.master_pool).await;
+    let mut inconsistencies = Vec::new();
+
+    let payment_intent = match store
+        .redis_conn
+        .get_hash_field_and_deserialize::<PaymentIntent>(&key, "pi", "PaymentIntent")
+        .await
+    {
+        Ok(payment_intent) => Some(payment_intent),
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => None,
+        Err(error) => return Err(DrainerError::from(error)).into_report(),
+    };
+    if let Some(payment_intent) = payment_intent {
+        let db_payment_intent =
+            PaymentIntent::find_optional_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .change_context(DrainerError::DatabaseOperationFailed)?;
+        inconsistencies.extend(compare_rows(
+            payment_id,
+            "payment_intent",
+            payment_id,
+            &payment_intent,
+            db_payment_intent.as_ref(),
+        )?);
+    }
+
+    let payment_attempts = store
+        .redis_conn
+        .hscan_and_deserialize::<PaymentAttempt>(&key, "pa_*", None)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    for payment_attempt in payment_attempts {
+        let db_payment_attempt = optional_row(
+            PaymentAttempt::find_by_merchant_id_attempt_id(
+                &conn,
+                merchant_id,
+                &payment_attempt.attempt_id,
+            )
+            .await,
+        )?;
+        inconsistencies.extend(compare_rows(
+            payment_id,
+            "payment_attempt",
+            &payment_attempt.attempt_id,
+            &payment_attempt,
+            db_payment_attempt.as_ref(),
+        )?);
+    }
+
+    let refunds = store
+        .redis_conn
+        .hscan_and_deserialize::<Refund>(&key, "pa_*_ref_*", None)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    for refund in refunds {
+        let db_refund = optional_row(
+            Refund::find_by_merchant_id_refund_id(&conn, merchant_id, &refund.refund_id).await,
+        )?;
+        inconsistencies.extend(compare_rows(
+            payment_id,
+            "refund",
+            &refund.refund_id,
+            &refund,
+            db_refund.as_ref(),
+        )?);
+    }
+
+    Ok(inconsistencies)
+}
+
+fn optional_row<T>(result: storage_models::StorageResult<T>) -> errors::DrainerResult<Option<T>> {
+    match result {
+        Ok(row) => Ok(Some(row)),
+        Err(error) if matches!(error.current_context(), DatabaseError::NotFound) => Ok(None),
+        Err(error) => Err(error.change_context(DrainerError::DatabaseOperationFailed)),
+    }
+}
+
+fn compare_rows<T: Serialize>(
+    payment_id: &str,
+    table: &'static str,
+    row_id: &str,
+    kv_row: &T,
+    db_row: Option<&T>,
+) -> errors::DrainerResult<Option<Inconsistency>> {
+    let db_row = match db_row {
+        Some(db_row) => db_row,
+        None => {
+            return Ok(Some(Inconsistency {
+                payment_id: payment_id.to_string(),
+                table,
+                row_id: row_id.to_string(),
+                kind: InconsistencyKind::MissingInDatabase,
+            }))
+        }
+    };
+
+    let fields = get_differing_fields(&to_json_object(kv_row)?, &to_json_object(db_row)?);
+
+    Ok((!fields.is_empty()).then(|| Inconsistency {
+        payment_id: payment_id.to_string(),
+        table,
+        row_id: row_id.to_string(),
+        kind: InconsistencyKind::FieldsDiffer { fields },
+    }))
+}
+
+/// The fields of `row` whose values differ in `db_row`, ignoring the fields assigned by the
+/// database
+pub(crate) fn get_differing_fields(
+    row: &serde_json::Map<String, serde_json::Value>,
+    db_row: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<String> {
+    row.iter()
+        .filter(|(field, value)| {
+            !IGNORED_FIELDS.contains(&field.as_str())
+                && !db_row
+                    .get(field.as_str())
+                    .map_or(false, |db_value| values_match(value, db_value))
+        })
+        .map(|(field, _)| field.clone())
+        .collect()
+}
+
+/// Timestamps are stored by the database with a precision of a microsecond, and match when they
+/// are equal at that precision
+fn values_match(value: &serde_json::Value, db_value: &serde_json::Value) -> bool {
+    if value == db_value {
+        return true;
+    }
+    match (parse_timestamp(value), parse_timestamp(db_value)) {
+        (Some(timestamp), Some(db_timestamp)) => {
+            (timestamp - db_timestamp).abs() < time::Duration::microseconds(1)
+        }
+        _ => false,
+    }
+}
+
+fn parse_timestamp(value: &serde_json::Value) -> Option<time::PrimitiveDateTime> {
+    let value = value.as_str()?;
+    serde_json::from_value(serde_json::Value::String(value.to_owned()))
+        .ok()
+        .or_else(|| {
+            time::OffsetDateTime::parse(
+                value,
+                &time::format_description::well_known::Iso8601::DEFAULT,
+            )
+            .ok()
+            .map(common_utils::date_time::convert_to_pdt)
+        })
+}
+
+pub(crate) fn to_json_object<T: Serialize>(
+    row: &T,
+) -> errors::DrainerResult<serde_json::Map<String, serde_json::Value>> {
+    match serde_json::to_value(row) {
+        Ok(serde_json::Value::Object(fields)) => Ok(fields),
+        _ => Err(DrainerError::UnexpectedError(
+            "Failed to serialize row for comparison".to_string(),
+        ))
+        .into_report(),
+    }
+}
@@ -0,0 +1,450 @@
+//! Entries of the drainer streams which could not be applied to the database are moved to a dead
+//! letter stream of the same shard, along with the error and the number of attempts made. Dead
+//! letters are retried automatically with an exponential backoff, up to the configured number of
+//! retries, after which they are left for an operator to replay or discard.
+//!
+//! Versioned entries of a row are applied in the order of their versions, so that a dead letter is
+//! never replayed over a newer state of its row: while an entry of a row is in the dead letter
+//! stream, the later entries of the row fail as out of order and are moved to the dead letter
+//! stream as well, where they wait for the earlier entry without using up their retries. Entries
+//! whose version was already applied are dropped when retried.
+
+use std::collections::HashMap;
+
+use error_stack::{AttachmentKind, FrameKind, IntoReport, ResultExt};
+use serde::Serialize;
+
+use crate::{
+    errors::{self, DrainerError},
+    execute_db_operation, logger, metrics,
+    services::Store,
+    skip_db_operation,
+};
+
+/// The ID to read a stream from its first entry
+const STREAM_START_ID: &str = "0-0";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadLetterEntry {
+    /// The serialized database operation of the original stream entry
+    pub typed_sql: String,
+    /// The ID of the entry in the drainer stream
+    pub original_entry_id: String,
+    /// The error of the last attempt to apply the database operation
+    pub error: String,
+    /// The number of failed attempts to apply the database operation
+    pub attempts: u8,
+    /// The UNIX timestamp after which the entry is retried automatically
+    pub next_retry_at: i64,
+}
+
+impl DeadLetterEntry {
+    pub(crate) fn new<C>(
+        typed_sql: String,
+        original_entry_id: String,
+        error: &error_stack::Report<C>,
+        retry_delay: u32,
+    ) -> Self {
+        Self {
+            typed_sql,
+            original_entry_id,
+            error: get_error_message(error),
+            attempts: 1,
+            next_retry_at: get_next_retry_at(retry_delay, 1),
+        }
+    }
+
+    fn to_field_value_pairs(&self) -> Vec<(&str, String)> {
+        vec![
+            ("typed_sql", self.typed_sql.clone()),
+            ("original_entry_id", self.original_entry_id.clone()),
+            ("error", self.error.clone()),
+            ("attempts", self.attempts.to_string()),
+            ("next_retry_at", self.next_retry_at.to_string()),
+        ]
+    }
+
+    fn from_fields(fields: &HashMap<String, String>) -> errors::DrainerResult<Self> {
+        let get_field = |field_name: &str| {
+            fields
+                .get(field_name)
+                .cloned()
+                .ok_or_else(|| {
+                    DrainerError::InvalidDeadLetterEntry(format!("missing {field_name}"))
+                })
+                .into_report()
+        };
+
+        Ok(Self {
+            typed_sql: get_field("typed_sql")?,
+            original_entry_id: get_field("original_entry_id")?,
+            error: get_field("error")?,
+            attempts: get_field("attempts")?
+                .parse()
+                .into_report()
+                .change_context(DrainerError::InvalidDeadLetterEntry(
+                    "invalid attempts".into(),
+                ))?,
+            next_retry_at: get_field("next_retry_at")?
+                .parse()
+                .into_report()
+                .change_context(DrainerError::InvalidDeadLetterEntry(
+                    "invalid next_retry_at".into(),
+                ))?,
+        })
+    }
+}
+
+/// Flatten the contexts and printable attachments of the error into a single line
+fn get_error_message<C>(error: &error_stack::Report<C>) -> String {
+    error
+        .frames()
+        .filter_map(|frame| match frame.kind() {
+            FrameKind::Context(context) => Some(context.to_string()),
+            FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+                Some(attachment.to_string())
+            }
+            FrameKind::Attachment(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
+/// The delay before a retry doubles with every failed attempt
+fn get_next_retry_at(retry_delay: u32, attempts: u8) -> i64 {
+    let backoff_multiplier = 2_i64
+        .checked_pow(u32::from(attempts.saturating_sub(1)))
+        .unwrap_or(i64::MAX);
+
+    common_utils::date_time::now_unix_timestamp()
+        .saturating_add(i64::from(retry_delay).saturating_mul(backoff_multiplier))
+}
+
+/// The scan continues after the last entry read, and wraps around to the start of the stream once
+/// fewer entries than requested are read
+fn get_next_cursor(entries: &[(String, HashMap<String, String>)], max_read_count: u64) -> String {
+    match entries.last() {
+        Some((entry_id, _))
+            if u64::try_from(entries.len()).unwrap_or(u64::MAX) >= max_read_count =>
+        {
+            entry_id.clone()
+        }
+        _ => STREAM_START_ID.to_string(),
+    }
+}
+
+fn is_out_of_order(error: &error_stack::Report<DrainerError>) -> bool {
+    matches!(
+        error.current_context(),
+        DrainerError::OutOfOrderEntry { .. } | DrainerError::EntryClaimedByOtherConsumer { .. }
+    )
+}
+
+fn get_shard_stream_name(store: &Store, shard_index: u8) -> String {
+    store.drainer_stream(format!("shard_{shard_index}").as_str())
+}
+
+pub(crate) async fn push_to_dead_letter_stream(
+    store: &Store,
+    stream_name: &str,
+    dead_letter: &DeadLetterEntry,
+) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .stream_append_entry(
+            &store.dead_letter_stream(stream_name),
+            &redis_interface::RedisEntryId::AutoGeneratedID,
+            dead_letter.to_field_value_pairs(),
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+}
+
+/// Retry the dead letters of the stream which are due for a retry. Every call scans the next
+/// `max_read_count` entries of the dead letter stream, wrapping around to its start once the end
+/// of the stream is reached, so that dead letters which have exhausted their retries do not
+/// prevent the remaining entries from being retried.
+pub(crate) async fn retry_dead_letters(
+    store: &Store,
+    stream_name: &str,
+    max_read_count: u64,
+) -> errors::DrainerResult<()> {
+    let dead_letter_stream = store.dead_letter_stream(stream_name);
+    let cursor_key = format!("{dead_letter_stream}_cursor");
+
+    let cursor = store
+        .redis_conn
+        .get_key::<Option<String>>(&cursor_key)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?
+        .unwrap_or_else(|| STREAM_START_ID.to_string());
+
+    let stream_read = store
+        .redis_conn
+        .stream_read_entries(
+            dead_letter_stream.as_str(),
+            cursor.as_str(),
+            Some(max_read_count),
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    let entries = stream_read
+        .get(&dead_letter_stream)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let now = common_utils::date_time::now_unix_timestamp();
+    for (entry_id, fields) in entries {
+        let dead_letter = match DeadLetterEntry::from_fields(fields) {
+            Ok(dead_letter) => dead_letter,
+            Err(error) => {
+                logger::error!(?error, %entry_id, "Skipping invalid dead letter entry");
+                continue;
+            }
+        };
+
+        if dead_letter.attempts > store.config.dead_letter_max_retries
+            || dead_letter.next_retry_at > now
+        {
+            continue;
+        }
+
+        match execute_db_operation(store, stream_name, &dead_letter.typed_sql).await {
+            Ok(()) => {
+                logger::info!(%entry_id, attempts = %dead_letter.attempts, "Dead letter retried successfully");
+                metrics::DEAD_LETTERS_RETRIED_SUCCESSFULLY.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+                );
+            }
+            Err(error) => {
+                logger::error!(?error, %entry_id, attempts = %dead_letter.attempts, "Dead letter retry failed");
+                // An entry waiting for an earlier entry of its row is not retried until the
+                // earlier entry is applied, and does not use up its retries meanwhile
+                let attempts = if is_out_of_order(&error) {
+                    dead_letter.attempts
+                } else {
+                    dead_letter.attempts.saturating_add(1)
+                };
+                let retried_dead_letter = DeadLetterEntry {
+                    error: get_error_message(&error),
+                    attempts,
+                    next_retry_at: get_next_retry_at(
+                        store.config.dead_letter_retry_delay,
+                        attempts,
+                    ),
+                    ..dead_letter
+                };
+                // The failed entry is re-appended with the updated details before removing it,
+                // so that it is not lost if the append fails
+                push_to_dead_letter_stream(store, stream_name, &retried_dead_letter).await?;
+            }
+        }
+
+        store
+            .redis_conn
+            .stream_delete_entries(&dead_letter_stream, entry_id.as_str())
+            .await
+            .map_err(DrainerError::from)
+            .into_report()?;
+    }
+
+    store
+        .redis_conn
+        .set_key(&cursor_key, get_next_cursor(entries, max_read_count))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+}
+
+/// Read up to `count` dead letters of the shard, starting from the oldest one
+pub async fn read_dead_letters(
+    store: &Store,
+    shard_index: u8,
+    count: u64,
+) -> errors::DrainerResult<Vec<(String, DeadLetterEntry)>> {
+    let dead_letter_stream = store.dead_letter_stream(&get_shard_stream_name(store, shard_index));
+
+    let entries = store
+        .redis_conn
+        .stream_read_range(dead_letter_stream.as_str(), "-", "+", Some(count))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    Ok(entries
+        .into_iter()
+        .filter_map(
+            |(entry_id, fields)| match DeadLetterEntry::from_fields(&fields) {
+                Ok(dead_letter) => Some((entry_id, dead_letter)),
+                Err(error) => {
+                    logger::error!(?error, %entry_id, "Skipping invalid dead letter entry");
+                    None
+                }
+            },
+        )
+        .collect())
+}
+
+/// Apply the database operation of the dead letter, and remove the dead letter if it succeeds
+pub async fn replay_dead_letter(
+    store: &Store,
+    shard_index: u8,
+    entry_id: &str,
+) -> errors::DrainerResult<()> {
+    let stream_name = get_shard_stream_name(store, shard_index);
+    let dead_letter_stream = store.dead_letter_stream(&stream_name);
+
+    let fields = store
+        .redis_conn
+        .stream_read_range(dead_letter_stream.as_str(), entry_id, entry_id, Some(1))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?
+        .into_iter()
+        .next()
+        .map(|(_, fields)| fields)
+        .ok_or_else(|| DrainerError::DeadLetterNotFound(entry_id.to_string()))
+        .into_report()?;
+    let dead_letter = DeadLetterEntry::from_fields(&fields)?;
+
+    execute_db_operation(store, &stream_name, &dead_letter.typed_sql).await?;
+
+    store
+        .redis_conn
+        .stream_delete_entries(&dead_letter_stream, entry_id)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    Ok(())
+}
+
+/// Remove the dead letter without applying its database operation. The version of the operation is
+/// marked applied, so that the later entries of its row are applied after it.
+pub async fn discard_dead_letter(
+    store: &Store,
+    shard_index: u8,
+    entry_id: &str,
+) -> errors::DrainerResult<()> {
+    let stream_name = get_shard_stream_name(store, shard_index);
+    let dead_letter_stream = store.dead_letter_stream(&stream_name);
+
+    let fields = store
+        .redis_conn
+        .stream_read_range(dead_letter_stream.as_str(), entry_id, entry_id, Some(1))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?
+        .into_iter()
+        .next()
+        .map(|(_, fields)| fields)
+        .ok_or_else(|| DrainerError::DeadLetterNotFound(entry_id.to_string()))
+        .into_report()?;
+    let dead_letter = DeadLetterEntry::from_fields(&fields)?;
+
+    skip_db_operation(store, &stream_name, &dead_letter.typed_sql).await?;
+
+    let deleted_count = store
+        .redis_conn
+        .stream_delete_entries(&dead_letter_stream, entry_id)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    common_utils::fp_utils::when(deleted_count == 0, || {
+        Err(DrainerError::DeadLetterNotFound(entry_id.to_string())).into_report()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn dead_letter_fields() -> HashMap<String, String> {
+        HashMap::from([
+            ("typed_sql".to_string(), "{}".to_string()),
+            ("original_entry_id".to_string(), "1-0".to_string()),
+            ("error".to_string(), "Failed".to_string()),
+            ("attempts".to_string(), "2".to_string()),
+            ("next_retry_at".to_string(), "1700000000".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_next_retry_at_doubles_the_delay_with_every_attempt() {
+        let now = common_utils::date_time::now_unix_timestamp();
+
+        let first_retry_delay = get_next_retry_at(5, 1) - now;
+        let third_retry_delay = get_next_retry_at(5, 3) - now;
+
+        // The clock may tick between the calls
+        assert!((5..=6).contains(&first_retry_delay));
+        assert!((20..=21).contains(&third_retry_delay));
+    }
+
+    #[test]
+    fn test_next_retry_at_saturates_for_large_attempts() {
+        assert_eq!(get_next_retry_at(5, u8::MAX), i64::MAX);
+    }
+
+    #[test]
+    fn test_dead_letter_entry_round_trips_through_fields() {
+        let dead_letter = DeadLetterEntry::from_fields(&dead_letter_fields()).unwrap();
+        assert_eq!(dead_letter.attempts, 2);
+        assert_eq!(dead_letter.next_retry_at, 1_700_000_000);
+
+        let fields = dead_letter
+            .to_field_value_pairs()
+            .into_iter()
+            .map(|(field, value)| (field.to_string(), value))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(fields, dead_letter_fields());
+    }
+
+    #[test]
+    fn test_dead_letter_entry_with_missing_field_is_invalid() {
+        let mut fields = dead_letter_fields();
+        fields.remove("original_entry_id");
+
+        let error = DeadLetterEntry::from_fields(&fields).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            DrainerError::InvalidDeadLetterEntry(message) if message == "missing original_entry_id"
+        ));
+    }
+
+    #[test]
+    fn test_dead_letter_entry_with_invalid_attempts_is_invalid() {
+        let mut fields = dead_letter_fields();
+        fields.insert("attempts".to_string(), "many".to_string());
+
+        let error = DeadLetterEntry::from_fields(&fields).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            DrainerError::InvalidDeadLetterEntry(message) if message == "invalid attempts"
+        ));
+    }
+
+    #[test]
+    fn test_cursor_continues_after_a_full_read() {
+        let entries = vec![
+            ("1-0".to_string(), dead_letter_fields()),
+            ("2-0".to_string(), dead_letter_fields()),
+        ];
+        assert_eq!(get_next_cursor(&entries, 2), "2-0");
+    }
+
+    #[test]
+    fn test_cursor_wraps_around_after_a_partial_read() {
+        let entries = vec![("1-0".to_string(), dead_letter_fields())];
+        assert_eq!(get_next_cursor(&entries, 2), STREAM_START_ID);
+        assert_eq!(get_next_cursor(&[], 2), STREAM_START_ID);
+    }
+}
@@ -15,6 +15,22 @@ pub enum DrainerError {
     SignalError(String),
     #[error("Unexpected error occurred: {0}")]
     UnexpectedError(String),
+    #[error("Failed to deserialize the database operation of the stream entry")]
+    EntryDeserializationFailed,
+    #[error("Failed to execute the database operation of the stream entry")]
+    DatabaseOperationFailed,
+    #[error(
+        "Entry version {version} cannot be applied while the last applied version is {applied_version}"
+    )]
+    OutOfOrderEntry { version: i64, applied_version: i64 },
+    #[error("Entry version {version} is being applied by another consumer")]
+    EntryClaimedByOtherConsumer { version: i64 },
+    #[error("Claim on entry version {version} was lost while the entry was applied")]
+    EntryClaimLost { version: i64 },
+    #[error("Dead letter entry is invalid: {0}")]
+    InvalidDeadLetterEntry(String),
+    #[error("Dead letter entry {0} not found")]
+    DeadLetterNotFound(String),
 }
 
 pub type DrainerResult<T> = error_stack::Result<T, DrainerError>;
@@ -0,0 +1,292 @@
+//! Tracks how far the database is lagging behind the KV store, and serves it over HTTP so that
+//! the drainer can be probed for readiness and alerted on.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, RwLock,
+};
+
+use actix_web::{web, HttpResponse};
+use error_stack::{IntoReport, ResultExt};
+use serde::Serialize;
+
+use crate::{
+    errors::{self, DrainerError},
+    logger, metrics,
+    services::Store,
+    settings::HealthSettings,
+    utils,
+};
+
+static APPLIED_ENTRIES: AtomicU64 = AtomicU64::new(0);
+static FAILED_ENTRIES: AtomicU64 = AtomicU64::new(0);
+
+/// Count a stream entry towards the apply error rate
+pub(crate) fn record_entry_result(applied: bool) {
+    let counter = if applied {
+        &APPLIED_ENTRIES
+    } else {
+        &FAILED_ENTRIES
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StreamLag {
+    pub stream: String,
+    /// Number of entries waiting to be drained
+    pub backlog: usize,
+    /// Age of the oldest entry waiting to be drained, in seconds
+    pub oldest_entry_age: i64,
+    /// Number of entries which could not be applied to the database
+    pub dead_letters: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LagReport {
+    pub streams: Vec<StreamLag>,
+    /// Ratio of stream entries which could not be applied since the previous check
+    pub apply_error_rate: f64,
+    /// UNIX timestamp of the check
+    pub checked_at: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct HealthResponse<'a> {
+    healthy: bool,
+    reasons: Vec<String>,
+    #[serde(flatten)]
+    lag: &'a LagReport,
+}
+
+pub struct DrainerHealth {
+    settings: HealthSettings,
+    report: RwLock<Option<LagReport>>,
+}
+
+impl DrainerHealth {
+    pub fn new(settings: HealthSettings) -> Self {
+        Self {
+            settings,
+            report: RwLock::new(None),
+        }
+    }
+
+    /// The latest lag report, if the lag was checked at least once
+    pub fn report(&self) -> Option<LagReport> {
+        self.report
+            .read()
+            .map(|report| report.clone())
+            .unwrap_or_default()
+    }
+
+    fn set_report(&self, report: LagReport) {
+        match self.report.write() {
+            Ok(mut latest_report) => *latest_report = Some(report),
+            Err(error) => logger::error!(%error, "Failed to update the drainer lag report"),
+        }
+    }
+
+    /// The thresholds exceeded by the report. A report which was not refreshed for several check
+    /// intervals is considered unhealthy as well, since the lag can no longer be checked.
+    fn unhealthy_reasons(&self, report: &LagReport) -> Vec<String> {
+        let settings = &self.settings;
+        let mut reasons = Vec::new();
+
+        let report_age = common_utils::date_time::now_unix_timestamp() - report.checked_at;
+        let max_report_age =
+            i64::try_from(settings.check_interval.saturating_mul(3)).unwrap_or(i64::MAX);
+        if report_age > max_report_age {
+            reasons.push(format!("lag was last checked {report_age} seconds ago"));
+        }
+
+        for stream_lag in &report.streams {
+            if stream_lag.backlog > settings.max_backlog {
+                reasons.push(format!(
+                    "backlog of {} is {}, exceeding {}",
+                    stream_lag.stream, stream_lag.backlog, settings.max_backlog
+                ));
+            }
+            if stream_lag.oldest_entry_age > settings.max_oldest_entry_age {
+                reasons.push(format!(
+                    "oldest entry of {} is {} seconds old, exceeding {}",
+                    stream_lag.stream, stream_lag.oldest_entry_age, settings.max_oldest_entry_age
+                ));
+            }
+        }
+
+        if report.apply_error_rate > settings.max_apply_error_rate {
+            reasons.push(format!(
+                "apply error rate is {:.3}, exceeding {}",
+                report.apply_error_rate, settings.max_apply_error_rate
+            ));
+        }
+
+        reasons
+    }
+
+    fn observe_metrics(&self, cx: &metrics::Context) {
+        let report = match self.report() {
+            Some(report) => report,
+            None => return,
+        };
+
+        for stream_lag in &report.streams {
+            let attributes = [metrics::KeyValue::new("stream", stream_lag.stream.clone())];
+            metrics::STREAM_BACKLOG.observe(
+                cx,
+                u64::try_from(stream_lag.backlog).unwrap_or(u64::MAX),
+                &attributes,
+            );
+            metrics::OLDEST_ENTRY_AGE.observe(cx, stream_lag.oldest_entry_age, &attributes);
+            metrics::DEAD_LETTER_BACKLOG.observe(
+                cx,
+                u64::try_from(stream_lag.dead_letters).unwrap_or(u64::MAX),
+                &attributes,
+            );
+        }
+        metrics::APPLY_ERROR_RATE.observe(cx, report.apply_error_rate, &[]);
+    }
+}
+
+/// Report the latest lag through the gauges whenever the metrics are collected
+pub fn register_metrics(health: Arc<DrainerHealth>) -> errors::DrainerResult<()> {
+    metrics::register_callback(move |cx| health.observe_metrics(cx))
+}
+
+/// Check the lag of every stream periodically, until the task is aborted
+pub async fn monitor_lag(store: Arc<Store>, health: Arc<DrainerHealth>, number_of_streams: u8) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+        health.settings.check_interval,
+    ));
+
+    loop {
+        interval.tick().await;
+
+        match check_lag(&store, number_of_streams).await {
+            Ok(report) => health.set_report(report),
+            Err(error) => logger::error!(?error, "Failed to check the drainer lag"),
+        }
+    }
+}
+
+async fn check_lag(store: &Arc<Store>, number_of_streams: u8) -> errors::DrainerResult<LagReport> {
+    let mut streams = Vec::with_capacity(usize::from(number_of_streams));
+    for stream_index in 0..number_of_streams {
+        let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
+        streams.push(get_stream_lag(store, stream_name).await?);
+    }
+
+    let applied = APPLIED_ENTRIES.swap(0, Ordering::Relaxed);
+    let failed = FAILED_ENTRIES.swap(0, Ordering::Relaxed);
+    let apply_error_rate = match applied.saturating_add(failed) {
+        0 => 0.0,
+        // Precision loss is irrelevant for a ratio
+        #[allow(clippy::as_conversions)]
+        total => failed as f64 / total as f64,
+    };
+
+    Ok(LagReport {
+        streams,
+        apply_error_rate,
+        checked_at: common_utils::date_time::now_unix_timestamp(),
+    })
+}
+
+async fn get_stream_lag(store: &Store, stream_name: String) -> errors::DrainerResult<StreamLag> {
+    // Entries are removed from the stream once they are drained, so every entry of the stream is
+    // waiting to be drained
+    let backlog = store
+        .redis_conn
+        .stream_get_length(stream_name.as_str())
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    let dead_letters = store
+        .redis_conn
+        .stream_get_length(store.dead_letter_stream(&stream_name))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    let oldest_entry = store
+        .redis_conn
+        .stream_read_range(stream_name.as_str(), "-", "+", Some(1))
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    let oldest_entry_age = match oldest_entry.first() {
+        Some((entry_id, _)) => {
+            // Entry IDs start with the UNIX timestamp in milliseconds at which they were added
+            let appended_at = entry_id
+                .split('-')
+                .next()
+                .and_then(|milliseconds| milliseconds.parse::<i64>().ok())
+                .ok_or_else(|| {
+                    DrainerError::UnexpectedError(format!("Invalid stream entry ID {entry_id}"))
+                })
+                .into_report()?
+                / 1000;
+            common_utils::date_time::now_unix_timestamp()
+                .saturating_sub(appended_at)
+                .max(0)
+        }
+        None => 0,
+    };
+
+    Ok(StreamLag {
+        stream: stream_name,
+        backlog,
+        oldest_entry_age,
+        dead_letters,
+    })
+}
+
+/// Start the HTTP server exposing the liveness (`/health`) and readiness (`/health/ready`) checks
+pub fn start_health_server(
+    health: Arc<DrainerHealth>,
+) -> errors::DrainerResult<actix_web::dev::Server> {
+    let address = (health.settings.host.clone(), health.settings.port);
+    let server = actix_web::HttpServer::new(move || {
+        actix_web::App::new()
+            .app_data(web::Data::from(health.clone()))
+            .route("/health", web::get().to(liveness))
+            .route("/health/ready", web::get().to(readiness))
+    })
+    .workers(1)
+    // Shutdown is handled by the drainer
+    .disable_signals()
+    .bind(address)
+    .into_report()
+    .change_context(DrainerError::UnexpectedError(
+        "Failed to bind the health server".to_string(),
+    ))?
+    .run();
+
+    Ok(server)
+}
+
+async fn liveness() -> HttpResponse {
+    HttpResponse::Ok().body("health is good")
+}
+
+async fn readiness(health: web::Data<DrainerHealth>) -> HttpResponse {
+    let report = match health.report() {
+        Some(report) => report,
+        None => {
+            return HttpResponse::ServiceUnavailable().body("Drainer lag was not checked yet");
+        }
+    };
+
+    let reasons = health.unhealthy_reasons(&report);
+    let response = HealthResponse {
+        healthy: reasons.is_empty(),
+        reasons,
+        lag: &report,
+    };
+    if response.healthy {
+        HttpResponse::Ok().json(response)
+    } else {
+        HttpResponse::ServiceUnavailable().json(response)
+    }
+}
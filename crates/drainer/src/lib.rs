@@ -1,6 +1,9 @@
 mod connection;
+pub mod consistency;
+pub mod dead_letter;
 pub mod env;
 pub mod errors;
+pub mod health;
 pub(crate) mod metrics;
 pub mod services;
 pub mod settings;
@@ -39,16 +42,27 @@ pub async fn start_drainer(
     let handle = signal.handle();
     let task_handle = tokio::spawn(common_utils::signals::signal_handler(signal, tx));
 
+    for index in 0..number_of_streams {
+        let stream_name = utils::get_drainer_stream_name(store.clone(), index);
+        utils::create_consumer_group(&stream_name, &store).await?;
+    }
+
+    let streams_in_use = Arc::new(
+        (0..number_of_streams)
+            .map(|_| atomic::AtomicBool::new(false))
+            .collect::<Vec<_>>(),
+    );
     let active_tasks = Arc::new(atomic::AtomicU64::new(0));
     'event: loop {
         match rx.try_recv() {
             Err(oneshot::error::TryRecvError::Empty) => {
-                if utils::is_stream_available(stream_index, store.clone()).await {
+                if utils::is_stream_available(stream_index, &streams_in_use) {
                     tokio::spawn(drainer_handler(
                         store.clone(),
                         stream_index,
                         max_read_count,
                         active_tasks.clone(),
+                        streams_in_use.clone(),
                     ));
                     jobs_picked += 1;
                 }
@@ -65,6 +79,14 @@ pub async fn start_drainer(
                 let shutdown_started = tokio::time::Instant::now();
                 loop {
                     if active_tasks.load(atomic::Ordering::Acquire) == 0 {
+                        for index in 0..number_of_streams {
+                            let stream_name = utils::get_drainer_stream_name(store.clone(), index);
+                            if let Err(error) =
+                                utils::release_shard_lease(&stream_name, &store).await
+                            {
+                                logger::error!(?error, stream = %stream_name, "Failed to release the shard lease");
+                            }
+                        }
                         logger::info!("Terminating drainer");
                         metrics::SUCCESSFUL_SHUTDOWN.add(&metrics::CONTEXT, 1, &[]);
                         let shutdown_ended = shutdown_started.elapsed().as_secs_f64() * 1000f64;
@@ -93,21 +115,52 @@ async fn drainer_handler(
     stream_index: u8,
     max_read_count: u64,
     active_tasks: Arc<atomic::AtomicU64>,
+    streams_in_use: Arc<Vec<atomic::AtomicBool>>,
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
-    let drainer_result = drainer(store.clone(), max_read_count, stream_name.as_str()).await;
+    let output = if utils::acquire_shard_lease(stream_name.as_str(), &store).await {
+        drain_shard(store, stream_name.as_str(), max_read_count).await
+    } else {
+        // Another drainer instance holds the lease of the shard
+        Ok(())
+    };
+
+    utils::make_stream_available(stream_index, &streams_in_use);
+    active_tasks.fetch_sub(1, atomic::Ordering::Release);
+    output
+}
+
+async fn drain_shard(
+    store: Arc<Store>,
+    stream_name: &str,
+    max_read_count: u64,
+) -> errors::DrainerResult<()> {
+    let drainer_result = drainer(store.clone(), max_read_count, stream_name).await;
 
     if let Err(error) = drainer_result {
         logger::error!(?error)
     }
 
-    let flag_stream_name = utils::get_stream_key_flag(store.clone(), stream_index);
-    //TODO: USE THE RESULT FOR LOGGING
-    let output =
-        utils::make_stream_available(flag_stream_name.as_str(), store.redis_conn.as_ref()).await;
-    active_tasks.fetch_sub(1, atomic::Ordering::Release);
+    let flag_stream_name = utils::get_dead_letter_stream_key_flag(&store, stream_name);
+    let mut output = Ok(());
+    if utils::is_dead_letter_stream_available(flag_stream_name.as_str(), &store).await {
+        let dead_letter_result =
+            dead_letter::retry_dead_letters(&store, stream_name, max_read_count).await;
+
+        if let Err(error) = dead_letter_result {
+            logger::error!(?error, "Failed to retry entries in the dead letter stream")
+        }
+
+        //TODO: USE THE RESULT FOR LOGGING
+        output = utils::make_dead_letter_stream_available(
+            flag_stream_name.as_str(),
+            store.redis_conn.as_ref(),
+        )
+        .await;
+    }
+
     output
 }
 
@@ -116,11 +169,32 @@ async fn drainer(
     max_read_count: u64,
     stream_name: &str,
 ) -> errors::DrainerResult<()> {
-    let stream_read =
-        utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await?; // this returns the error.
+    if let Err(error) = utils::claim_idle_entries(stream_name, max_read_count, &store).await {
+        logger::error!(?error, "Failed to claim idle stream entries");
+    }
+
+    // Entries delivered to this consumer which were not acknowledged, because they were claimed
+    // from another consumer or the drainer stopped before acknowledging them, are drained before
+    // reading new entries
+    let pending_read = utils::read_from_stream(
+        stream_name,
+        utils::PENDING_ENTRIES_ID,
+        max_read_count,
+        &store,
+    )
+    .await?;
+    let has_pending_entries = pending_read
+        .get(stream_name)
+        .map_or(false, |entries| !entries.is_empty());
+    let stream_read = if has_pending_entries {
+        pending_read
+    } else {
+        utils::read_from_stream(stream_name, utils::NEW_ENTRIES_ID, max_read_count, &store).await?
+        // this returns the error.
+    };
 
     // parse_stream_entries returns error if no entries is found, handle it
-    let (entries, last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
+    let (entries, _) = utils::parse_stream_entries(&stream_read, stream_name)?;
     let read_count = entries.len();
 
     metrics::JOBS_PICKED_PER_STREAM.add(
@@ -132,113 +206,561 @@ async fn drainer(
         }],
     );
 
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
+    // Entries are acknowledged only once they were either applied or moved to the dead letter
+    // stream, so that the entries of a drainer which stops midway are drained by another consumer
+    let mut handled_entry_ids = Vec::new();
+    for (entry_id, fields) in entries {
+        // Entries which were deleted after being delivered have no fields left to apply
+        if fields.is_empty() {
+            handled_entry_ids.push(entry_id.clone());
+            continue;
+        }
 
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
+        let typed_sql = utils::get_typed_sql(fields);
+
+        let result = execute_db_operation(&store, stream_name, &typed_sql).await;
+        health::record_entry_result(result.is_ok());
+
+        if let Err(error) = result {
+            logger::error!(
+                ?error,
+                %entry_id,
+                "Failed to drain stream entry, moving it to the dead letter stream"
+            );
+
+            let dead_letter = dead_letter::DeadLetterEntry::new(
+                typed_sql,
+                entry_id.clone(),
+                &error,
+                store.config.dead_letter_retry_delay,
+            );
+            if let Err(error) =
+                dead_letter::push_to_dead_letter_stream(&store, stream_name, &dead_letter).await
+            {
+                logger::error!(
+                    ?error,
+                    %entry_id,
+                    "Failed to move stream entry to the dead letter stream"
                 );
+                break;
             }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
-            }
-        };
+
+            metrics::ENTRIES_MOVED_TO_DEAD_LETTER_STREAM.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+            );
+        }
+
+        handled_entry_ids.push(entry_id.clone());
     }
 
-    let entries_trimmed =
-        utils::trim_from_stream(stream_name, last_entry_id.as_str(), &store.redis_conn).await?;
+    if handled_entry_ids.is_empty() {
+        return Ok(());
+    }
+    let handled_count = handled_entry_ids.len();
+    let entries_acknowledged =
+        utils::acknowledge_entries(stream_name, handled_entry_ids, &store).await?;
 
-    if read_count != entries_trimmed {
+    if handled_count != entries_acknowledged {
         logger::error!(
             read_entries = %read_count,
-            trimmed_entries = %entries_trimmed,
-            ?entries,
-            "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
+            handled_entries = %handled_count,
+            acknowledged_entries = %entries_acknowledged,
+            "Assertion Failed no. of entries handled from the stream doesn't match no. of entries acknowledged"
         );
     }
 
     Ok(())
 }
 
+/// Apply the database operation serialized in the `typed_sql` field of a stream entry.
+///
+/// Versioned operations are applied at most once and in the order of their versions: an operation
+/// whose version was already applied is skipped, and an operation whose previous version was not
+/// applied yet fails, so that it is retried from the dead letter stream. The version is claimed
+/// before the operation is applied and marked applied afterwards, each with a single atomic
+/// check-and-set on the version hash, so that a version is applied by a single consumer.
+pub(crate) async fn execute_db_operation(
+    store: &Store,
+    stream_name: &str,
+    typed_sql: &str,
+) -> errors::DrainerResult<()> {
+    let typed_sql = serde_json::from_str::<kv::TypedSql>(typed_sql)
+        .into_report()
+        .change_context(errors::DrainerError::EntryDeserializationFailed)?;
+
+    let versioned_row = typed_sql
+        .version
+        .zip(typed_sql.op.primary_key())
+        .map(|(version, primary_key)| (version, kv::version_key(stream_name, &primary_key)));
+
+    if let Some((version, version_key)) = &versioned_row {
+        match claim_version(store, version_key, *version).await? {
+            VersionClaim::AlreadyApplied { applied_version } => {
+                logger::info!(
+                    %version,
+                    %applied_version,
+                    %version_key,
+                    "Skipping database operation which was already applied"
+                );
+                metrics::ENTRIES_ALREADY_APPLIED.add(&metrics::CONTEXT, 1, &[]);
+                return Ok(());
+            }
+            VersionClaim::OutOfOrder { applied_version } => {
+                return Err(errors::DrainerError::OutOfOrderEntry {
+                    version: *version,
+                    applied_version,
+                })
+                .into_report()
+                .attach_printable(version_key.clone());
+            }
+            VersionClaim::ClaimedByOtherConsumer => {
+                return Err(errors::DrainerError::EntryClaimedByOtherConsumer {
+                    version: *version,
+                })
+                .into_report()
+                .attach_printable(version_key.clone());
+            }
+            VersionClaim::Acquired => {}
+        }
+    }
+
+    let result = apply_db_operation(store, typed_sql.op).await;
+
+    if let Some((version, version_key)) = versioned_row {
+        match &result {
+            Ok(()) => complete_version(store, &version_key, version).await?,
+            Err(_) => {
+                if let Err(error) = release_version(store, &version_key).await {
+                    logger::error!(?error, %version_key, "Failed to release the version claim");
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Mark the version of the database operation applied without applying the operation, so that the
+/// later operations of its row are applied
+pub(crate) async fn skip_db_operation(
+    store: &Store,
+    stream_name: &str,
+    typed_sql: &str,
+) -> errors::DrainerResult<()> {
+    let typed_sql = serde_json::from_str::<kv::TypedSql>(typed_sql)
+        .into_report()
+        .change_context(errors::DrainerError::EntryDeserializationFailed)?;
+
+    let (version, version_key) = match typed_sql.version.zip(typed_sql.op.primary_key()) {
+        Some((version, primary_key)) => (version, kv::version_key(stream_name, &primary_key)),
+        None => return Ok(()),
+    };
+
+    match claim_version(store, &version_key, version).await? {
+        VersionClaim::AlreadyApplied { .. } => Ok(()),
+        VersionClaim::OutOfOrder { applied_version } => {
+            Err(errors::DrainerError::OutOfOrderEntry {
+                version,
+                applied_version,
+            })
+            .into_report()
+            .attach_printable(version_key)
+        }
+        VersionClaim::ClaimedByOtherConsumer => {
+            Err(errors::DrainerError::EntryClaimedByOtherConsumer { version })
+                .into_report()
+                .attach_printable(version_key)
+        }
+        VersionClaim::Acquired => complete_version(store, &version_key, version).await,
+    }
+}
+
+/// Field of the version hash holding the consumer applying the next version of the row
+const CLAIMED_BY_FIELD: &str = "claimed_by";
+
+/// Field of the version hash holding the time at which the claim of the consumer applying the
+/// next version of the row expires, as a UNIX timestamp in milliseconds
+const CLAIM_EXPIRES_AT_FIELD: &str = "claim_expires_at";
+
+/// Claims the version for this consumer if it is the next version to be applied and no other
+/// consumer holds an unexpired claim on it. The claim expires using the clock of the Redis
+/// server, so that consumers agree on its expiry.
+///
+/// Returns the outcome along with the applied version.
+const CLAIM_VERSION_SCRIPT: &str = r#"
+local applied = tonumber(redis.call("HGET", KEYS[1], ARGV[1]) or "0")
+local version = tonumber(ARGV[4])
+if version <= applied then
+    return { 0, applied }
+end
+if version > applied + 1 then
+    return { 1, applied }
+end
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local claimed_by = redis.call("HGET", KEYS[1], ARGV[2])
+local claim_expires_at = tonumber(redis.call("HGET", KEYS[1], ARGV[3]) or "0")
+if claimed_by and claimed_by ~= ARGV[5] and now < claim_expires_at then
+    return { 2, applied }
+end
+redis.call("HSET", KEYS[1], ARGV[2], ARGV[5], ARGV[3], now + tonumber(ARGV[6]))
+redis.call("EXPIRE", KEYS[1], ARGV[7])
+return { 3, applied }
+"#;
+
+/// Marks the version applied if this consumer still holds the claim on it
+const COMPLETE_VERSION_SCRIPT: &str = r#"
+if redis.call("HGET", KEYS[1], ARGV[2]) ~= ARGV[5] then
+    return 0
+end
+local applied = tonumber(redis.call("HGET", KEYS[1], ARGV[1]) or "0")
+if tonumber(ARGV[4]) ~= applied + 1 then
+    return 0
+end
+redis.call("HSET", KEYS[1], ARGV[1], ARGV[4])
+redis.call("HDEL", KEYS[1], ARGV[2], ARGV[3])
+redis.call("EXPIRE", KEYS[1], ARGV[6])
+return 1
+"#;
+
+/// Releases the claim on the next version if this consumer holds it
+const RELEASE_VERSION_SCRIPT: &str = r#"
+if redis.call("HGET", KEYS[1], ARGV[1]) == ARGV[3] then
+    return redis.call("HDEL", KEYS[1], ARGV[1], ARGV[2])
+end
+return 0
+"#;
+
+enum VersionClaim {
+    AlreadyApplied { applied_version: i64 },
+    OutOfOrder { applied_version: i64 },
+    ClaimedByOtherConsumer,
+    Acquired,
+}
+
+async fn claim_version(
+    store: &Store,
+    version_key: &str,
+    version: i64,
+) -> errors::DrainerResult<VersionClaim> {
+    let claim = store
+        .redis_conn
+        .evaluate_script::<Vec<i64>, _>(
+            CLAIM_VERSION_SCRIPT,
+            version_key,
+            vec![
+                kv::APPLIED_VERSION_FIELD.to_string(),
+                CLAIMED_BY_FIELD.to_string(),
+                CLAIM_EXPIRES_AT_FIELD.to_string(),
+                version.to_string(),
+                store.config.consumer_name.clone(),
+                store.config.pending_entry_idle_time.to_string(),
+                kv::VERSION_TTL.to_string(),
+            ],
+        )
+        .await
+        .map_err(errors::DrainerError::from)
+        .into_report()?;
+
+    match claim.as_slice() {
+        [0, applied_version] => Ok(VersionClaim::AlreadyApplied {
+            applied_version: *applied_version,
+        }),
+        [1, applied_version] => Ok(VersionClaim::OutOfOrder {
+            applied_version: *applied_version,
+        }),
+        [2, _] => Ok(VersionClaim::ClaimedByOtherConsumer),
+        [3, _] => Ok(VersionClaim::Acquired),
+        _ => Err(errors::DrainerError::UnexpectedError(format!(
+            "Unexpected reply to version claim: {claim:?}"
+        )))
+        .into_report(),
+    }
+}
+
+async fn complete_version(
+    store: &Store,
+    version_key: &str,
+    version: i64,
+) -> errors::DrainerResult<()> {
+    let completed = store
+        .redis_conn
+        .evaluate_script::<i64, _>(
+            COMPLETE_VERSION_SCRIPT,
+            version_key,
+            vec![
+                kv::APPLIED_VERSION_FIELD.to_string(),
+                CLAIMED_BY_FIELD.to_string(),
+                CLAIM_EXPIRES_AT_FIELD.to_string(),
+                version.to_string(),
+                store.config.consumer_name.clone(),
+                kv::VERSION_TTL.to_string(),
+            ],
+        )
+        .await
+        .map_err(errors::DrainerError::from)
+        .into_report()?;
+
+    // The claim expired while the operation was applied, and the version may be applied again by
+    // the consumer which claimed it next
+    if completed == 1 {
+        Ok(())
+    } else {
+        Err(errors::DrainerError::EntryClaimLost { version })
+            .into_report()
+            .attach_printable(version_key.to_owned())
+    }
+}
+
+async fn release_version(store: &Store, version_key: &str) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .evaluate_script::<i64, _>(
+            RELEASE_VERSION_SCRIPT,
+            version_key,
+            vec![
+                CLAIMED_BY_FIELD.to_string(),
+                CLAIM_EXPIRES_AT_FIELD.to_string(),
+                store.config.consumer_name.clone(),
+            ],
+        )
+        .await
+        .map(|_| ())
+        .map_err(errors::DrainerError::from)
+        .into_report()
+}
+
+async fn apply_db_operation(store: &Store, db_op: kv::DBOperation) -> errors::DrainerResult<()> {
+    let conn = pg_connection(&store.master_pool).await;
+    let insert_op = "insert";
+    let update_op = "update";
+    let delete_op = "delete";
+    let payment_intent = "payment_intent";
+    let payment_attempt = "payment_attempt";
+    let refund = "refund";
+    let address = "address";
+    let connector_response = "connector_response";
+    let customer = "customer";
+    let mandate = "mandate";
+    let reverse_lookup = "reverse_lookup";
+    match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match insertable {
+                    kv::Insertable::PaymentIntent(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (payment_id, merchant_id) = (a.payment_id.clone(), a.merchant_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::payment_intent::PaymentIntent::find_by_payment_id_merchant_id(&conn, &payment_id, &merchant_id),
+                            inserted,
+                            insert_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Insertable::PaymentAttempt(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (merchant_id, attempt_id) = (a.merchant_id.clone(), a.attempt_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::payment_attempt::PaymentAttempt::find_by_merchant_id_attempt_id(&conn, &merchant_id, &attempt_id),
+                            inserted,
+                            insert_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Insertable::Refund(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (merchant_id, refund_id) = (a.merchant_id.clone(), a.refund_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::refund::Refund::find_by_merchant_id_refund_id(&conn, &merchant_id, &refund_id),
+                            inserted,
+                            insert_op,
+                            refund
+                        )
+                    }
+                    kv::Insertable::Address(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let address_id = a.address_id.clone();
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::address::Address::find_by_address_id(&conn, &address_id),
+                            inserted,
+                            insert_op,
+                            address
+                        )
+                    }
+                    kv::Insertable::ConnectorResponse(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (payment_id, merchant_id, attempt_id) = (a.payment_id.clone(), a.merchant_id.clone(), a.attempt_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::connector_response::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(&conn, &payment_id, &merchant_id, &attempt_id),
+                            inserted,
+                            insert_op,
+                            connector_response
+                        )
+                    }
+                    kv::Insertable::Customer(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (customer_id, merchant_id) = (a.customer_id.clone(), a.merchant_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::customers::Customer::find_by_customer_id_merchant_id(&conn, &customer_id, &merchant_id),
+                            inserted,
+                            insert_op,
+                            customer
+                        )
+                    }
+                    kv::Insertable::Mandate(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let (merchant_id, mandate_id) = (a.merchant_id.clone(), a.mandate_id.clone());
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::mandate::Mandate::find_by_merchant_id_mandate_id(&conn, &merchant_id, &mandate_id),
+                            inserted,
+                            insert_op,
+                            mandate
+                        )
+                    }
+                    kv::Insertable::ReverseLookup(a) => {
+                        let inserted = consistency::to_json_object(&a)?;
+                        let lookup_id = a.lookup_id.clone();
+                        macro_util::handle_insert_resp!(
+                            a.insert(&conn).await,
+                            storage_models::reverse_lookup::ReverseLookup::find_by_lookup_id(&lookup_id, &conn),
+                            inserted,
+                            insert_op,
+                            reverse_lookup
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: insert_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Update { updatable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match updatable {
+                    kv::Updateable::PaymentIntentUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Updateable::PaymentAttemptUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Updateable::RefundUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            refund
+                        )
+                    }
+                    kv::Updateable::AddressUpdate(a) => {
+                        macro_util::handle_resp!(
+                            storage_models::address::Address::update_by_address_id(
+                                &conn,
+                                a.orig.address_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            address
+                        )
+                    }
+                    kv::Updateable::ConnectorResponseUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            connector_response
+                        )
+                    }
+                    kv::Updateable::CustomerUpdate(a) => {
+                        macro_util::handle_resp!(
+                            storage_models::customers::Customer::update_by_customer_id_merchant_id(
+                                &conn,
+                                a.orig.customer_id,
+                                a.orig.merchant_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            customer
+                        )
+                    }
+                    kv::Updateable::MandateUpdate(a) => {
+                        macro_util::handle_resp!(
+                            storage_models::mandate::Mandate::update_by_merchant_id_mandate_id(
+                                &conn,
+                                &a.orig.merchant_id,
+                                &a.orig.mandate_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            mandate
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: update_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Delete { deletable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match deletable {
+                    kv::Deletable::Customer(a) => macro_util::handle_resp!(
+                        storage_models::customers::Customer::delete_by_customer_id_merchant_id(
+                            &conn,
+                            &a.customer_id,
+                            &a.merchant_id,
+                        )
+                        .await,
+                        delete_op,
+                        customer
+                    ),
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: delete_op.into(),
+                }],
+            );
+            result
+        }
+    }
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -252,6 +774,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Ok(())
                 }
                 Err(err) => {
                     logger::error!(operation = %$op_type, table = %$table, ?err);
@@ -261,9 +784,58 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Err(err.change_context(errors::DrainerError::DatabaseOperationFailed))
                 }
             }
         };
     }
     pub(crate) use handle_resp;
+
+    /// Inserts are idempotent: a duplicate key means that the entry was inserted before, by an
+    /// earlier attempt to drain the same entry, if the stored row matches the inserted one. A
+    /// stored row that differs is a conflicting write, and fails the entry
+    macro_rules! handle_insert_resp {
+        ($result:expr, $find_stored:expr, $inserted:expr, $op_type:expr, $table:expr) => {
+            match $result {
+                Err(err)
+                    if matches!(
+                        err.current_context(),
+                        storage_models::errors::DatabaseError::UniqueViolation
+                    ) =>
+                {
+                    let stored = $find_stored
+                        .await
+                        .change_context(errors::DrainerError::DatabaseOperationFailed)?;
+                    let differing_fields = consistency::get_differing_fields(
+                        &$inserted,
+                        &consistency::to_json_object(&stored)?,
+                    );
+                    if differing_fields.is_empty() {
+                        logger::warn!(operation = %$op_type, table = %$table, "Entry was already inserted");
+                        metrics::DUPLICATE_INSERTS_IGNORED.add(&metrics::CONTEXT, 1, &[
+                            metrics::KeyValue {
+                                key: "operation".into(),
+                                value: $table.into(),
+                            }
+                        ]);
+                        Ok(())
+                    } else {
+                        logger::error!(operation = %$op_type, table = %$table, ?differing_fields, "Entry conflicts with a stored row");
+                        metrics::ERRORS_WHILE_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, &[
+                            metrics::KeyValue {
+                                key: "operation".into(),
+                                value: $table.into(),
+                            }
+                        ]);
+                        Err(err.change_context(errors::DrainerError::DatabaseOperationFailed))
+                            .attach_printable_lazy(|| {
+                                format!("Stored row differs in fields {differing_fields:?}")
+                            })
+                    }
+                }
+                result => macro_util::handle_resp!(result, $op_type, $table),
+            }
+        };
+    }
+    pub(crate) use handle_insert_resp;
 }
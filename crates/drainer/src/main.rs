@@ -1,4 +1,6 @@
-use drainer::{errors, errors::DrainerResult, logger::logger, services, settings, start_drainer};
+use drainer::{
+    errors, errors::DrainerResult, health, logger::logger, services, settings, start_drainer,
+};
 use error_stack::ResultExt;
 
 #[tokio::main]
@@ -25,6 +27,18 @@ async fn main() -> DrainerResult<()> {
 
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
+    let drainer_health =
+        std::sync::Arc::new(health::DrainerHealth::new(conf.drainer.health.clone()));
+    health::register_metrics(drainer_health.clone())?;
+    let lag_monitor = tokio::spawn(health::monitor_lag(
+        store.clone(),
+        drainer_health.clone(),
+        number_of_streams,
+    ));
+    let health_server = health::start_health_server(drainer_health)?;
+    let health_server_handle = health_server.handle();
+    tokio::spawn(health_server);
+
     start_drainer(
         store.clone(),
         number_of_streams,
@@ -34,6 +48,11 @@ async fn main() -> DrainerResult<()> {
     )
     .await?;
 
+    health_server_handle.stop(true).await;
+    lag_monitor.abort();
+    // The monitor holds a reference to the store until it is dropped
+    let _ = lag_monitor.await;
+
     store.close().await;
     Ok(())
 }
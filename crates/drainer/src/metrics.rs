@@ -1,10 +1,12 @@
+use error_stack::{IntoReport, ResultExt};
 use once_cell::sync::Lazy;
-pub use router_env::opentelemetry::KeyValue;
 use router_env::opentelemetry::{
     global,
-    metrics::{Counter, Histogram, Meter},
-    Context,
+    metrics::{Counter, Histogram, Meter, ObservableGauge},
 };
+pub use router_env::opentelemetry::{Context, KeyValue};
+
+use crate::errors;
 
 pub(crate) static CONTEXT: Lazy<Context> = Lazy::new(Context::current);
 static DRAINER_METER: Lazy<Meter> = Lazy::new(|| global::meter("DRAINER"));
@@ -40,13 +42,37 @@ pub(crate) static SUCCESSFUL_QUERY_EXECUTION: Lazy<Counter<u64>> = Lazy::new(||
         .init()
 });
 
+pub(crate) static ENTRIES_MOVED_TO_DEAD_LETTER_STREAM: Lazy<Counter<u64>> = Lazy::new(|| {
+    DRAINER_METER
+        .u64_counter("ENTRIES_MOVED_TO_DEAD_LETTER_STREAM")
+        .init()
+});
+
+pub(crate) static DEAD_LETTERS_RETRIED_SUCCESSFULLY: Lazy<Counter<u64>> = Lazy::new(|| {
+    DRAINER_METER
+        .u64_counter("DEAD_LETTERS_RETRIED_SUCCESSFULLY")
+        .init()
+});
+
+pub(crate) static ENTRIES_ALREADY_APPLIED: Lazy<Counter<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_counter("ENTRIES_ALREADY_APPLIED").init());
+
+pub(crate) static DUPLICATE_INSERTS_IGNORED: Lazy<Counter<u64>> = Lazy::new(|| {
+    DRAINER_METER
+        .u64_counter("DUPLICATE_INSERTS_IGNORED")
+        .init()
+});
+
 // Time in (ms) milliseconds
 pub(crate) static REDIS_STREAM_READ_TIME: Lazy<Histogram<f64>> =
     Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_READ_TIME").init());
 
 // Time in (ms) milliseconds
-pub(crate) static REDIS_STREAM_TRIM_TIME: Lazy<Histogram<f64>> =
-    Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_TRIM_TIME").init());
+pub(crate) static REDIS_STREAM_ACK_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_ACK_TIME").init());
+
+pub(crate) static ENTRIES_CLAIMED: Lazy<Counter<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_counter("ENTRIES_CLAIMED").init());
 
 pub(crate) static SHUTDOWN_SIGNAL_RECEIVED: Lazy<Counter<u64>> =
     Lazy::new(|| DRAINER_METER.u64_counter("SHUTDOWN_SIGNAL_RECEIVED").init());
@@ -57,3 +83,36 @@ pub(crate) static SUCCESSFUL_SHUTDOWN: Lazy<Counter<u64>> =
 // Time in (ms) milliseconds
 pub(crate) static CLEANUP_TIME: Lazy<Histogram<f64>> =
     Lazy::new(|| DRAINER_METER.f64_histogram("CLEANUP_TIME").init());
+
+pub(crate) static STREAM_BACKLOG: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_observable_gauge("STREAM_BACKLOG").init());
+
+// Time in seconds
+pub(crate) static OLDEST_ENTRY_AGE: Lazy<ObservableGauge<i64>> = Lazy::new(|| {
+    DRAINER_METER
+        .i64_observable_gauge("OLDEST_ENTRY_AGE")
+        .init()
+});
+
+pub(crate) static DEAD_LETTER_BACKLOG: Lazy<ObservableGauge<u64>> = Lazy::new(|| {
+    DRAINER_METER
+        .u64_observable_gauge("DEAD_LETTER_BACKLOG")
+        .init()
+});
+
+pub(crate) static APPLY_ERROR_RATE: Lazy<ObservableGauge<f64>> = Lazy::new(|| {
+    DRAINER_METER
+        .f64_observable_gauge("APPLY_ERROR_RATE")
+        .init()
+});
+
+/// Register a callback observing the gauges whenever the metrics are collected
+pub(crate) fn register_callback<F>(callback: F) -> errors::DrainerResult<()>
+where
+    F: Fn(&Context) + Send + Sync + 'static,
+{
+    DRAINER_METER
+        .register_callback(callback)
+        .into_report()
+        .change_context(errors::DrainerError::MetricsError)
+}
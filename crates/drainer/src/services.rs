@@ -13,6 +13,12 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub dead_letter_max_retries: u8,
+    pub dead_letter_retry_delay: u32,
+    pub consumer_group: String,
+    pub consumer_name: String,
+    pub pending_entry_idle_time: u64,
+    pub shard_lease_time: u32,
 }
 
 impl Store {
@@ -23,6 +29,12 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                dead_letter_max_retries: config.drainer.dead_letter_max_retries,
+                dead_letter_retry_delay: config.drainer.dead_letter_retry_delay,
+                consumer_group: config.drainer.consumer_group.clone(),
+                consumer_name: config.drainer.consumer_name.clone(),
+                pending_entry_idle_time: config.drainer.pending_entry_idle_time,
+                shard_lease_time: config.drainer.shard_lease_time,
             },
         }
     }
@@ -32,6 +44,13 @@ impl Store {
         format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
     }
 
+    pub fn dead_letter_stream(&self, stream_name: &str) -> String {
+        // Example: {shard_5}_drainer_stream_dead_letter
+        // The dead letter stream has the same hash tag as the drainer stream, so that both the
+        // streams belong to the same Redis cluster slot
+        format!("{stream_name}_dead_letter")
+    }
+
     #[allow(clippy::expect_used)]
     pub async fn close(mut self: Arc<Self>) {
         Arc::get_mut(&mut self)
@@ -46,6 +46,33 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub dead_letter_max_retries: u8,
+    pub dead_letter_retry_delay: u32, // in seconds
+    pub consumer_group: String,
+    /// Must be unique for every drainer instance, defaults to the host name
+    pub consumer_name: String,
+    /// Time after which the entries of a consumer which stopped draining are claimed by the
+    /// other consumers of the group
+    pub pending_entry_idle_time: u64, // in milliseconds
+    /// Time for which a drainer instance owns a shard once it drains the shard, after which
+    /// another instance may take the shard over
+    pub shard_lease_time: u32, // in seconds
+    pub health: HealthSettings,
+}
+
+/// The drainer reports unhealthy when any of the thresholds is exceeded
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HealthSettings {
+    pub host: String,
+    pub port: u16,
+    pub check_interval: u64, // in seconds
+    /// Maximum number of entries waiting to be drained in a stream
+    pub max_backlog: usize,
+    /// Maximum age of the oldest entry waiting to be drained in a stream
+    pub max_oldest_entry_age: i64, // in seconds
+    /// Maximum ratio of stream entries which could not be applied to the database
+    pub max_apply_error_rate: f64,
 }
 
 impl Default for Database {
@@ -69,6 +96,27 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 500,      // in milliseconds
+            dead_letter_max_retries: 5,
+            dead_letter_retry_delay: 5, // in seconds
+            consumer_group: "drainer_consumer_group".into(),
+            consumer_name: std::env::var("HOSTNAME")
+                .unwrap_or_else(|_| format!("drainer_{}", std::process::id())),
+            pending_entry_idle_time: 60000, // in milliseconds
+            shard_lease_time: 30,           // in seconds
+            health: HealthSettings::default(),
+        }
+    }
+}
+
+impl Default for HealthSettings {
+    fn default() -> Self {
+        Self {
+            host: "0.0.0.0".into(),
+            port: 8081,
+            check_interval: 10, // in seconds
+            max_backlog: 10000,
+            max_oldest_entry_age: 300, // in seconds
+            max_apply_error_rate: 0.1,
         }
     }
 }
@@ -105,10 +153,56 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.consumer_group.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer consumer group must not be empty".into(),
+            ))
+        })?;
+
+        when(self.consumer_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer consumer name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.shard_lease_time == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer shard lease time must be greater than zero".into(),
+            ))
+        })?;
+
+        self.health.validate()
+    }
+}
+
+impl HealthSettings {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer health server host must not be empty".into(),
+            ))
+        })?;
+
+        when(self.check_interval == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer health check interval must be greater than 0".into(),
+            ))
+        })?;
+
+        when(!(0.0..=1.0).contains(&self.max_apply_error_rate), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer maximum apply error rate must be between 0 and 1".into(),
+            ))
         })
     }
 }
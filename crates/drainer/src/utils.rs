@@ -1,7 +1,11 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic, Arc},
+};
 
 use error_stack::IntoReport;
 use redis_interface as redis;
+use storage_models::kv;
 
 use crate::{
     errors::{self, DrainerError},
@@ -11,37 +15,232 @@ use crate::{
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
-pub async fn is_stream_available(stream_index: u8, store: Arc<services::Store>) -> bool {
-    let stream_key_flag = get_stream_key_flag(store.clone(), stream_index);
+/// Read the entries delivered to this consumer which were not acknowledged yet
+pub const PENDING_ENTRIES_ID: &str = "0";
+/// Read the entries which were not delivered to any consumer of the group yet
+pub const NEW_ENTRIES_ID: &str = ">";
+
+/// Streams are drained by a single task of the drainer instance at a time, so that the entries of
+/// a stream are applied in order
+pub fn is_stream_available(stream_index: u8, streams_in_use: &[atomic::AtomicBool]) -> bool {
+    streams_in_use
+        .get(usize::from(stream_index))
+        .map_or(false, |in_use| {
+            in_use
+                .compare_exchange(
+                    false,
+                    true,
+                    atomic::Ordering::AcqRel,
+                    atomic::Ordering::Acquire,
+                )
+                .is_ok()
+        })
+}
+
+pub fn make_stream_available(stream_index: u8, streams_in_use: &[atomic::AtomicBool]) {
+    if let Some(in_use) = streams_in_use.get(usize::from(stream_index)) {
+        in_use.store(false, atomic::Ordering::Release);
+    }
+}
+
+/// Shards are drained by a single drainer instance at a time, which holds the lease of the shard,
+/// so that the entries of a shard are not split between the consumers of the group. The lease is
+/// extended every time its holder drains the shard, and expires once the holder stops, so that
+/// another instance takes the shard over.
+pub async fn acquire_shard_lease(stream_name: &str, store: &services::Store) -> bool {
+    let lease_key = get_shard_lease_key(stream_name);
+    let config = &store.config;
+    let lease_time = i64::from(config.shard_lease_time);
+    let result = match store
+        .redis_conn
+        .set_key_if_not_exists_with_expiry(&lease_key, config.consumer_name.as_str(), lease_time)
+        .await
+    {
+        Ok(redis::types::SetnxReply::KeySet) => Ok(true),
+        Ok(redis::types::SetnxReply::KeyNotSet) => {
+            store
+                .redis_conn
+                .set_expiry_if_value_matches(&lease_key, &config.consumer_name, lease_time)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    result.unwrap_or_else(|error| {
+        logger::error!(?error, stream = %stream_name, "Failed to acquire the shard lease");
+        false
+    })
+}
+
+/// Release the lease of the shard if this instance holds it, so that another instance takes the
+/// shard over without waiting for the lease to expire
+pub async fn release_shard_lease(
+    stream_name: &str,
+    store: &services::Store,
+) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .delete_key_if_value_matches(
+            &get_shard_lease_key(stream_name),
+            &store.config.consumer_name,
+        )
+        .await
+        .map(|_| ())
+        .map_err(DrainerError::from)
+        .into_report()
+}
 
+/// Dead letters of a stream are retried by a single drainer instance at a time. The flag expires
+/// after the default TTL, so that it is released if the instance holding it stops.
+pub async fn is_dead_letter_stream_available(
+    stream_key_flag: &str,
+    store: &services::Store,
+) -> bool {
     match store
         .redis_conn
-        .set_key_if_not_exist(stream_key_flag.as_str(), true)
+        .set_key_if_not_exist(stream_key_flag, true)
         .await
     {
         Ok(resp) => resp == redis::types::SetnxReply::KeySet,
         Err(error) => {
             logger::error!(?error);
-            // Add metrics or logs
             false
         }
     }
 }
 
+pub async fn make_dead_letter_stream_available(
+    stream_key_flag: &str,
+    redis: &redis::RedisConnectionPool,
+) -> errors::DrainerResult<()> {
+    redis
+        .delete_key(stream_key_flag)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+}
+
+/// Join the consumer group of the stream, creating the stream and the group if they don't exist
+pub async fn create_consumer_group(
+    stream_name: &str,
+    store: &services::Store,
+) -> errors::DrainerResult<()> {
+    // Entries appended before the group was created are delivered to the group as well
+    let first_entry_id = redis::RedisEntryId::UserSpecifiedID {
+        milliseconds: "0".to_string(),
+        sequence_number: "0".to_string(),
+    };
+    match store
+        .redis_conn
+        .consumer_group_create(stream_name, &store.config.consumer_group, &first_entry_id)
+        .await
+    {
+        Ok(()) => Ok(()),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis::errors::RedisError::ConsumerGroupExists
+            ) =>
+        {
+            Ok(())
+        }
+        Err(error) => Err(DrainerError::from(error)).into_report(),
+    }
+}
+
+/// Take over the entries which were read by the previous holder of the shard lease but not
+/// acknowledged within the idle time, as the consumer which read them has stopped draining the
+/// shard. Only the holder of the shard lease claims entries, so that the entries of a live
+/// consumer are not taken over while it holds the lease.
+pub async fn claim_idle_entries(
+    stream_name: &str,
+    max_read_count: u64,
+    store: &services::Store,
+) -> errors::DrainerResult<()> {
+    let config = &store.config;
+    let entry_ids = store
+        .redis_conn
+        .consumer_group_get_idle_pending_entries(
+            stream_name,
+            &config.consumer_group,
+            config.pending_entry_idle_time,
+            max_read_count,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?
+        .into_iter()
+        .filter(|(_, consumer, _, _)| consumer != &config.consumer_name)
+        .map(|(entry_id, _, _, _)| entry_id)
+        .collect::<Vec<_>>();
+
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+    let claimed_count = entry_ids.len();
+
+    // The idle time is checked again when claiming, so that an entry is claimed by a single
+    // consumer even if several consumers attempt to claim it
+    store
+        .redis_conn
+        .consumer_group_set_message_owner::<_, ()>(
+            stream_name,
+            &config.consumer_group,
+            &config.consumer_name,
+            config.pending_entry_idle_time,
+            entry_ids,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    logger::info!(stream = %stream_name, %claimed_count, "Claimed idle stream entries");
+    metrics::ENTRIES_CLAIMED.add(
+        &metrics::CONTEXT,
+        u64::try_from(claimed_count).unwrap_or(u64::MAX),
+        &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+    );
+
+    Ok(())
+}
+
 pub async fn read_from_stream(
     stream_name: &str,
+    stream_id: &str,
     max_read_count: u64,
-    redis: &redis::RedisConnectionPool,
+    store: &services::Store,
 ) -> errors::DrainerResult<StreamReadResult> {
-    // "0-0" id gives first entry
-    let stream_id = "0-0";
     let (output, execution_time) = common_utils::date_time::time_it(|| async {
-        let entries = redis
-            .stream_read_entries(stream_name, stream_id, Some(max_read_count))
+        let entries = store
+            .redis_conn
+            .stream_read_with_options(
+                stream_name,
+                stream_id,
+                Some(max_read_count),
+                None,
+                Some((&store.config.consumer_group, &store.config.consumer_name)),
+            )
             .await
             .map_err(DrainerError::from)
             .into_report()?;
-        Ok(entries)
+
+        // Entries which were deleted after being delivered are returned without their fields
+        Ok(entries
+            .into_iter()
+            .map(|(stream, entries)| {
+                let entries = entries
+                    .into_iter()
+                    .map(|(entry_id, fields)| {
+                        let fields = fields
+                            .into_iter()
+                            .filter_map(|(field, value)| value.map(|value| (field, value)))
+                            .collect();
+                        (entry_id, fields)
+                    })
+                    .collect();
+                (stream, entries)
+            })
+            .collect())
     })
     .await;
 
@@ -54,53 +253,69 @@ pub async fn read_from_stream(
     output
 }
 
-pub async fn trim_from_stream(
+/// Acknowledge the entries and remove them from the stream, as the consumer group is the only
+/// reader of the stream
+pub async fn acknowledge_entries(
     stream_name: &str,
-    minimum_entry_id: &str,
-    redis: &redis::RedisConnectionPool,
+    entry_ids: Vec<String>,
+    store: &services::Store,
 ) -> errors::DrainerResult<usize> {
-    let trim_kind = redis::StreamCapKind::MinID;
-    let trim_type = redis::StreamCapTrim::Exact;
-    let trim_id = minimum_entry_id;
-    let (trim_result, execution_time) =
+    let (ack_result, execution_time) =
         common_utils::date_time::time_it::<errors::DrainerResult<_>, _, _>(|| async {
-            let trim_result = redis
-                .stream_trim_entries(stream_name, (trim_kind, trim_type, trim_id))
+            let acknowledged_count = store
+                .redis_conn
+                .stream_acknowledge_entries(
+                    stream_name,
+                    &store.config.consumer_group,
+                    entry_ids.clone(),
+                )
                 .await
                 .map_err(DrainerError::from)
                 .into_report()?;
 
-            // Since xtrim deletes entries below given id excluding the given id.
-            // Hence, deleting the minimum entry id
-            redis
-                .stream_delete_entries(stream_name, minimum_entry_id)
+            store
+                .redis_conn
+                .stream_delete_entries(stream_name, entry_ids.clone())
                 .await
                 .map_err(DrainerError::from)
                 .into_report()?;
 
-            Ok(trim_result)
+            Ok(acknowledged_count)
         })
         .await;
 
-    metrics::REDIS_STREAM_TRIM_TIME.record(
+    metrics::REDIS_STREAM_ACK_TIME.record(
         &metrics::CONTEXT,
         execution_time,
         &[metrics::KeyValue::new("stream", stream_name.to_owned())],
     );
 
-    // adding 1 because we are deleting the given id too
-    Ok(trim_result? + 1)
+    ack_result
 }
 
-pub async fn make_stream_available(
-    stream_name_flag: &str,
-    redis: &redis::RedisConnectionPool,
-) -> errors::DrainerResult<()> {
-    redis
-        .delete_key(stream_name_flag)
-        .await
-        .map_err(DrainerError::from)
-        .into_report()
+/// The serialized database operation of the stream entry, along with the version assigned to the
+/// operation when the entry was appended. Operations which cannot be deserialized are returned as
+/// is, so that they fail to be applied and are moved to the dead letter stream.
+pub fn get_typed_sql(fields: &HashMap<String, String>) -> String {
+    let typed_sql = fields
+        .get(kv::TYPED_SQL_ENTRY_FIELD)
+        .map_or(String::new(), Clone::clone);
+    let version = match fields
+        .get(kv::VERSION_ENTRY_FIELD)
+        .and_then(|version| version.parse::<i64>().ok())
+    {
+        Some(version) => version,
+        None => return typed_sql,
+    };
+
+    serde_json::from_str::<kv::TypedSql>(&typed_sql)
+        .and_then(|operation| {
+            serde_json::to_string(&kv::TypedSql {
+                version: Some(version),
+                ..operation
+            })
+        })
+        .unwrap_or(typed_sql)
 }
 
 pub fn parse_stream_entries<'a>(
@@ -141,8 +356,16 @@ pub async fn increment_stream_index(
     }
 }
 
-pub(crate) fn get_stream_key_flag(store: Arc<services::Store>, stream_index: u8) -> String {
-    format!("{}_in_use", get_drainer_stream_name(store, stream_index))
+pub(crate) fn get_dead_letter_stream_key_flag(
+    store: &services::Store,
+    stream_name: &str,
+) -> String {
+    format!("{}_in_use", store.dead_letter_stream(stream_name))
+}
+
+pub(crate) fn get_shard_lease_key(stream_name: &str) -> String {
+    // Example: {shard_5}_drainer_stream_lease
+    format!("{stream_name}_lease")
 }
 
 pub(crate) fn get_drainer_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
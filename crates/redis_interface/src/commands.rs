@@ -15,10 +15,10 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, PubsubInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
+        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse, XReadValue,
     },
 };
 use futures::StreamExt;
@@ -79,6 +79,23 @@ impl super::RedisConnectionPool {
         self.set_key(key, serialized.as_slice()).await
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn serialize_and_set_key_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: serde::Serialize + Debug,
+    {
+        let serialized = Encode::<V>::encode_to_vec(&value)
+            .change_context(errors::RedisError::JsonSerializationFailed)?;
+
+        self.set_key_with_expiry(key, serialized.as_slice(), seconds)
+            .await
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_key<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
     where
@@ -171,6 +188,96 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_if_not_exists_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug,
+        V::Error: Into<fred::error::RedisError>,
+    {
+        self.pool
+            .set(
+                key,
+                value,
+                Some(Expiration::EX(seconds)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
+    /// Delete the key only if it holds the given value, returning whether the key was deleted
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_key_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let script = r#"
+            if redis.call("GET", KEYS[1]) == ARGV[1] then
+                return redis.call("DEL", KEYS[1])
+            else
+                return 0
+            end
+        "#;
+        self.pool
+            .eval::<i64, _, _, _>(script, key, vec![value])
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteFailed)
+            .map(|deleted| deleted == 1)
+    }
+
+    /// Set the expiry of the key only if it holds the given value, returning whether the expiry
+    /// was set
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_expiry_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+        seconds: i64,
+    ) -> CustomResult<bool, errors::RedisError> {
+        let script = r#"
+            if redis.call("GET", KEYS[1]) == ARGV[1] then
+                return redis.call("EXPIRE", KEYS[1], ARGV[2])
+            else
+                return 0
+            end
+        "#;
+        self.pool
+            .eval::<i64, _, _, _>(script, key, vec![value.to_string(), seconds.to_string()])
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetExpiryFailed)
+            .map(|expiry_set| expiry_set == 1)
+    }
+
+    /// Run a Lua script on the keys, passing `args` to the script as `ARGV`. The keys must belong
+    /// to the same cluster slot.
+    #[instrument(level = "DEBUG", skip(self, script))]
+    pub async fn evaluate_script<V, K>(
+        &self,
+        script: &str,
+        keys: K,
+        args: Vec<String>,
+    ) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+        K: Into<MultipleKeys> + Debug + Send,
+    {
+        self.pool
+            .eval(script, keys, args)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ScriptEvaluationFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -333,6 +440,40 @@ impl super::RedisConnectionPool {
             .collect())
     }
 
+    /// Scan the keys matching the pattern, stopping once `limit` keys are found
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn scan(
+        &self,
+        pattern: &str,
+        count: Option<u32>,
+        limit: usize,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        Ok(self
+            .pool
+            .scan(pattern, count, None)
+            .filter_map(|value| async move {
+                match value {
+                    Ok(mut v) => {
+                        let v = v.take_results()?;
+
+                        let v: Vec<String> = v
+                            .iter()
+                            .filter_map(|key| key.as_str().map(ToString::to_string))
+                            .collect();
+                        Some(futures::stream::iter(v))
+                    }
+                    Err(err) => {
+                        logger::error!(?err);
+                        None
+                    }
+                }
+            })
+            .flatten()
+            .take(limit)
+            .collect::<Vec<_>>()
+            .await)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field<V>(
         &self,
@@ -349,6 +490,48 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+        increment: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .hincrby(key, field, increment)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementHashFieldFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_fields<F>(
+        &self,
+        key: &str,
+        fields: F,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        F: Into<MultipleKeys> + Debug + Send,
+    {
+        self.pool
+            .hdel(key, fields)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -498,6 +681,28 @@ impl super::RedisConnectionPool {
         .change_context(errors::RedisError::StreamReadFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range<K, S, E>(
+        &self,
+        stream: K,
+        start: S,
+        end: E,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError>
+    where
+        K: Into<RedisKey> + Debug,
+        S: TryInto<RedisValue> + Debug,
+        S::Error: Into<fred::error::RedisError>,
+        E: TryInto<RedisValue> + Debug,
+        E::Error: Into<fred::error::RedisError>,
+    {
+        self.pool
+            .xrange_values(stream, start, end, count)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     //                                              Consumer Group API
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -519,7 +724,14 @@ impl super::RedisConnectionPool {
             .xgroup_create(stream, group, id, true)
             .await
             .into_report()
-            .change_context(errors::RedisError::ConsumerGroupCreateFailed)
+            .map_err(|error| {
+                let context = if error.current_context().details().starts_with("BUSYGROUP") {
+                    errors::RedisError::ConsumerGroupExists
+                } else {
+                    errors::RedisError::ConsumerGroupCreateFailed
+                };
+                error.change_context(context)
+            })
     }
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -564,6 +776,23 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::ConsumerGroupSetIdFailed)
     }
 
+    // Returns the ID, consumer, idle time (in milliseconds) and delivery count of the pending
+    // entries which have been idle for at least `min_idle_time` milliseconds
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_get_idle_pending_entries(
+        &self,
+        stream: &str,
+        group: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<Vec<(String, String, u64, u64)>, errors::RedisError> {
+        self.pool
+            .xpending(stream, group, (min_idle_time, "-", "+", count))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ConsumerGroupGetPendingFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn consumer_group_set_message_owner<Ids, R>(
         &self,
@@ -594,6 +823,44 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    //                                              Pub/Sub API
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn subscribe(&self, channel: &str) -> CustomResult<(), errors::RedisError> {
+        self.subscriber
+            .subscribe(channel)
+            .await
+            .map(|_| ())
+            .into_report()
+            .change_context(errors::RedisError::SubscribeFailed)
+    }
+
+    // Returns the number of subscribers which received the message
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn publish<V>(
+        &self,
+        channel: &str,
+        message: V,
+    ) -> CustomResult<usize, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .publish(channel, message)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::PublishFailed)
+    }
+
+    /// The channel and the message of every message published on the subscribed channels. The
+    /// stream ends once the connections are closed.
+    pub fn on_message(&self) -> impl futures::Stream<Item = (String, Option<String>)> {
+        self.subscriber
+            .on_message()
+            .map(|(channel, message)| (channel, message.as_string()))
+    }
 }
 
 #[cfg(test)]
@@ -30,12 +30,16 @@ pub enum RedisError {
     StreamAcknowledgeFailed,
     #[error("Failed to create Redis consumer group")]
     ConsumerGroupCreateFailed,
+    #[error("Redis consumer group already exists")]
+    ConsumerGroupExists,
     #[error("Failed to destroy Redis consumer group")]
     ConsumerGroupDestroyFailed,
     #[error("Failed to delete consumer from consumer group")]
     ConsumerGroupRemoveConsumerFailed,
     #[error("Failed to set last ID on consumer group")]
     ConsumerGroupSetIdFailed,
+    #[error("Failed to get pending entries of Redis consumer group")]
+    ConsumerGroupGetPendingFailed,
     #[error("Failed to set Redis stream message owner")]
     ConsumerGroupClaimFailed,
     #[error("Failed to serialize application type to JSON")]
@@ -48,10 +52,20 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to increment hash field in Redis")]
+    IncrementHashFieldFailed,
+    #[error("Failed to delete hash fields in Redis")]
+    DeleteHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
     InvalidRedisEntryId,
     #[error("Failed to establish Redis connection")]
     RedisConnectionError,
+    #[error("Failed to subscribe to Redis channel")]
+    SubscribeFailed,
+    #[error("Failed to publish message on Redis channel")]
+    PublishFailed,
+    #[error("Failed to evaluate Lua script in Redis")]
+    ScriptEvaluationFailed,
 }
@@ -29,6 +29,9 @@ pub use self::{commands::*, types::*};
 
 pub struct RedisConnectionPool {
     pub pool: fred::pool::RedisPool,
+    /// Connection dedicated to the subscriptions, as a subscribed connection cannot run other
+    /// commands
+    pub subscriber: fred::clients::RedisClient,
     config: RedisConfig,
     join_handles: Vec<fred::types::ConnectHandle>,
 }
@@ -66,20 +69,29 @@ impl RedisConnectionPool {
             conf.reconnect_max_attempts,
             conf.reconnect_delay,
         );
+        let subscriber = fred::clients::RedisClient::new(config.clone());
         let pool = fred::pool::RedisPool::new(config, conf.pool_size)
             .into_report()
             .change_context(errors::RedisError::RedisConnectionError)?;
 
-        let join_handles = pool.connect(Some(policy));
+        let mut join_handles = pool.connect(Some(policy.clone()));
         pool.wait_for_connect()
             .await
             .into_report()
             .change_context(errors::RedisError::RedisConnectionError)?;
 
+        join_handles.push(subscriber.connect(Some(policy)));
+        subscriber
+            .wait_for_connect()
+            .await
+            .into_report()
+            .change_context(errors::RedisError::RedisConnectionError)?;
+
         let config = RedisConfig::from(conf);
 
         Ok(Self {
             pool,
+            subscriber,
             config,
             join_handles,
         })
@@ -87,6 +99,9 @@ impl RedisConnectionPool {
 
     pub async fn close_connections(&mut self) {
         self.pool.quit_pool().await;
+        if let Err(error) = fred::interfaces::ClientLike::quit(&self.subscriber).await {
+            logger::error!(%error);
+        }
         for handle in self.join_handles.drain(..) {
             match handle.await {
                 Ok(Ok(_)) => (),
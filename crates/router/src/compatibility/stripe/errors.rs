@@ -52,6 +52,15 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::ApiError, code = "refund_failed", message = "refund has failed")]
     RefundFailed, // stripe error code
 
+    #[error(error_type = StripeErrorType::ApiError, code = "payout_failed", message = "payout has failed")]
+    PayoutFailed { data: Option<serde_json::Value> },
+
+    #[error(error_type = StripeErrorType::ApiError, code = "dispute_failure", message = "Dispute operation has failed")]
+    DisputeFailed { data: Option<serde_json::Value> },
+
+    #[error(error_type = StripeErrorType::ApiError, code = "file_upload_failure", message = "File upload has failed")]
+    FileUploadFailed { data: Option<serde_json::Value> },
+
     #[error(error_type = StripeErrorType::ApiError, code = "internal_server_error", message = "Server is down")]
     InternalServerError,
 
@@ -97,6 +106,21 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such API key")]
     ApiKeyNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such dispute")]
+    DisputeNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such file")]
+    FileNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "File is not available")]
+    FileNotAvailable,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "file_validation_failed", message = "File validation failed")]
+    FileValidationFailed { reason: String },
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -168,6 +192,14 @@ pub enum StripeErrorCode {
     PaymentIntentMandateInvalid { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "lock_timeout", message = "This object cannot be accessed right now because another API request is currently accessing it.")]
+    LockTimeout,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with.")]
+    IdempotencyError,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly. We recommend an exponential backoff of your requests.")]
+    RateLimit,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -238,7 +270,6 @@ pub enum StripeErrorCode {
         InvoicePaymentIntentRequiresAction,
         InvoiceUpcomingNone,
         LivemodeMismatch,
-        LockTimeout,
         Missing,
         NoAccount,
         NotAllowedOnStandardAccount,
@@ -277,7 +308,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -367,6 +397,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
+            errors::ApiErrorResponse::PayoutFailed { data } => Self::PayoutFailed { data },
+            errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed { data },
+            errors::ApiErrorResponse::FileUploadFailed { data } => Self::FileUploadFailed { data },
 
             errors::ApiErrorResponse::InternalServerError => Self::InternalServerError, // not a stripe code
             errors::ApiErrorResponse::ExternalConnectorError { .. } => Self::InternalServerError,
@@ -387,6 +420,13 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::DisputeNotFound => Self::DisputeNotFound,
+            errors::ApiErrorResponse::FileNotFound => Self::FileNotFound,
+            errors::ApiErrorResponse::FileNotAvailable => Self::FileNotAvailable,
+            errors::ApiErrorResponse::FileValidationFailed { reason } => {
+                Self::FileValidationFailed { reason }
+            }
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -429,6 +469,12 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
+            errors::ApiErrorResponse::ResourceBusy => Self::LockTimeout,
+            errors::ApiErrorResponse::IdempotencyKeyConflict => Self::IdempotencyError,
+            errors::ApiErrorResponse::TooManyRequests => Self::RateLimit,
         }
     }
 }
@@ -458,6 +504,11 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound
+            | Self::FileNotFound
+            | Self::FileNotAvailable
+            | Self::FileValidationFailed { .. }
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
@@ -474,12 +525,19 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ResourceIdNotFound
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
-            | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST,
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. }
+            | Self::IdempotencyError => StatusCode::BAD_REQUEST,
             Self::RefundFailed
+            | Self::PayoutFailed { .. }
+            | Self::DisputeFailed { .. }
+            | Self::FileUploadFailed { .. }
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::LockTimeout => StatusCode::CONFLICT,
+            Self::RateLimit => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
@@ -218,7 +218,9 @@ impl From<api_enums::IntentStatus> for StripePaymentStatus {
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
             api_enums::IntentStatus::RequiresPaymentMethod => Self::RequiresPaymentMethod,
             api_enums::IntentStatus::RequiresConfirmation => Self::RequiresConfirmation,
-            api_enums::IntentStatus::RequiresCapture => Self::RequiresCapture,
+            // Stripe keeps payments which can be captured further as requiring capture
+            api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCaptured => Self::RequiresCapture,
             api_enums::IntentStatus::Cancelled => Self::Canceled,
         }
     }
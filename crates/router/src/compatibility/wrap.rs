@@ -69,6 +69,9 @@ where
         Ok(api::ApplicationResponse::Form(form_data)) => api::build_redirection_form(&form_data)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
+            api::http_response_file_data(file_data, content_type)
+        }
         Err(error) => {
             logger::error!(api_response_error=?error);
             let pg_error = E::from(error.current_context().clone());
@@ -63,6 +63,76 @@ impl Default for super::settings::SupportedConnectors {
     }
 }
 
+impl Default for super::settings::CacheSettings {
+    fn default() -> Self {
+        Self {
+            max_capacity: 10000,
+            ttl: 300,          // in seconds
+            redis_ttl: 1800,   // in seconds
+            not_found_ttl: 30, // in seconds
+        }
+    }
+}
+
+impl Default for super::settings::LockSettings {
+    fn default() -> Self {
+        Self {
+            ttl: 10,             // in seconds
+            retry_interval: 100, // in milliseconds
+            max_attempts: 50,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            ttl: 86400,           // in seconds
+            in_progress_ttl: 300, // in seconds
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            merchant: super::settings::TokenBucketSettings {
+                capacity: 200,
+                refill_rate: 100,
+            },
+            api_key: super::settings::TokenBucketSettings {
+                capacity: 100,
+                refill_rate: 50,
+            },
+        }
+    }
+}
+
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_enabled: false,
+            max_delivery_attempts: 10,
+            retry_base_delay_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::FileUploadConfig {
+    fn default() -> Self {
+        Self {
+            storage_backend: super::settings::FileStorageBackend::default(),
+            file_system_path: "files".into(),
+            #[cfg(feature = "s3")]
+            bucket_name: String::new(),
+            #[cfg(feature = "s3")]
+            region: String::new(),
+            max_file_size: 5 * 1024 * 1024, // Uploaded files are limited to 5MiB
+        }
+    }
+}
+
 impl Default for super::settings::Refund {
     fn default() -> Self {
         Self {
@@ -40,6 +40,10 @@ pub struct Settings {
     #[cfg(feature = "olap")]
     pub replica_database: Database,
     pub redis: RedisSettings,
+    pub cache: CacheSettings,
+    pub lock_settings: LockSettings,
+    pub idempotency: IdempotencySettings,
+    pub rate_limit: RateLimitSettings,
     pub log: Log,
     pub secrets: Secrets,
     pub locker: Locker,
@@ -51,6 +55,7 @@ pub struct Settings {
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
     pub webhooks: WebhooksSettings,
+    pub file_upload_config: FileUploadConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -135,7 +140,7 @@ pub struct SupportedConnectors {
 #[serde(default)]
 pub struct Connectors {
     pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
+    pub adyen: ConnectorParamsWithSecondaryBaseUrl,
     pub applepay: ConnectorParams,
     pub authorizedotnet: ConnectorParams,
     pub braintree: ConnectorParams,
@@ -147,7 +152,7 @@ pub struct Connectors {
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
     pub shift4: ConnectorParams,
-    pub stripe: ConnectorParams,
+    pub stripe: ConnectorParamsWithSecondaryBaseUrl,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
     pub square: ConnectorParams,
@@ -162,6 +167,13 @@ pub struct ConnectorParams {
     pub base_url: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithSecondaryBaseUrl {
+    pub base_url: String,
+    pub secondary_base_url: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -199,10 +211,93 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// Maximum number of values held in the in-memory cache of each application instance
+    pub max_capacity: u64,
+    /// Time after which values are evicted from the in-memory cache, in seconds. Redacted values
+    /// are evicted from every instance right away.
+    pub ttl: u64,
+    /// Time after which values expire from the Redis cache, in seconds
+    pub redis_ttl: u32,
+    /// Time for which keys not found in the database are cached as such, in seconds
+    pub not_found_ttl: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LockSettings {
+    /// Time after which a lock expires unless its holder is still running, in seconds
+    pub ttl: i64,
+    /// Delay between attempts to acquire a lock held by another request, in milliseconds
+    pub retry_interval: u64,
+    /// Number of attempts to acquire a lock before the request is rejected
+    pub max_attempts: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Time for which the responses of requests carrying an idempotency key are replayed to their
+    /// retries, in seconds
+    pub ttl: i64,
+    /// Time after which a request carrying an idempotency key is no longer considered in progress,
+    /// in case the instance running it stopped before storing its response, in seconds
+    pub in_progress_ttl: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Limit on the requests of each merchant, unless the merchant has a rate limit configured
+    pub merchant: TokenBucketSettings,
+    /// Limit on the requests made with each API key, unless the merchant has a rate limit
+    /// configured
+    pub api_key: TokenBucketSettings,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TokenBucketSettings {
+    /// Number of requests which can be made in a burst
+    pub capacity: u32,
+    /// Number of requests which can be made every second once the burst is spent
+    pub refill_rate: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
+    /// Maximum number of times an outgoing webhook is sent before giving up on its delivery
+    pub max_delivery_attempts: i16,
+    /// Delay before the first retry of a failed outgoing webhook, in seconds. The delay doubles
+    /// with every failed retry.
+    pub retry_base_delay_in_secs: i32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileUploadConfig {
+    pub storage_backend: FileStorageBackend,
+    /// Directory in which uploaded files are stored when using the file system backend
+    pub file_system_path: String,
+    #[cfg(feature = "s3")]
+    pub bucket_name: String,
+    #[cfg(feature = "s3")]
+    pub region: String,
+    /// Maximum size of an uploaded file, in bytes
+    pub max_file_size: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStorageBackend {
+    #[default]
+    FileSystem,
+    #[cfg(feature = "s3")]
+    AwsS3,
 }
 
 impl Settings {
@@ -268,6 +363,10 @@ impl Settings {
                 ));
             }
         }
+        self.cache.validate()?;
+        self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
         self.secrets.validate()?;
         self.locker.validate()?;
         self.connectors.validate()?;
@@ -279,6 +378,7 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        self.file_upload_config.validate()?;
 
         Ok(())
     }
@@ -20,6 +20,73 @@ impl super::settings::Secrets {
     }
 }
 
+impl super::settings::CacheSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl == 0 || self.redis_ttl == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cache TTLs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.not_found_ttl == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cache TTL of keys not found must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::LockSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "lock TTL must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "lock acquisition attempts must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl <= 0 || self.in_progress_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency TTLs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.merchant.validate()?;
+        self.api_key.validate()
+    }
+}
+
+impl super::settings::TokenBucketSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.capacity == 0 || self.refill_rate == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit capacity and refill rate must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -61,6 +128,43 @@ impl super::settings::Jwekey {
     }
 }
 
+impl super::settings::FileUploadConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_file_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum file size must be greater than zero".into(),
+            ))
+        })?;
+
+        match self.storage_backend {
+            super::settings::FileStorageBackend::FileSystem => {
+                when(self.file_system_path.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "file system path must not be empty when using the file system backend"
+                            .into(),
+                    ))
+                })
+            }
+            #[cfg(feature = "s3")]
+            super::settings::FileStorageBackend::AwsS3 => {
+                when(self.bucket_name.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "S3 bucket name must not be empty when using the S3 backend".into(),
+                    ))
+                })?;
+
+                when(self.region.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "S3 region must not be empty when using the S3 backend".into(),
+                    ))
+                })
+            }
+        }
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
@@ -142,6 +246,24 @@ impl super::settings::ConnectorParams {
     }
 }
 
+impl super::settings::ConnectorParamsWithSecondaryBaseUrl {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.secondary_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector secondary base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
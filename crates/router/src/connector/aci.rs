@@ -526,6 +526,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::PaymentIncrementalAuthorization for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Aci {}
+impl api::PayoutCreate for Aci {}
+impl api::PayoutFulfill for Aci {}
+impl api::PayoutCancel for Aci {}
+impl api::PayoutReverse for Aci {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Aci {}
+impl api::AcceptDispute for Aci {}
+impl api::SubmitEvidence for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Aci {}
+impl api::UploadFile for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Aci {
     fn get_webhook_object_reference_id(
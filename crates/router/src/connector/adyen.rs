@@ -45,6 +45,12 @@ impl ConnectorCommon for Adyen {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.adyen.base_url.as_ref()
     }
+
+    fn is_retryable_error_code(&self, error_code: &str) -> bool {
+        // Refusal reason codes for "Acquirer Error" and "Issuer Unavailable"
+        // Reference: https://docs.adyen.com/development-resources/refusal-reasons
+        matches!(error_code, "4" | "9")
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -52,6 +58,7 @@ impl api::PaymentAuthorize for Adyen {}
 impl api::PaymentSync for Adyen {}
 impl api::PaymentVoid for Adyen {}
 impl api::PaymentCapture for Adyen {}
+impl api::PaymentIncrementalAuthorization for Adyen {}
 impl api::PreVerify for Adyen {}
 impl api::ConnectorAccessToken for Adyen {}
 
@@ -184,6 +191,108 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}{}/{}/amountUpdates",
+            self.base_url(connectors),
+            "v68/payments",
+            id
+        ))
+    }
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenIncrementalAuthorizationRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenIncrementalAuthorizationRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: adyen::AdyenIncrementalAuthorizationResponse = res
+            .response
+            .parse_struct("AdyenIncrementalAuthorizationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Adyen
@@ -637,6 +746,305 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payouts for Adyen {}
+impl api::PayoutCreate for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutCancel for Adyen {}
+impl api::PayoutReverse for Adyen {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutCreateType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
+            connectors.adyen.secondary_base_url,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCreateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCreateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutFulfillType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/confirmThirdParty",
+            connectors.adyen.secondary_base_url,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutReviewRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutReviewRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutCancelType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/declineThirdParty",
+            connectors.adyen.secondary_base_url,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutReviewRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutReviewRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCancelType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    // Not Implemented (R)
+}
+
 fn get_webhook_object_from_body(
     body: &[u8],
 ) -> CustomResult<adyen::AdyenNotificationRequestItemWH, errors::ParsingError> {
@@ -652,6 +1060,43 @@ fn get_webhook_object_from_body(
     Ok(item_object.notification_request_item)
 }
 
+impl api::Dispute for Adyen {}
+impl api::AcceptDispute for Adyen {}
+impl api::SubmitEvidence for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Adyen {}
+impl api::UploadFile for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Adyen
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Adyen {
     fn get_webhook_source_verification_algorithm(
@@ -738,6 +1183,13 @@ impl api::IncomingWebhook for Adyen {
 
         Ok(match notif.event_code.as_str() {
             "AUTHORISATION" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            // Reports whether the issuer approved an incremental authorization
+            "AUTHORISATION_ADJUSTMENT" if notif.success == "true" => {
+                api::IncomingWebhookEvent::IncrementalAuthorizationSuccess
+            }
+            "AUTHORISATION_ADJUSTMENT" => {
+                api::IncomingWebhookEvent::IncrementalAuthorizationFailure
+            }
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
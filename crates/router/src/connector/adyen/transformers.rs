@@ -91,6 +91,8 @@ pub struct AdyenPaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     recurring_processing_model: Option<AdyenRecurringModel>,
     additional_data: Option<AdditionalData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpi_data: Option<AdyenMpiData>,
     shopper_name: Option<ShopperName>,
     shopper_email: Option<Secret<String, Email>>,
     telephone_number: Option<Secret<String>>,
@@ -100,6 +102,19 @@ pub struct AdyenPaymentRequest {
     line_items: Option<Vec<LineItem>>,
 }
 
+/// 3DS authentication performed by the merchant outside of Adyen
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenMpiData {
+    cavv: Option<Secret<String>>,
+    eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: Option<String>,
+    authentication_response: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct AdyenBrowserInfo {
@@ -119,6 +134,10 @@ pub enum AdyenStatus {
     Refused,
     Cancelled,
     RedirectShopper,
+    // The shopper completes payments with the below statuses outside of the checkout, such as by
+    // scanning a QR code with their banking app
+    Pending,
+    PresentToShopper,
 }
 
 impl From<AdyenStatus> for storage_enums::AttemptStatus {
@@ -127,7 +146,9 @@ impl From<AdyenStatus> for storage_enums::AttemptStatus {
             AdyenStatus::Authorised => Self::Charged,
             AdyenStatus::Refused => Self::Failure,
             AdyenStatus::Cancelled => Self::Voided,
-            AdyenStatus::RedirectShopper => Self::AuthenticationPending,
+            AdyenStatus::RedirectShopper | AdyenStatus::Pending | AdyenStatus::PresentToShopper => {
+                Self::AuthenticationPending
+            }
         }
     }
 }
@@ -167,6 +188,7 @@ pub struct AdyenThreeDS {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum AdyenPaymentResponse {
+    AdyenNextActionResponse(AdyenNextActionResponse),
     AdyenResponse(AdyenResponse),
     AdyenRedirectResponse(AdyenRedirectionResponse),
 }
@@ -180,6 +202,36 @@ pub struct AdyenResponse {
     merchant_reference: String,
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
+    additional_data: Option<AdyenResponseAdditionalData>,
+}
+
+/// The 3DS fields of the additional data, which Adyen returns as strings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenResponseAdditionalData {
+    liability_shift: Option<String>,
+    three_d_authenticated: Option<String>,
+    cavv: Option<Secret<String>>,
+    eci: Option<String>,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+}
+
+impl AdyenResponseAdditionalData {
+    fn get_authentication_details(self) -> Option<api::AuthenticationDetails> {
+        if self.three_d_authenticated.as_deref() != Some("true") {
+            return None;
+        }
+        Some(api::AuthenticationDetails {
+            version: self.three_ds_version,
+            eci: self.eci,
+            cavv: self.cavv,
+            ds_transaction_id: self.ds_trans_id,
+            liability_shift: self.liability_shift.map(|shifted| shifted == "true"),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -202,6 +254,56 @@ pub struct AdyenRedirectionAction {
     data: Option<HashMap<String, String>>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNextActionResponse {
+    result_code: AdyenStatus,
+    psp_reference: Option<String>,
+    action: AdyenNextAction,
+    refusal_reason: Option<String>,
+    refusal_reason_code: Option<String>,
+}
+
+/// Action the shopper takes to complete the payment other than being redirected
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdyenNextAction {
+    /// A QR code the shopper scans with their banking or wallet app, such as for PIX, PayNow or
+    /// UPI payments
+    #[serde(rename_all = "camelCase")]
+    QrCode {
+        payment_method_type: String,
+        qr_code_data: String,
+    },
+    /// The data the SDK of a wallet is invoked with, for the shopper to pay in the wallet app
+    #[serde(rename_all = "camelCase")]
+    Sdk {
+        payment_method_type: String,
+        sdk_data: serde_json::Value,
+    },
+}
+
+impl From<AdyenNextAction> for api::NextActionData {
+    fn from(action: AdyenNextAction) -> Self {
+        match action {
+            AdyenNextAction::QrCode { qr_code_data, .. } => {
+                Self::DisplayQrCode(api::QrCodeInformation {
+                    image_data_url: None,
+                    qr_code_data: Some(qr_code_data),
+                    expires_at: None,
+                })
+            }
+            AdyenNextAction::Sdk {
+                payment_method_type,
+                sdk_data,
+            } => Self::InvokeSdkClient(api::SdkClientInformation {
+                sdk_name: payment_method_type,
+                sdk_parameters: sdk_data,
+            }),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Amount {
     currency: String,
@@ -399,6 +501,20 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
     }
 }
 
+fn get_mpi_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenMpiData> {
+    item.request
+        .external_authentication_details
+        .clone()
+        .map(|details| AdyenMpiData {
+            // A cryptogram is only issued for a successful authentication
+            authentication_response: details.cavv.as_ref().map(|_| "Y".to_string()),
+            cavv: details.cavv,
+            eci: details.eci,
+            ds_trans_id: details.ds_transaction_id,
+            three_ds_version: details.version,
+        })
+}
+
 fn get_amount_data(item: &types::PaymentsAuthorizeRouterData) -> Amount {
     Amount {
         currency: item.request.currency.to_string(),
@@ -549,6 +665,7 @@ fn get_card_specific_payment_data(
     let recurring_processing_model = get_recurring_processing_model(item);
     let browser_info = get_browser_info(item);
     let additional_data = get_additional_data(item);
+    let mpi_data = get_mpi_data(item);
     let return_url = item.get_return_url()?;
     let payment_method = get_payment_method_data(item)?;
     Ok(AdyenPaymentRequest {
@@ -561,6 +678,7 @@ fn get_card_specific_payment_data(
         recurring_processing_model,
         browser_info,
         additional_data,
+        mpi_data,
         telephone_number: None,
         shopper_name: None,
         shopper_email: None,
@@ -592,6 +710,7 @@ fn get_wallet_specific_payment_data(
         recurring_processing_model,
         browser_info,
         additional_data,
+        mpi_data: None,
         telephone_number: None,
         shopper_name: None,
         shopper_email: None,
@@ -630,6 +749,7 @@ fn get_paylater_specific_payment_data(
         recurring_processing_model,
         browser_info,
         additional_data,
+        mpi_data: None,
         telephone_number,
         shopper_name,
         shopper_email,
@@ -675,6 +795,8 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<AdyenCancelResponse>>
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             ..item.data
         })
@@ -719,12 +841,18 @@ pub fn get_adyen_response(
         None
     };
 
+    let authentication_details = response
+        .additional_data
+        .and_then(AdyenResponseAdditionalData::get_authentication_details);
+
     let payments_response_data = types::PaymentsResponseData::TransactionResponse {
         resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
         redirection_data: None,
         redirect: false,
         mandate_reference: None,
         connector_metadata: None,
+        authentication_details,
+        next_action_data: None,
     };
     Ok((status, error, payments_response_data))
 }
@@ -786,6 +914,52 @@ pub fn get_redirection_response(
         redirect: true,
         mandate_reference: None,
         connector_metadata: None,
+        authentication_details: None,
+        next_action_data: None,
+    };
+    Ok((status, error, payments_response_data))
+}
+
+pub fn get_next_action_response(
+    response: AdyenNextActionResponse,
+    status_code: u16,
+) -> errors::CustomResult<
+    (
+        storage_enums::AttemptStatus,
+        Option<types::ErrorResponse>,
+        types::PaymentsResponseData,
+    ),
+    errors::ConnectorError,
+> {
+    let status = response.result_code.into();
+
+    let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
+        Some(types::ErrorResponse {
+            code: response
+                .refusal_reason_code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .refusal_reason
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+            status_code,
+        })
+    } else {
+        None
+    };
+
+    let payments_response_data = types::PaymentsResponseData::TransactionResponse {
+        resource_id: response
+            .psp_reference
+            .map_or(types::ResponseId::NoResponseId, |psp_reference| {
+                types::ResponseId::ConnectorTransactionId(psp_reference)
+            }),
+        redirection_data: None,
+        redirect: false,
+        mandate_reference: None,
+        connector_metadata: None,
+        authentication_details: None,
+        next_action_data: Some(response.action.into()),
     };
     Ok((status, error, payments_response_data))
 }
@@ -806,6 +980,9 @@ impl<F, Req>
         let item = items.0;
         let is_manual_capture = items.1;
         let (status, error, payment_response_data) = match item.response {
+            AdyenPaymentResponse::AdyenNextActionResponse(response) => {
+                get_next_action_response(response, item.http_code)?
+            }
             AdyenPaymentResponse::AdyenResponse(response) => {
                 get_adyen_response(response, is_manual_capture, item.http_code)?
             }
@@ -880,6 +1057,8 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             amount_captured,
             ..item.data
@@ -887,6 +1066,80 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationRequest {
+    merchant_account: String,
+    /// The total amount to be authorized, including the amount authorized so far
+    amount: Amount,
+    reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for AdyenIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.total_amount,
+            },
+            reference: item.request.authorization_id.clone(),
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+    amount: Amount,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Adyen only acknowledges the request, the outcome of the incremental authorization is
+        // reported by the AUTHORISATION_ADJUSTMENT webhook
+        let status = match item.response.status.as_str() {
+            "received" => storage_enums::AttemptStatus::Pending,
+            _ => storage_enums::AttemptStatus::AuthorizationFailed,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.psp_reference),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -1043,6 +1296,301 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
             }),
             refusal_reason: None,
             refusal_reason_code: None,
+            additional_data: None,
         }
     }
 }
+
+// Payouts Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    #[serde(flatten)]
+    payout_method: AdyenPayoutMethod,
+    merchant_account: String,
+    recurring: AdyenRecurring,
+    reference: String,
+    shopper_email: Option<Secret<String, Email>>,
+    shopper_reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdyenPayoutMethod {
+    Bank(AdyenBankPayout),
+    Card(AdyenCardPayout),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBankPayout {
+    iban: Secret<String>,
+    owner_name: Secret<String>,
+    country_code: String,
+    bank_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCardPayout {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenRecurring {
+    contract: AdyenRecurringContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenRecurringContract {
+    Payout,
+}
+
+// Used for both confirming and declining a payout stored at Adyen
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutReviewRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    result_code: Option<String>,
+    response: Option<String>,
+    refusal_reason: Option<String>,
+}
+
+// Payout Request Transform
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method = match item.request.payout_method_data.clone() {
+            Some(api::PayoutMethodData::Bank(bank)) => AdyenPayoutMethod::Bank(AdyenBankPayout {
+                iban: bank.iban,
+                owner_name: bank.bank_account_holder_name,
+                country_code: bank.bank_country_code,
+                bank_name: bank.bank_name,
+            }),
+            Some(api::PayoutMethodData::Card(card)) => AdyenPayoutMethod::Card(AdyenCardPayout {
+                number: card.card_number,
+                expiry_month: card.card_exp_month,
+                expiry_year: card.card_exp_year,
+                holder_name: card.card_holder_name,
+            }),
+            Some(api::PayoutMethodData::ConnectedAccount(_)) => Err(
+                errors::ConnectorError::NotImplemented("Connected account payouts".to_string()),
+            )?,
+            None => Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            })?,
+        };
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            payout_method,
+            merchant_account: auth_type.merchant_account,
+            recurring: AdyenRecurring {
+                contract: AdyenRecurringContract::Payout,
+            },
+            reference: item.request.payout_id.clone(),
+            shopper_email: item.request.email.clone(),
+            // Adyen stores the payout details against a shopper, fall back to the payout for
+            // payouts which are not associated with a customer
+            shopper_reference: item
+                .request
+                .customer_id
+                .clone()
+                .unwrap_or_else(|| item.request.payout_id.clone()),
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutReviewRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item.request.connector_payout_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "connector_payout_id",
+                },
+            )?,
+        })
+    }
+}
+
+// Payout Response Transform
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        if let Some(refusal_reason) = response.refusal_reason {
+            return Ok(Self {
+                response: Err(types::ErrorResponse {
+                    code: response
+                        .result_code
+                        .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                    message: refusal_reason.clone(),
+                    reason: Some(refusal_reason),
+                    status_code: item.http_code,
+                }),
+                ..item.data
+            });
+        }
+        // Submitting a payout only stores it at Adyen, it has to be confirmed separately
+        let status = match response
+            .result_code
+            .as_deref()
+            .or(response.response.as_deref())
+        {
+            Some("[payout-submit-received]") => storage_enums::PayoutStatus::RequiresFulfillment,
+            Some("[payout-confirm-received]") => storage_enums::PayoutStatus::Success,
+            Some("[payout-decline-received]") => storage_enums::PayoutStatus::Cancelled,
+            _ => storage_enums::PayoutStatus::Pending,
+        };
+        // Confirming or declining a payout returns the reference of the modification, keep the
+        // reference of the original payout instead
+        let connector_payout_id = item
+            .data
+            .request
+            .connector_payout_id
+            .clone()
+            .unwrap_or(response.psp_reference);
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id,
+                status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    fn get_payments_response_data(response: serde_json::Value) -> types::PaymentsResponseData {
+        let response: AdyenPaymentResponse = serde_json::from_value(response).unwrap();
+        let data = types::RouterData::<(), (), types::PaymentsResponseData> {
+            flow: PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            connector: "adyen".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: Some("pay_1_1".to_string()),
+            status: storage_enums::AttemptStatus::Started,
+            payment_method: storage_enums::PaymentMethodType::Upi,
+            connector_auth_type: types::ConnectorAuthType::default(),
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: Default::default(),
+            auth_type: storage_enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: (),
+            response: Err(types::ErrorResponse::get_not_implemented()),
+            payment_method_id: None,
+        };
+        let router_data = types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data,
+                http_code: 200,
+            },
+            false,
+        ))
+        .unwrap();
+        assert_eq!(
+            router_data.status,
+            storage_enums::AttemptStatus::AuthenticationPending
+        );
+        router_data.response.unwrap()
+    }
+
+    #[test]
+    fn test_qr_code_next_action() {
+        let response = get_payments_response_data(serde_json::json!({
+            "resultCode": "Pending",
+            "pspReference": "QFQTPCQ8HXSKGK82",
+            "action": {
+                "paymentMethodType": "upi_qr",
+                "type": "qrCode",
+                "qrCodeData": "upi://pay?pa=merchant@bank&pn=Merchant&am=65.40&cu=INR"
+            }
+        }));
+        let (resource_id, next_action_data) = match response {
+            types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                next_action_data,
+                ..
+            } => (resource_id, next_action_data),
+            _ => panic!("expected a transaction response"),
+        };
+        assert!(matches!(
+            resource_id,
+            types::ResponseId::ConnectorTransactionId(id) if id == "QFQTPCQ8HXSKGK82"
+        ));
+        assert_eq!(
+            next_action_data,
+            Some(api::NextActionData::DisplayQrCode(api::QrCodeInformation {
+                image_data_url: None,
+                qr_code_data: Some(
+                    "upi://pay?pa=merchant@bank&pn=Merchant&am=65.40&cu=INR".to_string()
+                ),
+                expires_at: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_sdk_next_action() {
+        let response = get_payments_response_data(serde_json::json!({
+            "resultCode": "Pending",
+            "pspReference": "KHQC5N7G84BLNK43",
+            "action": {
+                "paymentMethodType": "wechatpaySDK",
+                "type": "sdk",
+                "sdkData": { "prepayid": "wx20180123123456", "noncestr": "lzTdBtULSE5B" }
+            }
+        }));
+        let next_action_data = match response {
+            types::PaymentsResponseData::TransactionResponse {
+                next_action_data, ..
+            } => next_action_data,
+            _ => panic!("expected a transaction response"),
+        };
+        assert_eq!(
+            next_action_data,
+            Some(api::NextActionData::InvokeSdkClient(
+                api::SdkClientInformation {
+                    sdk_name: "wechatpaySDK".to_string(),
+                    sdk_parameters: serde_json::json!({
+                        "prepayid": "wx20180123123456",
+                        "noncestr": "lzTdBtULSE5B"
+                    }),
+                }
+            ))
+        );
+    }
+}
@@ -238,6 +238,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 impl services::ConnectorRedirectResponse for Applepay {}
 
+impl api::PaymentIncrementalAuthorization for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Applepay {}
+impl api::PayoutCreate for Applepay {}
+impl api::PayoutFulfill for Applepay {}
+impl api::PayoutCancel for Applepay {}
+impl api::PayoutReverse for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Applepay {}
+impl api::AcceptDispute for Applepay {}
+impl api::SubmitEvidence for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Applepay {}
+impl api::UploadFile for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Applepay {
     fn get_webhook_object_reference_id(
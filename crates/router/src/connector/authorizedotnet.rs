@@ -568,6 +568,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Authorizedotnet {}
+impl api::PayoutCreate for Authorizedotnet {}
+impl api::PayoutFulfill for Authorizedotnet {}
+impl api::PayoutCancel for Authorizedotnet {}
+impl api::PayoutReverse for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Authorizedotnet {}
+impl api::AcceptDispute for Authorizedotnet {}
+impl api::SubmitEvidence for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Authorizedotnet {}
+impl api::UploadFile for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Authorizedotnet
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Authorizedotnet {
     fn get_webhook_object_reference_id(
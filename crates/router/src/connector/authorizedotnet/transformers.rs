@@ -264,6 +264,7 @@ pub struct TransactionResponse {
     transaction_id: String,
     pub(super) account_number: Option<String>,
     pub(super) errors: Option<Vec<ErrorMessage>>,
+    next_action_data: None,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -332,6 +333,8 @@ impl<F, T>
                     redirect: false,
                     mandate_reference: None,
                     connector_metadata: metadata,
+                    authentication_details: None,
+                    next_action_data: None,
                 }),
             },
             ..item.data
@@ -532,6 +535,7 @@ pub struct SyncTransactionResponse {
     #[serde(rename = "transId")]
     transaction_id: String,
     transaction_status: SyncStatus,
+    next_action_data: None,
 }
 
 #[derive(Debug, Deserialize)]
@@ -609,6 +613,8 @@ impl<F, Req>
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             status: payment_status,
             ..item.data
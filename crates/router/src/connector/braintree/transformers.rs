@@ -213,6 +213,8 @@ impl<F, T>
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             ..item.data
         })
@@ -269,6 +271,7 @@ pub struct TransactionResponse {
     currency_iso_code: String,
     amount: String,
     status: BraintreePaymentStatus,
+    next_action_data: None,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -51,6 +51,10 @@ impl ConnectorCommon for Checkout {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.checkout.base_url.as_ref()
     }
+
+    fn supports_multiple_captures(&self) -> bool {
+        true
+    }
 }
 
 impl api::Payment for Checkout {}
@@ -59,6 +63,7 @@ impl api::PaymentAuthorize for Checkout {}
 impl api::PaymentSync for Checkout {}
 impl api::PaymentVoid for Checkout {}
 impl api::PaymentCapture for Checkout {}
+impl api::PaymentIncrementalAuthorization for Checkout {}
 impl api::PaymentSession for Checkout {}
 impl api::ConnectorAccessToken for Checkout {}
 
@@ -199,6 +204,113 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Checkout
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}payments/{id}/authorizations",
+            self.base_url(connectors)
+        ))
+    }
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = checkout::PaymentIncrementalAuthorizationRequest::try_from(req)?;
+        let checkout_req = utils::Encode::<checkout::PaymentIncrementalAuthorizationRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(checkout_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: checkout::PaymentIncrementalAuthorizationResponse = res
+            .response
+            .parse_struct("PaymentIncrementalAuthorizationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: checkout::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error_codes
+                .unwrap_or_else(|| vec![consts::NO_ERROR_CODE.to_string()])
+                .join(" & "),
+            message: response
+                .error_type
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Checkout
@@ -225,14 +337,20 @@ impl
         req: &types::PaymentsSyncRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        let suffix = match req.request.sync_type {
+            // The captures of the payment are among the actions performed on it
+            types::SyncRequestType::MultipleCaptureSync(_) => "/actions",
+            types::SyncRequestType::SinglePaymentSync => "",
+        };
         Ok(format!(
-            "{}{}{}",
+            "{}{}{}{}",
             self.base_url(connectors),
             "payments/",
             req.request
                 .connector_transaction_id
                 .get_connector_transaction_id()
-                .change_context(errors::ConnectorError::MissingConnectorTransactionID)?
+                .change_context(errors::ConnectorError::MissingConnectorTransactionID)?,
+            suffix
         ))
     }
 
@@ -263,17 +381,33 @@ impl
         types::PaymentsResponseData: Clone,
     {
         logger::debug!(raw_response=?res);
-        let response: checkout::PaymentsResponse = res
-            .response
-            .parse_struct("PaymentsResponse")
-            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        logger::debug!(payment_sync_response=?response);
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
-        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+        match data.request.sync_type {
+            types::SyncRequestType::MultipleCaptureSync(_) => {
+                let response: Vec<checkout::ActionResponse> = res
+                    .response
+                    .parse_struct("checkout::ActionResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                types::RouterData::try_from(types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)
+            }
+            types::SyncRequestType::SinglePaymentSync => {
+                let response: checkout::PaymentsResponse = res
+                    .response
+                    .parse_struct("PaymentsResponse")
+                    .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+                logger::debug!(payment_sync_response=?response);
+                types::RouterData::try_from(types::ResponseRouterData {
+                    response,
+                    data: data.clone(),
+                    http_code: res.status_code,
+                })
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)
+            }
+        }
     }
 
     fn get_error_response(
@@ -717,6 +851,73 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payouts for Checkout {}
+impl api::PayoutCreate for Checkout {}
+impl api::PayoutFulfill for Checkout {}
+impl api::PayoutCancel for Checkout {}
+impl api::PayoutReverse for Checkout {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Checkout {}
+impl api::AcceptDispute for Checkout {}
+impl api::SubmitEvidence for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Checkout {}
+impl api::UploadFile for Checkout {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Checkout
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Checkout {
     fn get_webhook_object_reference_id(
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    consts,
     core::errors,
     pii, services,
     types::{
@@ -49,12 +50,25 @@ pub struct PaymentsRequest {
     #[serde(flatten)]
     pub return_url: ReturnUrl,
     pub capture: bool,
+    pub authorization_type: Option<CheckoutAuthorizationType>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum CheckoutAuthorizationType {
+    /// Allows the authorized amount to be increased later on
+    Estimated,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CheckoutThreeDS {
     enabled: bool,
     force_3ds: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eci: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cryptogram: Option<pii::Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -81,14 +95,32 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             | api::PaymentMethod::Paypal => None,
         };
 
-        let three_ds = match item.auth_type {
-            enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
+        let three_ds = match (
+            item.request.external_authentication_details.clone(),
+            item.auth_type,
+        ) {
+            // The customer has already been authenticated by the merchant, so the results of the
+            // authentication are passed along instead of authenticating the customer again
+            (Some(details), _) => CheckoutThreeDS {
+                enabled: true,
+                force_3ds: false,
+                eci: details.eci,
+                cryptogram: details.cavv,
+                version: details.version,
+            },
+            (None, enums::AuthenticationType::ThreeDs) => CheckoutThreeDS {
                 enabled: true,
                 force_3ds: true,
+                eci: None,
+                cryptogram: None,
+                version: None,
             },
-            enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
+            (None, enums::AuthenticationType::NoThreeDs) => CheckoutThreeDS {
                 enabled: false,
                 force_3ds: false,
+                eci: None,
+                cryptogram: None,
+                version: None,
             },
         };
 
@@ -107,6 +139,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             item.request.capture_method,
             Some(enums::CaptureMethod::Automatic)
         );
+        let authorization_type = if item.request.request_incremental_authorization && !capture {
+            Some(CheckoutAuthorizationType::Estimated)
+        } else {
+            None
+        };
 
         let source_var = Source::Card(CardSource {
             source_type: Some("card".to_owned()),
@@ -125,6 +162,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             three_ds,
             return_url,
             capture,
+            authorization_type,
         })
     }
 }
@@ -207,6 +245,33 @@ pub struct PaymentsResponse {
     #[serde(rename = "_links")]
     links: Links,
     balances: Balances,
+    eci: Option<String>,
+    #[serde(rename = "3ds")]
+    three_ds: Option<CheckoutThreeDSResponse>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct CheckoutThreeDSResponse {
+    version: Option<String>,
+    authentication_response: Option<String>,
+    cryptogram: Option<pii::Secret<String>>,
+}
+
+impl PaymentsResponse {
+    fn get_authentication_details(&self) -> Option<api::AuthenticationDetails> {
+        self.three_ds
+            .clone()
+            .map(|three_ds| api::AuthenticationDetails {
+                version: three_ds.version,
+                eci: self.eci.clone(),
+                cavv: three_ds.cryptogram,
+                ds_transaction_id: None,
+                // Fully authenticated and attempted authentications both shift the liability
+                liability_shift: three_ds
+                    .authentication_response
+                    .map(|response| matches!(response.as_str(), "Y" | "A")),
+            })
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
@@ -221,6 +286,7 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let authentication_details = item.response.get_authentication_details();
         let redirection_url = item
             .response
             .links
@@ -250,6 +316,8 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
                 redirection_data,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details,
+                next_action_data: None,
             }),
             ..item.data
         })
@@ -263,6 +331,7 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
     fn try_from(
         item: types::PaymentsSyncResponseRouterData<PaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let authentication_details = item.response.get_authentication_details();
         let redirection_url = item
             .response
             .links
@@ -293,6 +362,8 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
                 redirection_data,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details,
+                next_action_data: None,
             }),
             ..item.data
         })
@@ -336,6 +407,8 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<PaymentVoidResponse>>
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             status: response.into(),
             ..item.data
@@ -371,9 +444,14 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
+        let capture_type = if item.request.final_capture {
+            CaptureType::Final
+        } else {
+            CaptureType::NonFinal
+        };
         Ok(Self {
             amount: item.request.amount_to_capture,
-            capture_type: Some(CaptureType::Final),
+            capture_type: Some(capture_type),
             processing_channel_id,
         })
     }
@@ -401,13 +479,14 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
         };
         Ok(Self {
             response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.data.request.connector_transaction_id.to_owned(),
-                ),
+                // The action ID references the capture among the captures of the payment
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.action_id),
                 redirect: false,
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             status,
             amount_captured,
@@ -416,6 +495,86 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PaymentIncrementalAuthorizationRequest {
+    /// The amount to be added to the amount authorized so far
+    pub amount: i64,
+    pub reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for PaymentIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.additional_amount,
+            reference: item.request.authorization_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaymentIncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub approved: Option<bool>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<
+            PaymentIncrementalAuthorizationResponse,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            PaymentIncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = if item.response.approved.unwrap_or(false) {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                // The action ID references the authorization among the actions of the payment
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.action_id),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
+            })
+        } else {
+            Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: item
+                    .response
+                    .response_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .response_summary
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+            })
+        };
+        let status = if response.is_ok() {
+            enums::AttemptStatus::Authorized
+        } else {
+            enums::AttemptStatus::AuthorizationFailed
+        };
+        Ok(Self {
+            response,
+            status,
+            ..item.data
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     amount: Option<i64>,
@@ -533,6 +692,36 @@ impl From<&ActionResponse> for enums::RefundStatus {
     }
 }
 
+impl From<&ActionResponse> for enums::CaptureStatus {
+    fn from(item: &ActionResponse) -> Self {
+        match item.approved {
+            Some(true) => Self::Charged,
+            Some(false) => Self::Failed,
+            None => Self::Pending,
+        }
+    }
+}
+
+impl TryFrom<types::PaymentsSyncResponseRouterData<Vec<ActionResponse>>>
+    for types::PaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsSyncResponseRouterData<Vec<ActionResponse>>,
+    ) -> Result<Self, Self::Error> {
+        let captures = item
+            .response
+            .iter()
+            .filter(|action| matches!(action.action_type, ActionType::Capture))
+            .map(|action| (action.action_id.clone(), enums::CaptureStatus::from(action)))
+            .collect();
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::MultipleCaptureResponse { captures }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckoutRedirectResponseStatus {
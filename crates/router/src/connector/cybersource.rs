@@ -692,6 +692,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Cybersource {}
+impl api::PayoutCreate for Cybersource {}
+impl api::PayoutFulfill for Cybersource {}
+impl api::PayoutCancel for Cybersource {}
+impl api::PayoutReverse for Cybersource {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Cybersource {}
+impl api::AcceptDispute for Cybersource {}
+impl api::SubmitEvidence for Cybersource {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Cybersource {}
+impl api::UploadFile for Cybersource {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Cybersource
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Cybersource {
     fn get_webhook_object_reference_id(
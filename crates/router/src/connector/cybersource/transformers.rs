@@ -17,6 +17,18 @@ pub struct CybersourcePaymentsRequest {
     processing_information: ProcessingInformation,
     payment_information: PaymentInformation,
     order_information: OrderInformationWithBill,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consumer_authentication_information: Option<CybersourceConsumerAuthInformation>,
+}
+
+/// 3DS authentication performed by the merchant outside of Cybersource
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceConsumerAuthInformation {
+    cavv: Option<Secret<String>>,
+    eci_raw: Option<String>,
+    directory_server_transaction_id: Option<String>,
+    pa_specification_version: Option<String>,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -146,10 +158,22 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
                     capture_options: None,
                 };
 
+                let consumer_authentication_information = item
+                    .request
+                    .external_authentication_details
+                    .clone()
+                    .map(|details| CybersourceConsumerAuthInformation {
+                        cavv: details.cavv,
+                        eci_raw: details.eci,
+                        directory_server_transaction_id: details.ds_transaction_id,
+                        pa_specification_version: details.version,
+                    });
+
                 Ok(Self {
                     processing_information,
                     payment_information,
                     order_information,
+                    consumer_authentication_information,
                 })
             }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
@@ -275,6 +299,32 @@ pub struct CybersourcePaymentsResponse {
     id: String,
     status: CybersourcePaymentStatus,
     error_information: Option<CybersourceErrorInformation>,
+    consumer_authentication_information: Option<CybersourceConsumerAuthResponse>,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceConsumerAuthResponse {
+    cavv: Option<Secret<String>>,
+    eci_raw: Option<String>,
+    directory_server_transaction_id: Option<String>,
+    specification_version: Option<String>,
+    pares_status: Option<String>,
+}
+
+impl From<CybersourceConsumerAuthResponse> for api::AuthenticationDetails {
+    fn from(item: CybersourceConsumerAuthResponse) -> Self {
+        Self {
+            version: item.specification_version,
+            eci: item.eci_raw,
+            cavv: item.cavv,
+            ds_transaction_id: item.directory_server_transaction_id,
+            // Fully authenticated and attempted authentications both shift the liability
+            liability_shift: item
+                .pares_status
+                .map(|status| matches!(status.as_str(), "Y" | "A")),
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
@@ -318,6 +368,11 @@ impl<F, T>
                     redirect: false,
                     mandate_reference: None,
                     connector_metadata: None,
+                    authentication_details: item
+                        .response
+                        .consumer_authentication_information
+                        .map(api::AuthenticationDetails::from),
+                    next_action_data: None,
                 }),
             },
             ..item.data
@@ -330,6 +385,7 @@ impl<F, T>
 pub struct CybersourceTransactionResponse {
     id: String,
     application_information: ApplicationInformation,
+    next_action_data: None,
 }
 
 #[derive(Debug, Deserialize)]
@@ -382,6 +438,8 @@ impl<F, T>
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             ..item.data
         })
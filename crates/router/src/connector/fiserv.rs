@@ -412,6 +412,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::PaymentIncrementalAuthorization for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Fiserv {}
+impl api::PayoutCreate for Fiserv {}
+impl api::PayoutFulfill for Fiserv {}
+impl api::PayoutCancel for Fiserv {}
+impl api::PayoutReverse for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Fiserv {}
+impl api::AcceptDispute for Fiserv {}
+impl api::SubmitEvidence for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Fiserv {}
+impl api::UploadFile for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Fiserv {
     fn get_webhook_object_reference_id(
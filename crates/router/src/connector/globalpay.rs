@@ -689,6 +689,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Globalpay {}
+impl api::PayoutCreate for Globalpay {}
+impl api::PayoutFulfill for Globalpay {}
+impl api::PayoutCancel for Globalpay {}
+impl api::PayoutReverse for Globalpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Globalpay {}
+impl api::AcceptDispute for Globalpay {}
+impl api::SubmitEvidence for Globalpay {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Globalpay {}
+impl api::UploadFile for Globalpay {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Globalpay
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Globalpay {
     fn get_webhook_object_reference_id(
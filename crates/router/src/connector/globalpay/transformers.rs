@@ -168,6 +168,8 @@ fn get_payment_response(
             redirect: false,
             mandate_reference: None,
             connector_metadata: None,
+            authentication_details: None,
+            next_action_data: None,
         }),
     }
 }
@@ -42,6 +42,11 @@ impl ConnectorCommon for Klarna {
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
         Ok(vec![(headers::AUTHORIZATION.to_string(), auth.basic_token)])
     }
+
+    fn get_authorization_validity(&self) -> time::Duration {
+        // Klarna orders expire 28 days after they are authorized
+        time::Duration::days(28)
+    }
 }
 
 impl api::Payment for Klarna {}
@@ -340,6 +345,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::PaymentIncrementalAuthorization for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Klarna {}
+impl api::PayoutCreate for Klarna {}
+impl api::PayoutFulfill for Klarna {}
+impl api::PayoutCancel for Klarna {}
+impl api::PayoutReverse for Klarna {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Klarna {}
+impl api::AcceptDispute for Klarna {}
+impl api::SubmitEvidence for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Klarna {}
+impl api::UploadFile for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Klarna {
     fn get_webhook_object_reference_id(
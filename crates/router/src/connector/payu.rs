@@ -680,6 +680,69 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Payu {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Payu {}
+impl api::PayoutCreate for Payu {}
+impl api::PayoutFulfill for Payu {}
+impl api::PayoutCancel for Payu {}
+impl api::PayoutReverse for Payu {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData> for Payu {
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Payu {}
+impl api::AcceptDispute for Payu {}
+impl api::SubmitEvidence for Payu {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Payu
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Payu
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Payu {}
+impl api::UploadFile for Payu {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Payu
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Payu {
     fn get_webhook_object_reference_id(
@@ -662,6 +662,85 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Rapyd {}
+impl api::PayoutCreate for Rapyd {}
+impl api::PayoutFulfill for Rapyd {}
+impl api::PayoutCancel for Rapyd {}
+impl api::PayoutReverse for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Rapyd {}
+impl api::AcceptDispute for Rapyd {}
+impl api::SubmitEvidence for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Rapyd {}
+impl api::UploadFile for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Rapyd {
     fn get_webhook_source_verification_algorithm(
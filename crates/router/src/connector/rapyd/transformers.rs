@@ -418,6 +418,8 @@ impl<F, T>
                                 redirection_data,
                                 mandate_reference: None,
                                 connector_metadata: None,
+                                authentication_details: None,
+                                next_action_data: None,
                             }),
                         )
                     }
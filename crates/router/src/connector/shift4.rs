@@ -495,6 +495,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Shift4 {}
+impl api::PayoutCreate for Shift4 {}
+impl api::PayoutFulfill for Shift4 {}
+impl api::PayoutCancel for Shift4 {}
+impl api::PayoutReverse for Shift4 {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Shift4 {}
+impl api::AcceptDispute for Shift4 {}
+impl api::SubmitEvidence for Shift4 {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Shift4 {}
+impl api::UploadFile for Shift4 {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Shift4
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Shift4 {
     fn get_webhook_object_reference_id(
@@ -489,6 +489,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Square {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Square
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Square {}
+impl api::PayoutCreate for Square {}
+impl api::PayoutFulfill for Square {}
+impl api::PayoutCancel for Square {}
+impl api::PayoutReverse for Square {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Square
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Square
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Square
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Square
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Square {}
+impl api::AcceptDispute for Square {}
+impl api::SubmitEvidence for Square {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Square
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Square
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Square {}
+impl api::UploadFile for Square {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Square
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Square {
     fn get_webhook_object_reference_id(
@@ -200,6 +200,8 @@ impl<F, T>
                     redirect: false,
                     mandate_reference: None,
                     connector_metadata: None,
+                    authentication_details: None,
+                    next_action_data: None,
                 }),
                 ..item.data
             }),
@@ -19,6 +19,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon},
+        storage::enums,
     },
     utils::{self, crypto, ByteSliceExt, BytesExt},
 };
@@ -52,6 +53,14 @@ impl ConnectorCommon for Stripe {
             format!("Bearer {}", auth.api_key),
         )])
     }
+
+    fn is_retryable_error_code(&self, error_code: &str) -> bool {
+        // Reference: https://stripe.com/docs/error-codes
+        matches!(
+            error_code,
+            "processing_error" | "rate_limit" | "lock_timeout"
+        )
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -60,6 +69,7 @@ impl api::PaymentAuthorize for Stripe {}
 impl api::PaymentSync for Stripe {}
 impl api::PaymentVoid for Stripe {}
 impl api::PaymentCapture for Stripe {}
+impl api::PaymentIncrementalAuthorization for Stripe {}
 impl api::PaymentSession for Stripe {}
 impl api::ConnectorAccessToken for Stripe {}
 
@@ -200,6 +210,117 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                Self::common_get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/payment_intents/{}/increment_authorization",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req =
+            utils::Encode::<stripe::IncrementalAuthorizationRequest>::convert_and_url_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: stripe::PaymentIntentResponse = res
+            .response
+            .parse_struct("PaymentIntentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Stripe
@@ -846,122 +967,677 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
-fn get_signature_elements_from_header(
-    headers: &actix_web::http::header::HeaderMap,
-) -> CustomResult<HashMap<String, Vec<u8>>, errors::ConnectorError> {
-    let security_header = headers
-        .get("Stripe-Signature")
-        .map(|header_value| {
-            header_value
-                .to_str()
-                .map(String::from)
-                .map_err(|_| errors::ConnectorError::WebhookSignatureNotFound)
-                .into_report()
-        })
-        .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
-        .into_report()??;
-
-    let props = security_header.split(',').collect::<Vec<&str>>();
-    let mut security_header_kvs: HashMap<String, Vec<u8>> = HashMap::with_capacity(props.len());
-
-    for prop_str in &props {
-        let (prop_key, prop_value) = prop_str
-            .split_once('=')
-            .ok_or(errors::ConnectorError::WebhookSourceVerificationFailed)
-            .into_report()?;
+impl api::Payouts for Stripe {}
+impl api::PayoutCreate for Stripe {}
+impl api::PayoutFulfill for Stripe {}
+impl api::PayoutCancel for Stripe {}
+impl api::PayoutReverse for Stripe {}
 
-        security_header_kvs.insert(prop_key.to_string(), prop_value.bytes().collect());
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutCreateType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
     }
 
-    Ok(security_header_kvs)
-}
-
-#[async_trait::async_trait]
-impl api::IncomingWebhook for Stripe {
-    fn get_webhook_source_verification_algorithm(
-        &self,
-        _headers: &actix_web::http::header::HeaderMap,
-        _body: &[u8],
-    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::HmacSha256))
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
     }
 
-    fn get_webhook_source_verification_signature(
+    fn get_url(
         &self,
-        headers: &actix_web::http::header::HeaderMap,
-        _body: &[u8],
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
-
-        let signature = security_header_kvs
-            .remove("v1")
-            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
-            .into_report()?;
-
-        hex::decode(signature)
-            .into_report()
-            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "v1/transfers"))
     }
 
-    fn get_webhook_source_verification_message(
+    fn get_request_body(
         &self,
-        headers: &actix_web::http::header::HeaderMap,
-        body: &[u8],
-        _merchant_id: &str,
-        _secret: &[u8],
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
-
-        let timestamp = security_header_kvs
-            .remove("t")
-            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
-            .into_report()?;
-
-        Ok(format!(
-            "{}.{}",
-            String::from_utf8_lossy(&timestamp),
-            String::from_utf8_lossy(body)
-        )
-        .into_bytes())
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req =
+            utils::Encode::<stripe::StripeTransferRequest>::convert_and_url_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
     }
 
-    async fn get_webhook_source_verification_merchant_secret(
+    fn build_request(
         &self,
-        db: &dyn StorageInterface,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .get_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
-
-        Ok(secret)
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+            .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+            .body(types::PayoutCreateType::get_request_body(self, req)?)
+            .build();
+        Ok(Some(request))
     }
 
-    fn get_webhook_object_reference_id(
+    #[instrument(skip_all)]
+    fn handle_response(
         &self,
-        body: &[u8],
-    ) -> CustomResult<String, errors::ConnectorError> {
-        let details: stripe::StripeWebhookObjectId = body
-            .parse_struct("StripeWebhookObjectId")
-            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        logger::debug!(response=?res);
 
-        Ok(details.data.object.id)
+        let response: stripe::StripeTransferResponse = res
+            .response
+            .parse_struct("Stripe TransferResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
 
-    fn get_webhook_event_type(
+    fn get_error_response(
         &self,
-        body: &[u8],
-    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        let details: stripe::StripeWebhookObjectEventType = body
-            .parse_struct("StripeWebhookObjectEventType")
-            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
-
-        Ok(match details.event_type.as_str() {
-            "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
-            "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
-            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    // Not Implemented (R)
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PayoutReverseType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let transfer_id = req.request.connector_payout_id.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            },
+        )?;
+        Ok(format!(
+            "{}v1/transfers/{}/reversals",
+            self.base_url(connectors),
+            transfer_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        // The complete amount of the transfer is reversed when no amount is sent
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PayoutReverseType::get_url(self, req, connectors)?)
+            .headers(types::PayoutReverseType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoReverse>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoReverse>, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeTransferReversalResponse = res
+            .response
+            .parse_struct("Stripe TransferReversalResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl api::Dispute for Stripe {}
+impl api::AcceptDispute for Stripe {}
+impl api::SubmitEvidence for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::DisputeResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::AcceptDisputeType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}/close",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+            .headers(types::AcceptDisputeType::get_headers(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::SubmitEvidenceType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/disputes/{}",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let stripe_req =
+            utils::Encode::<stripe::StripeSubmitEvidenceRequest>::convert_and_url_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+            .headers(types::SubmitEvidenceType::get_headers(
+                self, req, connectors,
+            )?)
+            .body(types::SubmitEvidenceType::get_request_body(self, req)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeDisputeResponse = res
+            .response
+            .parse_struct("Stripe DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+fn get_signature_elements_from_header(
+    headers: &actix_web::http::header::HeaderMap,
+) -> CustomResult<HashMap<String, Vec<u8>>, errors::ConnectorError> {
+    let security_header = headers
+        .get("Stripe-Signature")
+        .map(|header_value| {
+            header_value
+                .to_str()
+                .map(String::from)
+                .map_err(|_| errors::ConnectorError::WebhookSignatureNotFound)
+                .into_report()
+        })
+        .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+        .into_report()??;
+
+    let props = security_header.split(',').collect::<Vec<&str>>();
+    let mut security_header_kvs: HashMap<String, Vec<u8>> = HashMap::with_capacity(props.len());
+
+    for prop_str in &props {
+        let (prop_key, prop_value) = prop_str
+            .split_once('=')
+            .ok_or(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .into_report()?;
+
+        security_header_kvs.insert(prop_key.to_string(), prop_value.bytes().collect());
+    }
+
+    Ok(security_header_kvs)
+}
+
+impl api::FileUpload for Stripe {}
+impl api::UploadFile for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // The multipart `Content-Type` header (along with its boundary) is set by the HTTP client
+        self.get_auth_header(&req.connector_auth_type)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "multipart/form-data"
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}v1/files", connectors.stripe.secondary_base_url))
+    }
+
+    fn get_request_form_data(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        let stripe_req = stripe::construct_file_upload_request(req)?;
+        Ok(Some(stripe_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::UploadFileType::get_url(self, req, connectors)?)
+            .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+            .content_type(services::ContentType::FormData)
+            .form_data(types::UploadFileType::get_request_form_data(self, req)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        logger::debug!(response=?res);
+
+        let response: stripe::StripeFileUploadResponse = res
+            .response
+            .parse_struct("Stripe FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .message
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Stripe {
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+
+        let signature = security_header_kvs
+            .remove("v1")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(headers)?;
+
+        let timestamp = security_header_kvs
+            .remove("t")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        Ok(format!(
+            "{}.{}",
+            String::from_utf8_lossy(&timestamp),
+            String::from_utf8_lossy(body)
+        )
+        .into_bytes())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        Ok(secret)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        if is_dispute_event(body)? {
+            // Disputes are looked up through the payment intent they were raised against
+            let details: stripe::StripeWebhookDisputeObject = body
+                .parse_struct("StripeWebhookDisputeObject")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return Ok(details.data.object.payment_intent);
+        }
+
+        let details: stripe::StripeWebhookObjectId = body
+            .parse_struct("StripeWebhookObjectId")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(details.data.object.id)
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        let details: stripe::StripeWebhookObjectEventType = body
+            .parse_struct("StripeWebhookObjectEventType")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        Ok(match details.event_type.as_str() {
+            "payment_intent.payment_failed" => api::IncomingWebhookEvent::PaymentIntentFailure,
+            "payment_intent.succeeded" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            event_type if event_type.starts_with(DISPUTE_EVENT_PREFIX) => {
+                let dispute: stripe::StripeWebhookDisputeObject = body
+                    .parse_struct("StripeWebhookDisputeObject")
+                    .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+                match stripe::get_dispute_status(&dispute.data.object.status) {
+                    Some(enums::DisputeStatus::DisputeOpened) => {
+                        api::IncomingWebhookEvent::DisputeOpened
+                    }
+                    Some(enums::DisputeStatus::DisputeChallenged) => {
+                        api::IncomingWebhookEvent::DisputeChallenged
+                    }
+                    Some(enums::DisputeStatus::DisputeCancelled) => {
+                        api::IncomingWebhookEvent::DisputeCancelled
+                    }
+                    Some(enums::DisputeStatus::DisputeWon) => api::IncomingWebhookEvent::DisputeWon,
+                    Some(enums::DisputeStatus::DisputeLost) => {
+                        api::IncomingWebhookEvent::DisputeLost
+                    }
+                    _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
+                }
+            }
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
 
@@ -975,6 +1651,27 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(details.data.object)
     }
+
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::DisputePayload, errors::ConnectorError> {
+        let details: stripe::StripeWebhookDisputeObject = body
+            .parse_struct("StripeWebhookDisputeObject")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        api::DisputePayload::try_from(details.data.object)
+    }
+}
+
+const DISPUTE_EVENT_PREFIX: &str = "charge.dispute.";
+
+fn is_dispute_event(body: &[u8]) -> CustomResult<bool, errors::ConnectorError> {
+    let details: stripe::StripeWebhookObjectEventType = body
+        .parse_struct("StripeWebhookObjectEventType")
+        .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+    Ok(details.event_type.starts_with(DISPUTE_EVENT_PREFIX))
 }
 
 impl services::ConnectorRedirectResponse for Stripe {
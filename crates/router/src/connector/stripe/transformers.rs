@@ -67,6 +67,12 @@ pub enum Auth3ds {
     Any,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestIncrementalAuthorization {
+    IfAvailable,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct PaymentIntentRequest {
     pub amount: i64, //amount in cents, hence passed as integer
@@ -88,6 +94,25 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(rename = "payment_method_options[card][request_incremental_authorization]")]
+    pub request_incremental_authorization: Option<StripeRequestIncrementalAuthorization>,
+    #[serde(flatten)]
+    pub external_three_ds: Option<StripeExternalThreeDs>,
+}
+
+/// Result of a 3DS authentication performed outside of Stripe
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeExternalThreeDs {
+    #[serde(rename = "payment_method_options[card][three_d_secure][version]")]
+    pub version: Option<String>,
+    #[serde(
+        rename = "payment_method_options[card][three_d_secure][electronic_commerce_indicator]"
+    )]
+    pub electronic_commerce_indicator: Option<String>,
+    #[serde(rename = "payment_method_options[card][three_d_secure][cryptogram]")]
+    pub cryptogram: Option<Secret<String>>,
+    #[serde(rename = "payment_method_options[card][three_d_secure][transaction_id]")]
+    pub transaction_id: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -257,6 +282,28 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             .off_session
             .and_then(|value| mandate.as_ref().map(|_| value));
 
+        // Stripe only supports incremental authorizations of card payments
+        let request_incremental_authorization = match item.request.payment_method_data {
+            api::PaymentMethod::Card(_) if item.request.request_incremental_authorization => {
+                Some(StripeRequestIncrementalAuthorization::IfAvailable)
+            }
+            _ => None,
+        };
+
+        let external_three_ds = match item.request.payment_method_data {
+            api::PaymentMethod::Card(_) => item
+                .request
+                .external_authentication_details
+                .clone()
+                .map(|details| StripeExternalThreeDs {
+                    version: details.version,
+                    electronic_commerce_indicator: details.eci,
+                    cryptogram: details.cavv,
+                    transaction_id: details.ds_transaction_id,
+                }),
+            _ => None,
+        };
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -276,6 +323,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             payment_data,
             off_session,
             mandate,
+            request_incremental_authorization,
+            external_three_ds,
         })
     }
 }
@@ -360,6 +409,53 @@ pub struct PaymentIntentResponse {
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
     pub payment_method_options: Option<StripePaymentMethodOptions>,
+    pub charges: Option<StripeCharges>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct StripeCharges {
+    pub data: Vec<StripeCharge>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct StripeCharge {
+    pub payment_method_details: Option<StripePaymentMethodDetails>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct StripePaymentMethodDetails {
+    pub card: Option<StripeCardDetails>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct StripeCardDetails {
+    pub three_d_secure: Option<StripeThreeDsDetails>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+pub struct StripeThreeDsDetails {
+    pub result: Option<String>,
+    pub version: Option<String>,
+    pub electronic_commerce_indicator: Option<String>,
+    pub transaction_id: Option<String>,
+}
+
+impl From<StripeThreeDsDetails> for api::AuthenticationDetails {
+    fn from(item: StripeThreeDsDetails) -> Self {
+        // Stripe shifts the liability for fraudulent chargebacks to the issuer when the customer
+        // was authenticated, or when the issuer acknowledged the attempt to authenticate them
+        let liability_shift = item
+            .result
+            .as_deref()
+            .map(|result| matches!(result, "authenticated" | "attempt_acknowledged"));
+        Self {
+            version: item.version,
+            eci: item.electronic_commerce_indicator,
+            cavv: None,
+            ds_transaction_id: item.transaction_id,
+            liability_shift,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
@@ -384,22 +480,18 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_redirection_data);
+        let next_action_data = item
+            .response
+            .next_action
+            .as_ref()
+            .map(StripeNextActionResponse::get_next_action_data)
+            .transpose()?
+            .flatten();
 
         let mandate_reference =
             item.response
@@ -413,6 +505,16 @@ impl<F, T>
                     StripePaymentMethodOptions::AfterpayClearpay {} => None,
                 });
 
+        // The latest charge of the payment intent is listed first
+        let authentication_details = item
+            .response
+            .charges
+            .and_then(|charges| charges.data.into_iter().next())
+            .and_then(|charge| charge.payment_method_details)
+            .and_then(|payment_method_details| payment_method_details.card)
+            .and_then(|card| card.three_d_secure)
+            .map(api::AuthenticationDetails::from);
+
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
             // client_secret: Some(item.response.client_secret.clone().as_str()),
@@ -425,6 +527,8 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                authentication_details,
+                next_action_data,
             }),
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -440,22 +544,18 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_redirection_data);
+        let next_action_data = item
+            .response
+            .next_action
+            .as_ref()
+            .map(StripeNextActionResponse::get_next_action_data)
+            .transpose()?
+            .flatten();
 
         let mandate_reference =
             item.response
@@ -477,6 +577,8 @@ impl<F, T>
                 redirection_data,
                 mandate_reference,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data,
             }),
             ..item.data
         })
@@ -487,6 +589,48 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    PixDisplayQrCode(StripeQrCodeResponse),
+    PaynowDisplayQrCode(StripeQrCodeResponse),
+}
+
+impl StripeNextActionResponse {
+    fn get_redirection_data(&self) -> Option<services::RedirectForm> {
+        match self {
+            Self::RedirectToUrl(response) => {
+                let mut base_url = response.url.clone();
+                base_url.set_query(None);
+                Some(services::RedirectForm {
+                    url: base_url.to_string(),
+                    method: services::Method::Get,
+                    form_fields: std::collections::HashMap::from_iter(
+                        response
+                            .url
+                            .query_pairs()
+                            .map(|(k, v)| (k.to_string(), v.to_string())),
+                    ),
+                })
+            }
+            Self::PixDisplayQrCode(_) | Self::PaynowDisplayQrCode(_) => None,
+        }
+    }
+
+    fn get_next_action_data(
+        &self,
+    ) -> Result<Option<api::NextActionData>, error_stack::Report<errors::ConnectorError>> {
+        match self {
+            Self::RedirectToUrl(_) => Ok(None),
+            Self::PixDisplayQrCode(response) | Self::PaynowDisplayQrCode(response) => Ok(Some(
+                api::NextActionData::DisplayQrCode(api::QrCodeInformation {
+                    image_data_url: response.image_url_png.clone(),
+                    qr_code_data: Some(response.data.clone()),
+                    expires_at: response
+                        .expires_at
+                        .map(from_timestamp_to_datetime)
+                        .transpose()?,
+                }),
+            )),
+        }
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -515,6 +659,14 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+/// The QR code of PIX and PayNow payments, of which only PIX codes expire
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeQrCodeResponse {
+    data: String,
+    expires_at: Option<i64>,
+    image_url_png: Option<String>,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -615,6 +767,99 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+// PAYOUTS :
+// Payouts to a connected account are made as a transfer, reversing a payout reverses the transfer
+
+#[derive(Debug, Serialize)]
+pub struct StripeTransferRequest {
+    pub amount: i64,
+    pub currency: String,
+    pub destination: String,
+    pub description: Option<String>,
+    #[serde(rename = "metadata[payout_id]")]
+    pub metadata_payout_id: String,
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for StripeTransferRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let destination = match item.request.payout_method_data.clone() {
+            Some(api::PayoutMethodData::ConnectedAccount(account)) => account.account_id,
+            Some(api::PayoutMethodData::Card(_) | api::PayoutMethodData::Bank(_)) => {
+                Err(errors::ConnectorError::NotImplemented(String::from(
+                    "Stripe only supports payouts to connected accounts",
+                )))?
+            }
+            None => Err(errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            })?,
+        };
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency.to_string(),
+            destination,
+            description: item.request.description.clone(),
+            metadata_payout_id: item.request.payout_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StripeTransferResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub destination: Option<String>,
+    pub reversed: bool,
+}
+
+impl TryFrom<types::PayoutsResponseRouterData<api::PoCreate, StripeTransferResponse>>
+    for types::PayoutsRouterData<api::PoCreate>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<api::PoCreate, StripeTransferResponse>,
+    ) -> Result<Self, Self::Error> {
+        // Transfers are executed synchronously, there is nothing to fulfill
+        let status = if item.response.reversed {
+            enums::PayoutStatus::Reversed
+        } else {
+            enums::PayoutStatus::Success
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.id,
+                status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StripeTransferReversalResponse {
+    pub id: String,
+    pub amount: i64,
+    pub transfer: String,
+}
+
+impl TryFrom<types::PayoutsResponseRouterData<api::PoReverse, StripeTransferReversalResponse>>
+    for types::PayoutsRouterData<api::PoReverse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<api::PoReverse, StripeTransferReversalResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: item.response.transfer,
+                status: enums::PayoutStatus::Reversed,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ErrorDetails {
     pub code: Option<String>,
@@ -708,6 +953,28 @@ pub enum StripePaymentMethodOptions {
 pub struct StripeMandateOptions {
     reference: String, // Extendable, But only important field to be captured
 }
+/// Represents the incremental authorization request body for stripe connector.
+#[derive(Debug, Serialize)]
+pub struct IncrementalAuthorizationRequest {
+    /// The total amount to be authorized, including the amount authorized so far
+    amount: i64,
+    description: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.total_amount,
+            description: item.request.reason.clone(),
+        })
+    }
+}
+
 /// Represents the capture request body for stripe connector.
 #[derive(Debug, Serialize, Clone, Copy)]
 pub struct CaptureRequest {
@@ -792,6 +1059,245 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDisputeObject {
+    pub data: StripeWebhookDisputeData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDisputeData {
+    pub object: StripeDisputeObject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeObject {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub payment_intent: String,
+    pub created: i64,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+fn from_timestamp_to_datetime(
+    time: i64,
+) -> Result<time::PrimitiveDateTime, error_stack::Report<errors::ConnectorError>> {
+    let time = time::OffsetDateTime::from_unix_timestamp(time)
+        .into_report()
+        .change_context(errors::ConnectorError::InvalidDateFormat)?;
+    Ok(time::PrimitiveDateTime::new(time.date(), time.time()))
+}
+
+impl TryFrom<StripeDisputeObject> for api::DisputePayload {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(dispute: StripeDisputeObject) -> Result<Self, Self::Error> {
+        // Stripe raises inquiries with a `warning_` prefixed status before a formal dispute
+        let dispute_stage = if dispute.status.starts_with("warning_") {
+            api_models::enums::DisputeStage::PreDispute
+        } else {
+            api_models::enums::DisputeStage::Dispute
+        };
+        let challenge_required_by = dispute
+            .evidence_details
+            .and_then(|details| details.due_by)
+            .map(from_timestamp_to_datetime)
+            .transpose()?;
+        Ok(Self {
+            amount: dispute.amount.to_string(),
+            currency: dispute.currency.to_uppercase(),
+            dispute_stage,
+            connector_status: dispute.status,
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by,
+            created_at: Some(from_timestamp_to_datetime(dispute.created)?),
+            updated_at: None,
+        })
+    }
+}
+
+pub fn get_dispute_status(status: &str) -> Option<enums::DisputeStatus> {
+    match status {
+        "warning_needs_response" | "needs_response" => Some(enums::DisputeStatus::DisputeOpened),
+        "warning_under_review" | "under_review" => Some(enums::DisputeStatus::DisputeChallenged),
+        "warning_closed" => Some(enums::DisputeStatus::DisputeCancelled),
+        "won" => Some(enums::DisputeStatus::DisputeWon),
+        "lost" => Some(enums::DisputeStatus::DisputeLost),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub status: String,
+}
+
+impl
+    TryFrom<
+        types::DisputesResponseRouterData<
+            api::Accept,
+            types::AcceptDisputeRequestData,
+            StripeDisputeResponse,
+        >,
+    > for types::AcceptDisputeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::DisputesResponseRouterData<
+            api::Accept,
+            types::AcceptDisputeRequestData,
+            StripeDisputeResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // Closing a dispute concedes it, which Stripe reports as `lost`
+        Ok(Self {
+            response: Ok(types::DisputeResponseData {
+                dispute_status: enums::DisputeStatus::DisputeAccepted,
+                connector_status: Some(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeSubmitEvidenceRequest {
+    #[serde(rename = "evidence[product_description]")]
+    pub product_description: Option<String>,
+    #[serde(rename = "evidence[customer_email_address]")]
+    pub customer_email_address: Option<String>,
+    #[serde(rename = "evidence[customer_name]")]
+    pub customer_name: Option<String>,
+    #[serde(rename = "evidence[customer_purchase_ip]")]
+    pub customer_purchase_ip: Option<String>,
+    #[serde(rename = "evidence[shipping_address]")]
+    pub shipping_address: Option<String>,
+    #[serde(rename = "evidence[shipping_carrier]")]
+    pub shipping_carrier: Option<String>,
+    #[serde(rename = "evidence[shipping_date]")]
+    pub shipping_date: Option<String>,
+    #[serde(rename = "evidence[shipping_tracking_number]")]
+    pub shipping_tracking_number: Option<String>,
+    #[serde(rename = "evidence[service_date]")]
+    pub service_date: Option<String>,
+    #[serde(rename = "evidence[refund_policy_disclosure]")]
+    pub refund_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[refund_refusal_explanation]")]
+    pub refund_refusal_explanation: Option<String>,
+    #[serde(rename = "evidence[cancellation_policy_disclosure]")]
+    pub cancellation_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[cancellation_rebuttal]")]
+    pub cancellation_rebuttal: Option<String>,
+    #[serde(rename = "evidence[uncategorized_text]")]
+    pub uncategorized_text: Option<String>,
+    /// Submits the evidence to the bank right away instead of staging it
+    pub submit: bool,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for StripeSubmitEvidenceRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let evidence = item.request.evidence.clone();
+        Ok(Self {
+            product_description: evidence.product_description,
+            customer_email_address: evidence.customer_email_address,
+            customer_name: evidence.customer_name,
+            customer_purchase_ip: evidence.customer_purchase_ip,
+            shipping_address: evidence.shipping_address,
+            shipping_carrier: evidence.shipping_carrier,
+            shipping_date: evidence.shipping_date,
+            shipping_tracking_number: evidence.shipping_tracking_number,
+            service_date: evidence.service_date,
+            refund_policy_disclosure: evidence.refund_policy_disclosure,
+            refund_refusal_explanation: evidence.refund_refusal_explanation,
+            cancellation_policy_disclosure: evidence.cancellation_policy_disclosure,
+            cancellation_rebuttal: evidence.cancellation_rebuttal,
+            uncategorized_text: evidence.uncategorized_text,
+            submit: true,
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::DisputesResponseRouterData<
+            api::Evidence,
+            types::SubmitEvidenceRequestData,
+            StripeDisputeResponse,
+        >,
+    > for types::SubmitEvidenceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::DisputesResponseRouterData<
+            api::Evidence,
+            types::SubmitEvidenceRequestData,
+            StripeDisputeResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let dispute_status = get_dispute_status(&item.response.status)
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+            .into_report()
+            .attach_printable_lazy(|| format!("Unknown dispute status {}", item.response.status))?;
+        Ok(Self {
+            response: Ok(types::DisputeResponseData {
+                dispute_status,
+                connector_status: Some(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub fn construct_file_upload_request(
+    item: &types::UploadFileRouterData,
+) -> Result<reqwest::multipart::Form, error_stack::Report<errors::ConnectorError>> {
+    let purpose = match item.request.purpose {
+        api::FilePurpose::DisputeEvidence => "dispute_evidence",
+        api::FilePurpose::IdentityDocument => "identity_document",
+    };
+    let file_part = reqwest::multipart::Part::bytes(item.request.file.clone())
+        .file_name(item.request.file_key.clone())
+        .mime_str(item.request.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+        .attach_printable("Failure in constructing file data")?;
+    Ok(reqwest::multipart::Form::new()
+        .text("purpose", purpose)
+        .part("file", file_part))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeFileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+impl TryFrom<types::UploadFileResponseRouterData<StripeFileUploadResponse>>
+    for types::UploadFileRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::UploadFileResponseRouterData<StripeFileUploadResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: item.response.file_id,
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentMethodData {
     type Error = errors::ConnectorError;
     fn try_from(
@@ -855,3 +1361,117 @@ impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentM
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    fn get_next_action_data(next_action: serde_json::Value) -> Option<api::NextActionData> {
+        let response: PaymentIntentResponse = serde_json::from_value(serde_json::json!({
+            "id": "pi_3MtwBwLkdIwHu7ix28a3tqPa",
+            "object": "payment_intent",
+            "amount": 2000,
+            "amount_received": 0,
+            "amount_capturable": 0,
+            "currency": "brl",
+            "status": "requires_action",
+            "client_secret": "pi_3MtwBwLkdIwHu7ix28a3tqPa_secret_YrKJUKribcBjcG8HVhfZluoGH",
+            "created": 1680800504,
+            "metadata": { "order_id": "pay_1", "txn_id": "pay_1_1", "txn_uuid": "1" },
+            "next_action": next_action
+        }))
+        .unwrap();
+        let data = types::RouterData::<(), (), types::PaymentsResponseData> {
+            flow: PhantomData,
+            merchant_id: "merchant_1".to_string(),
+            connector: "stripe".to_string(),
+            payment_id: "pay_1".to_string(),
+            attempt_id: Some("pay_1_1".to_string()),
+            status: enums::AttemptStatus::Started,
+            payment_method: enums::PaymentMethodType::BankTransfer,
+            connector_auth_type: types::ConnectorAuthType::default(),
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: Default::default(),
+            auth_type: enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            request: (),
+            response: Err(types::ErrorResponse::get_not_implemented()),
+            payment_method_id: None,
+        };
+        let router_data = types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data,
+            http_code: 200,
+        })
+        .unwrap();
+        assert_eq!(
+            router_data.status,
+            enums::AttemptStatus::AuthenticationPending
+        );
+        match router_data.response.unwrap() {
+            types::PaymentsResponseData::TransactionResponse {
+                redirect,
+                next_action_data,
+                ..
+            } => {
+                assert!(!redirect);
+                next_action_data
+            }
+            _ => panic!("expected a transaction response"),
+        }
+    }
+
+    #[test]
+    fn test_pix_qr_code_next_action() {
+        let next_action_data = get_next_action_data(serde_json::json!({
+            "type": "pix_display_qr_code",
+            "pix_display_qr_code": {
+                "data": "00020101021226870014br.gov.bcb.pix2565qrcodes.example.com",
+                "expires_at": 1680886904,
+                "hosted_instructions_url": "https://payments.stripe.com/pix/instructions/test",
+                "image_url_png": "https://qr.stripe.com/test.png",
+                "image_url_svg": "https://qr.stripe.com/test.svg"
+            }
+        }));
+        assert_eq!(
+            next_action_data,
+            Some(api::NextActionData::DisplayQrCode(api::QrCodeInformation {
+                image_data_url: Some("https://qr.stripe.com/test.png".to_string()),
+                qr_code_data: Some(
+                    "00020101021226870014br.gov.bcb.pix2565qrcodes.example.com".to_string()
+                ),
+                expires_at: Some(time::macros::datetime!(2023-04-07 17:01:44)),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_paynow_qr_code_next_action() {
+        let next_action_data = get_next_action_data(serde_json::json!({
+            "type": "paynow_display_qr_code",
+            "paynow_display_qr_code": {
+                "data": "00020101021226580009SG.PAYNOW010120213UEN185200166X",
+                "hosted_instructions_url": "https://payments.stripe.com/paynow/instructions/test",
+                "image_url_png": "https://qr.stripe.com/test.png",
+                "image_url_svg": "https://qr.stripe.com/test.svg"
+            }
+        }));
+        assert_eq!(
+            next_action_data,
+            Some(api::NextActionData::DisplayQrCode(api::QrCodeInformation {
+                image_data_url: Some("https://qr.stripe.com/test.png".to_string()),
+                qr_code_data: Some(
+                    "00020101021226580009SG.PAYNOW010120213UEN185200166X".to_string()
+                ),
+                expires_at: None,
+            }))
+        );
+    }
+}
@@ -651,6 +651,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldline {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Worldline {}
+impl api::PayoutCreate for Worldline {}
+impl api::PayoutFulfill for Worldline {}
+impl api::PayoutCancel for Worldline {}
+impl api::PayoutReverse for Worldline {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Worldline {}
+impl api::AcceptDispute for Worldline {}
+impl api::SubmitEvidence for Worldline {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Worldline {}
+impl api::UploadFile for Worldline {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Worldline
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldline {
     fn get_webhook_object_reference_id(
@@ -163,6 +163,8 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
                         redirect: false,
                         mandate_reference: None,
                         connector_metadata: None,
+                        authentication_details: None,
+                        next_action_data: None,
                     }),
                     ..data.clone()
                 })
@@ -259,6 +261,8 @@ impl ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsRe
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
+                authentication_details: None,
+                next_action_data: None,
             }),
             ..data.clone()
         })
@@ -317,6 +321,8 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
                         redirect: false,
                         mandate_reference: None,
                         connector_metadata: None,
+                        authentication_details: None,
+                        next_action_data: None,
                     }),
                     ..data.clone()
                 })
@@ -593,6 +599,77 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl api::Payouts for Worldpay {}
+impl api::PayoutCreate for Worldpay {}
+impl api::PayoutFulfill for Worldpay {}
+impl api::PayoutCancel for Worldpay {}
+impl api::PayoutReverse for Worldpay {}
+
+impl ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl api::Dispute for Worldpay {}
+impl api::AcceptDispute for Worldpay {}
+impl api::SubmitEvidence for Worldpay {}
+
+impl ConnectorIntegration<api::Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl
+    ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::DisputeResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
+impl api::FileUpload for Worldpay {}
+impl api::UploadFile for Worldpay {}
+
+impl ConnectorIntegration<api::Upload, types::UploadFileRequestData, types::UploadFileResponse>
+    for Worldpay
+{
+    // Not Implemented (R)
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldpay {
     fn get_webhook_object_reference_id(
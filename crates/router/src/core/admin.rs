@@ -5,7 +5,10 @@ use uuid::Uuid;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        routing,
+    },
     db::StorageInterface,
     pii::Secret,
     services::api as service_api,
@@ -49,13 +52,7 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(routing_algorithm)?;
     }
 
     let merchant_account = storage::MerchantAccountNew {
@@ -127,13 +124,7 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(routing_algorithm)?;
     }
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
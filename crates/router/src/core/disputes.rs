@@ -0,0 +1,285 @@
+use std::fmt::Debug;
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks,
+    },
+    db, logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        storage::{self, enums},
+        transformers::{Foreign, ForeignInto},
+    },
+    utils,
+};
+
+// ********************************************** DISPUTE RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn disputes_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let dispute = find_dispute(&*state.store, &merchant_account.merchant_id, &dispute_id).await?;
+
+    Ok(services::ApplicationResponse::Json(dispute.foreign_into()))
+}
+
+// ********************************************** DISPUTE LIST **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn disputes_list_core(
+    db: &dyn db::StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: disputes::DisputeListConstraints,
+) -> RouterResponse<disputes::DisputeListResponse> {
+    let constraints = disputes::DisputeListConstraints {
+        limit: Some(constraints.limit.unwrap_or(10).clamp(1, 100)),
+        ..constraints
+    };
+
+    let disputes = db
+        .filter_dispute_by_constraints(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list disputes")?;
+
+    let data: Vec<disputes::DisputeResponse> = disputes
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        disputes::DisputeListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** DISPUTE ACCEPT **********************************************
+
+#[instrument(skip_all)]
+pub async fn disputes_accept_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = find_dispute(db, &merchant_account.merchant_id, &dispute_id).await?;
+    validate_dispute_status(&dispute, "accepted")?;
+
+    let request = types::AcceptDisputeRequestData {
+        dispute_id: dispute.dispute_id.clone(),
+        connector_dispute_id: dispute.connector_dispute_id.clone(),
+    };
+    let router_data =
+        call_connector_dispute::<api::Accept, _>(state, &merchant_account, &dispute, request)
+            .await?;
+
+    let dispute = update_dispute_with_connector_response(
+        state,
+        &merchant_account,
+        dispute,
+        router_data.response,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(dispute.foreign_into()))
+}
+
+// ********************************************** DISPUTE SUBMIT EVIDENCE **********************************************
+
+#[instrument(skip_all)]
+pub async fn disputes_submit_evidence_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: &str,
+    req: disputes::SubmitEvidenceRequest,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = find_dispute(db, &merchant_account.merchant_id, dispute_id).await?;
+    validate_dispute_status(&dispute, "challenged")?;
+
+    let request = types::SubmitEvidenceRequestData {
+        dispute_id: dispute.dispute_id.clone(),
+        connector_dispute_id: dispute.connector_dispute_id.clone(),
+        evidence: req,
+    };
+    let router_data =
+        call_connector_dispute::<api::Evidence, _>(state, &merchant_account, &dispute, request)
+            .await?;
+
+    let dispute = update_dispute_with_connector_response(
+        state,
+        &merchant_account,
+        dispute,
+        router_data.response,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(dispute.foreign_into()))
+}
+
+// ********************************************** HELPERS **********************************************
+
+async fn find_dispute(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    dispute_id: &str,
+) -> RouterResult<storage::Dispute> {
+    db.find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound))
+}
+
+/// Only disputes which are still open at the connector can be accepted or challenged
+fn validate_dispute_status(dispute: &storage::Dispute, action: &str) -> RouterResult<()> {
+    let allowed_statuses = [
+        enums::DisputeStatus::DisputeOpened,
+        enums::DisputeStatus::DisputeChallenged,
+    ];
+    utils::when(!allowed_statuses.contains(&dispute.dispute_status), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This dispute cannot be {action} because it has a status of {}. The expected state is {}",
+                dispute.dispute_status,
+                allowed_statuses
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }))
+    })
+}
+
+#[instrument(skip_all)]
+async fn call_connector_dispute<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, types::DisputeResponseData>>
+where
+    F: Clone + Debug + 'static,
+    Req: Clone + Debug + 'static,
+    dyn api::Connector: services::ConnectorIntegration<F, Req, types::DisputeResponseData>,
+{
+    let db = &*state.store;
+    let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let router_data = core_utils::construct_dispute_router_data::<F, Req>(
+        state,
+        merchant_account,
+        &payment_attempt,
+        dispute,
+        request,
+    )
+    .await?;
+
+    logger::debug!(dispute_router_data=?router_data);
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        Req,
+        types::DisputeResponseData,
+    > = connector.connector.get_connector_integration();
+    services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_dispute_failed_response())
+}
+
+async fn update_dispute_with_connector_response(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: storage::Dispute,
+    response: Result<types::DisputeResponseData, types::ErrorResponse>,
+) -> RouterResult<storage::Dispute> {
+    let response = response.map_err(|error| {
+        report!(errors::ApiErrorResponse::DisputeFailed {
+            data: Some(serde_json::json!({
+                "code": error.code,
+                "message": error.message,
+                "reason": error.reason,
+            })),
+        })
+        .attach_printable("Connector rejected the dispute operation")
+    })?;
+
+    let dispute_id = dispute.dispute_id.clone();
+    let previous_status = dispute.dispute_status;
+    let dispute = state
+        .store
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::StatusUpdate {
+                dispute_status: response.dispute_status,
+                connector_status: response.connector_status,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to update dispute {dispute_id}"))?;
+
+    if dispute.dispute_status != previous_status {
+        webhooks::trigger_dispute_outgoing_webhook(state, merchant_account, &dispute).await;
+    }
+
+    Ok(dispute)
+}
+
+impl From<Foreign<storage::Dispute>> for Foreign<disputes::DisputeResponse> {
+    fn from(dispute: Foreign<storage::Dispute>) -> Self {
+        let dispute = dispute.0;
+        disputes::DisputeResponse {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_stage: dispute.dispute_stage.foreign_into(),
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            created_at: dispute.connector_created_at,
+            updated_at: dispute.connector_updated_at,
+            received_at: dispute.created_at,
+        }
+        .into()
+    }
+}
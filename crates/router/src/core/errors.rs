@@ -94,6 +94,8 @@ impl StorageError {
                 err.current_context(),
                 storage_errors::DatabaseError::UniqueViolation,
             ),
+            // Raised instead of a unique violation when the entry is written to the KV store
+            Self::DuplicateValue { .. } => true,
             _ => false,
         }
     }
@@ -326,6 +328,8 @@ pub enum ProcessTrackerError {
     EParsingError(error_stack::Report<ParsingError>),
     #[error("Validation Error Received: {0}")]
     EValidationError(error_stack::Report<ValidationError>),
+    #[error("Received Error WebhooksFlowError: {0}")]
+    EWebhooksFlowError(error_stack::Report<WebhooksFlowError>),
 }
 
 macro_rules! error_to_process_tracker_error {
@@ -371,6 +375,11 @@ error_to_process_tracker_error!(
     ProcessTrackerError::EValidationError(error_stack::Report<ValidationError>)
 );
 
+error_to_process_tracker_error!(
+    error_stack::Report<WebhooksFlowError>,
+    ProcessTrackerError::EWebhooksFlowError(error_stack::Report<WebhooksFlowError>)
+);
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
@@ -383,12 +392,44 @@ pub enum WebhooksFlowError {
     PaymentsCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
+    #[error("Webhook event update failed")]
+    WebhookEventUpdateFailed,
+    #[error("Failed to schedule a retry of the outgoing webhook")]
+    OutgoingWebhookRetrySchedulingFailed,
+    #[error("Failed to sign the outgoing webhook")]
+    OutgoingWebhookSigningFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
     ForkFlowFailed,
     #[error("Webhook api call to merchant failed")]
     CallToMerchantFailed,
     #[error("Webhook not received by merchant")]
     NotReceivedByMerchant,
+    #[error("Incoming webhook source verification failed")]
+    WebhookSourceVerificationFailed,
+    #[error("Incoming webhook event object not found")]
+    WebhookEventObjectNotFound,
+    #[error("Resource referenced by incoming webhook not found")]
+    ResourceNotFound,
+    #[error("Dispute core flow failed")]
+    DisputeCoreFailed,
+    #[error("Dispute webhook event type is invalid")]
+    DisputeWebhookValidationFailed,
+    #[error("Incremental authorization core flow failed")]
+    IncrementalAuthorizationCoreFailed,
+    #[error("Incremental authorization webhook event type is invalid")]
+    IncrementalAuthorizationWebhookValidationFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    #[error("Failed to upload file to file storage")]
+    UploadFailed,
+    #[error("Failed to retrieve file from file storage")]
+    RetrieveFailed,
+    #[error("Failed to delete file from file storage")]
+    DeleteFailed,
+    #[error("File not found in file storage")]
+    FileNotFound,
 }
 
 #[derive(Debug, thiserror::Error)]
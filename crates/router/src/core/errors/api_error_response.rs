@@ -78,6 +78,21 @@ pub enum ApiErrorResponse {
         message = "{message}",
     )]
     GenericUnauthorized { message: String },
+    #[error(
+        error_type = ErrorType::InvalidRequestError, code = "IR_19",
+        message = "Another request on this resource is in progress. Retry the request later"
+    )]
+    ResourceBusy,
+    #[error(
+        error_type = ErrorType::InvalidRequestError, code = "IR_20",
+        message = "The idempotency key was already used with a different request"
+    )]
+    IdempotencyKeyConflict,
+    #[error(
+        error_type = ErrorType::InvalidRequestError, code = "IR_21",
+        message = "Too many requests were made in a short time. Retry the request later"
+    )]
+    TooManyRequests,
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -100,6 +115,12 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Verification failed while processing with connector. Retry operation")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Payout failed while processing with connector. Retry payout")]
+    PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "Dispute operation failed while processing with connector. Retry operation")]
+    DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_10", message = "File upload failed while processing with connector. Retry operation")]
+    FileUploadFailed { data: Option<serde_json::Value> },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_00", message = "Something went wrong")]
     InternalServerError,
@@ -115,6 +136,8 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
@@ -133,6 +156,12 @@ pub enum ApiErrorResponse {
     ResourceIdNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Mandate does not exist in our records")]
     MandateNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dispute does not exist in our records")]
+    DisputeNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File does not exist in our records")]
+    FileNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
@@ -141,6 +170,8 @@ pub enum ApiErrorResponse {
     RefundNotPossible { connector: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Mandate Validation Failed" )]
     MandateValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "File validation failed")]
+    FileValidationFailed { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
@@ -149,6 +180,8 @@ pub enum ApiErrorResponse {
     IncorrectConnectorNameGiven,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "Address does not exist in our records")]
     AddressNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "File is not available")]
+    FileNotAvailable,
 }
 
 #[derive(Clone)]
@@ -204,6 +237,8 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::RefundAmountExceedsPaymentAmount => StatusCode::BAD_REQUEST, // 400
             Self::MaximumRefundCount => StatusCode::BAD_REQUEST, // 400
             Self::PreconditionFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::ResourceBusy | Self::IdempotencyKeyConflict => StatusCode::CONFLICT, // 409
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS, // 429
 
             Self::PaymentAuthorizationFailed { .. }
             | Self::PaymentAuthenticationFailed { .. }
@@ -213,11 +248,17 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundFailed { .. }
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
+            | Self::PayoutFailed { .. }
+            | Self::DisputeFailed { .. }
+            | Self::FileUploadFailed { .. }
             | Self::PaymentUnexpectedState { .. }
-            | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            | Self::MandateValidationFailed { .. }
+            | Self::FileValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::MandateActive
@@ -227,6 +268,10 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::MerchantAccountNotFound
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound
+            | Self::FileNotFound
+            | Self::FileNotAvailable
             | Self::ClientSecretNotGiven
             | Self::ClientSecretInvalid
             | Self::SuccessfulPaymentNotFound
@@ -239,9 +284,9 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
             | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
-            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
-            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
+            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,  // 503
+            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,           // 400
+            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,     // 501
         }
     }
 
@@ -44,6 +44,9 @@ pub trait ConnectorErrorExt {
     fn to_refund_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
     fn to_payment_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
     fn to_verify_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
+    fn to_file_upload_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse>;
 }
 
 impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
@@ -118,6 +121,63 @@ impl ConnectorErrorExt for error_stack::Report<errors::ConnectorError> {
         };
         self.change_context(errors::ApiErrorResponse::PaymentAuthorizationFailed { data })
     }
+
+    fn to_payout_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let data = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let response_str = std::str::from_utf8(bytes);
+                match response_str {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        self.change_context(errors::ApiErrorResponse::PayoutFailed { data })
+    }
+
+    fn to_dispute_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let data = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let response_str = std::str::from_utf8(bytes);
+                match response_str {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        self.change_context(errors::ApiErrorResponse::DisputeFailed { data })
+    }
+
+    fn to_file_upload_failed_response(self) -> error_stack::Report<errors::ApiErrorResponse> {
+        let data = match self.current_context() {
+            errors::ConnectorError::ProcessingStepFailed(Some(bytes)) => {
+                let response_str = std::str::from_utf8(bytes);
+                match response_str {
+                    Ok(s) => serde_json::from_str(s)
+                        .map_err(|err| logger::error!(%err, "Failed to convert response to JSON"))
+                        .ok(),
+                    Err(err) => {
+                        logger::error!(%err, "Failed to convert response to UTF8 string");
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        self.change_context(errors::ApiErrorResponse::FileUploadFailed { data })
+    }
 }
 
 pub trait RedisErrorExt {
@@ -0,0 +1,174 @@
+pub mod helpers;
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db,
+    routes::AppState,
+    services,
+    types::{api, storage},
+    utils::{self, generate_id},
+};
+
+// ********************************************** FILE CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResponse<api::CreateFileResponse> {
+    helpers::validate_file_upload(&create_file_request)?;
+    let db = &*state.store;
+
+    // Validate the dispute before storing anything, so that a bad request does not leave an
+    // orphaned file behind
+    let dispute = match &create_file_request.dispute_id {
+        Some(dispute_id) => Some(
+            db.find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound)
+                })?,
+        ),
+        None => None,
+    };
+
+    let file_id = generate_id(consts::ID_LENGTH, "file");
+    let file_key = get_file_key(&merchant_account.merchant_id, &file_id);
+    let file_metadata = db
+        .insert_file_metadata(storage::FileMetadataNew {
+            file_id: file_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            file_name: create_file_request.file_name.clone(),
+            file_size: create_file_request.file_size,
+            file_type: create_file_request.file_type.to_string(),
+            provider_file_id: None,
+            file_upload_provider: None,
+            available: false,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file metadata")?;
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, create_file_request.file.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to store file")?;
+
+    let (provider_file_id, file_upload_provider) = match dispute {
+        Some(dispute) => {
+            let provider_file_id = helpers::upload_file_to_connector(
+                state,
+                &merchant_account,
+                &dispute,
+                &create_file_request,
+                file_key,
+            )
+            .await?;
+            (Some(provider_file_id), Some(dispute.connector))
+        }
+        None => (None, None),
+    };
+
+    db.update_file_metadata(
+        file_metadata,
+        storage::FileMetadataUpdate::Update {
+            provider_file_id,
+            file_upload_provider,
+            available: true,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Unable to update file metadata for file {file_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CreateFileResponse { file_id },
+    ))
+}
+
+// ********************************************** FILE RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata =
+        find_file_metadata(&*state.store, &merchant_account.merchant_id, &file_id).await?;
+    utils::when(!file_metadata.available, || {
+        Err(errors::ApiErrorResponse::FileNotAvailable).into_report()
+    })?;
+
+    let file_type: mime::Mime = file_metadata
+        .file_type
+        .parse()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse the stored file type")?;
+    let file_key = get_file_key(&file_metadata.merchant_id, &file_metadata.file_id);
+    let file = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve file from file storage")?;
+
+    Ok(services::ApplicationResponse::FileData((file, file_type)))
+}
+
+// ********************************************** FILE DELETE **********************************************
+
+#[instrument(skip_all)]
+pub async fn files_delete_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let file_metadata = find_file_metadata(db, &merchant_account.merchant_id, &file_id).await?;
+
+    let file_key = get_file_key(&file_metadata.merchant_id, &file_metadata.file_id);
+    if let Err(error) = state.file_storage_client.delete_file(&file_key).await {
+        // The file may not have been stored if the upload failed midway
+        if !matches!(
+            error.current_context(),
+            errors::FileStorageError::FileNotFound
+        ) {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to delete file from file storage"));
+        }
+    }
+
+    db.delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to delete file metadata")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+// ********************************************** HELPERS **********************************************
+
+async fn find_file_metadata(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    file_id: &str,
+) -> RouterResult<storage::FileMetadata> {
+    db.find_file_metadata_by_merchant_id_file_id(merchant_id, file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))
+}
+
+/// Files are stored under a per-merchant prefix in the file storage
+fn get_file_key(merchant_id: &str, file_id: &str) -> String {
+    format!("{merchant_id}/{file_id}")
+}
@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use actix_multipart::Multipart;
+use error_stack::{report, IntoReport, ResultExt};
+use futures::TryStreamExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, CustomResult, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api, storage},
+    utils,
+};
+
+/// File types accepted by the file upload endpoint
+const SUPPORTED_FILE_TYPES: [mime::Mime; 3] =
+    [mime::APPLICATION_PDF, mime::IMAGE_JPEG, mime::IMAGE_PNG];
+
+/// Maximum size of the non-file fields of the multipart request, in bytes
+const MAX_TEXT_FIELD_SIZE: usize = 64;
+
+fn invalid_multipart_request(field_name: &str) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Unable to read the `{field_name}` field of the multipart request"),
+    })
+}
+
+async fn read_multipart_field(
+    field: &mut actix_multipart::Field,
+    field_name: &str,
+    max_size: usize,
+) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let mut data = Vec::new();
+    while let Some(chunk) = field.try_next().await.map_err(|error| {
+        logger::error!(?error, "Failed to read multipart field");
+        invalid_multipart_request(field_name)
+    })? {
+        utils::when(data.len() + chunk.len() > max_size, || {
+            Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!("{field_name} exceeds the maximum size of {max_size} bytes"),
+            }))
+        })?;
+        data.extend_from_slice(&chunk);
+    }
+    Ok(data)
+}
+
+async fn read_multipart_text_field(
+    field: &mut actix_multipart::Field,
+    field_name: &str,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let data = read_multipart_field(field, field_name, MAX_TEXT_FIELD_SIZE).await?;
+    String::from_utf8(data).into_report().change_context(
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: field_name.to_string(),
+            expected_format: "UTF-8 string".to_string(),
+        },
+    )
+}
+
+/// Parse the multipart payload of a file upload request
+#[instrument(skip_all)]
+pub async fn get_create_file_request(
+    mut payload: Multipart,
+    max_file_size: usize,
+) -> CustomResult<api::CreateFileRequest, errors::ApiErrorResponse> {
+    let mut file = None;
+    let mut file_name = None;
+    let mut file_type = None;
+    let mut purpose = None;
+    let mut dispute_id = None;
+
+    while let Some(mut field) = payload.try_next().await.map_err(|error| {
+        logger::error!(?error, "Failed to read multipart request");
+        invalid_multipart_request("file")
+    })? {
+        let field_name = field.content_disposition().get_name().map(String::from);
+        match field_name.as_deref() {
+            Some("file") => {
+                file_name = field.content_disposition().get_filename().map(String::from);
+                file_type = field.content_type().cloned();
+                file = Some(read_multipart_field(&mut field, "file", max_file_size).await?);
+            }
+            Some("purpose") => {
+                let value = read_multipart_text_field(&mut field, "purpose").await?;
+                purpose = Some(
+                    api::FilePurpose::from_str(&value)
+                        .into_report()
+                        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "purpose",
+                        })?,
+                );
+            }
+            Some("dispute_id") => {
+                dispute_id = Some(read_multipart_text_field(&mut field, "dispute_id").await?);
+            }
+            _ => (),
+        }
+    }
+
+    let file = file.ok_or(errors::ApiErrorResponse::MissingRequiredField { field_name: "file" })?;
+    let file_type = file_type.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "file content type",
+    })?;
+    let purpose = purpose.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "purpose",
+    })?;
+    let file_size = i32::try_from(file.len()).into_report().change_context(
+        errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file size is too large".to_string(),
+        },
+    )?;
+
+    Ok(api::CreateFileRequest {
+        file,
+        file_name,
+        file_size,
+        file_type,
+        purpose,
+        dispute_id,
+    })
+}
+
+pub fn validate_file_upload(create_file_request: &api::CreateFileRequest) -> RouterResult<()> {
+    utils::when(create_file_request.file_size == 0, || {
+        Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file must not be empty".to_string(),
+        }))
+    })?;
+
+    let is_supported_file_type = SUPPORTED_FILE_TYPES
+        .iter()
+        .any(|file_type| file_type.essence_str() == create_file_request.file_type.essence_str());
+    utils::when(!is_supported_file_type, || {
+        Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+            reason: format!(
+                "file type {} is not supported, expected one of {}",
+                create_file_request.file_type,
+                SUPPORTED_FILE_TYPES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }))
+    })?;
+
+    utils::when(
+        create_file_request.dispute_id.is_some()
+            && create_file_request.purpose != api::FilePurpose::DisputeEvidence,
+        || {
+            Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                reason: "dispute_id can only be provided for files uploaded as dispute evidence"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+/// Upload the file to the connector through which the dispute was raised, returning the
+/// identifier assigned to the file by the connector
+#[instrument(skip_all)]
+pub async fn upload_file_to_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    create_file_request: &api::CreateFileRequest,
+    file_key: String,
+) -> RouterResult<String> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let router_data = core_utils::construct_upload_file_router_data(
+        state,
+        merchant_account,
+        &payment_attempt,
+        &dispute.connector,
+        create_file_request,
+        file_key,
+    )
+    .await?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_file_upload_failed_response())?;
+
+    let upload_file_response = router_data.response.map_err(|error| {
+        report!(errors::ApiErrorResponse::FileUploadFailed {
+            data: Some(serde_json::json!({
+                "code": error.code,
+                "message": error.message,
+                "reason": error.reason,
+            })),
+        })
+        .attach_printable("Connector rejected the file upload")
+    })?;
+
+    Ok(upload_file_response.provider_file_id)
+}
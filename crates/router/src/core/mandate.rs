@@ -4,8 +4,10 @@ use storage_models::enums as storage_enums;
 
 use super::payments::helpers;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks,
+    },
     routes::AppState,
     services,
     types::{
@@ -27,7 +29,11 @@ pub async fn get_mandate(
 ) -> RouterResponse<mandates::MandateResponse> {
     let mandate = state
         .store
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
     Ok(services::ApplicationResponse::Json(
@@ -35,28 +41,33 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
-    let mandate = db
+    let mandate = state
+        .store
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
             &req.mandate_id,
             storage::MandateUpdate::StatusUpdate {
                 mandate_status: storage::enums::MandateStatus::Revoked,
             },
+            merchant_account.storage_scheme,
         )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
+    let mandate_revoked_response = mandates::MandateRevokedResponse {
+        mandate_id: mandate.mandate_id.clone(),
+        status: mandate.mandate_status.foreign_into(),
+    };
+    webhooks::trigger_mandate_outgoing_webhook(state, &merchant_account, mandate).await;
+
     Ok(services::ApplicationResponse::Json(
-        mandates::MandateRevokedResponse {
-            mandate_id: mandate.mandate_id,
-            status: mandate.mandate_status.foreign_into(),
-        },
+        mandate_revoked_response,
     ))
 }
 
@@ -106,21 +117,35 @@ where
             let mandate_id = &mandate_id.mandate_id;
             let mandate = state
                 .store
-                .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
+                .find_mandate_by_merchant_id_mandate_id(
+                    resp.merchant_id.as_ref(),
+                    mandate_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .change_context(errors::ApiErrorResponse::MandateNotFound)?;
             let mandate = match mandate.mandate_type {
-                storage_enums::MandateType::SingleUse => state
-                    .store
-                    .update_mandate_by_merchant_id_mandate_id(
-                        &resp.merchant_id,
-                        mandate_id,
-                        storage::MandateUpdate::StatusUpdate {
-                            mandate_status: storage_enums::MandateStatus::Revoked,
-                        },
+                storage_enums::MandateType::SingleUse => {
+                    let mandate = state
+                        .store
+                        .update_mandate_by_merchant_id_mandate_id(
+                            &resp.merchant_id,
+                            mandate_id,
+                            storage::MandateUpdate::StatusUpdate {
+                                mandate_status: storage_enums::MandateStatus::Revoked,
+                            },
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::MandateNotFound)?;
+                    webhooks::trigger_mandate_outgoing_webhook(
+                        state,
+                        merchant_account,
+                        mandate.clone(),
                     )
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound),
+                    .await;
+                    Ok(mandate)
+                }
                 storage_enums::MandateType::MultiUse => state
                     .store
                     .update_mandate_by_merchant_id_mandate_id(
@@ -131,6 +156,7 @@ where
                                 mandate.amount_captured.unwrap_or(0) + resp.request.get_amount(),
                             ),
                         },
+                        merchant_account.storage_scheme,
                     )
                     .await
                     .change_context(errors::ApiErrorResponse::MandateNotFound),
@@ -173,15 +199,17 @@ where
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
                         });
-                    state
+                    let mandate = state
                         .store
-                        .insert_mandate(new_mandate_data)
+                        .insert_mandate(new_mandate_data, merchant_account.storage_scheme)
                         .await
                         .map_err(|err| {
                             err.to_duplicate_response(
                                 errors::ApiErrorResponse::DuplicateRefundRequest,
                             )
                         })?;
+                    webhooks::trigger_mandate_outgoing_webhook(state, merchant_account, mandate)
+                        .await;
                 };
             } else if resp.request.get_setup_future_usage().is_some() {
                 helpers::call_payment_method(
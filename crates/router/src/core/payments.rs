@@ -1,7 +1,9 @@
 pub mod access_token;
+pub mod capture_policy;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
 pub mod transformers;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
@@ -13,8 +15,8 @@ use router_env::{instrument, tracing};
 use time;
 
 pub use self::operations::{
-    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentMethodValidate,
-    PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
+    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentIncrementalAuthorization,
+    PaymentMethodValidate, PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -24,15 +26,18 @@ use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult},
         payment_methods::vault,
+        routing, utils as core_utils, webhooks,
     },
     db::StorageInterface,
-    logger, pii,
+    logger,
+    pii::{self, PeekInterface},
     routes::AppState,
     scheduler::utils as pt_utils,
     services,
     types::{
         self, api,
         storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
     },
     utils::OptionExt,
 };
@@ -62,6 +67,7 @@ where
     FData: Send,
 {
     let operation: BoxedOperation<'_, F, Req> = Box::new(operation);
+    let lock_on = operation.lock_on();
 
     let (operation, validate_result) = operation
         .to_validate_request()?
@@ -69,6 +75,12 @@ where
 
     tracing::Span::current().record("payment_id", &format!("{:?}", validate_result.payment_id));
 
+    // Held until the operation completes, and released when dropped
+    let lock = match lock_on {
+        Some(lock_on) => Some(acquire_operation_lock(state, lock_on, &validate_result).await?),
+        None => None,
+    };
+
     let (operation, mut payment_data, customer_details) = operation
         .to_get_tracker()?
         .get_trackers(
@@ -87,6 +99,7 @@ where
             &mut payment_data,
             customer_details,
             validate_result.merchant_id,
+            validate_result.storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -109,7 +122,7 @@ where
         )
         .await?;
 
-    let connector_details = route_connector(
+    let (connector_details, fallback_connectors) = route_connector(
         state,
         &merchant_account,
         &mut payment_data,
@@ -117,6 +130,7 @@ where
     )
     .await?;
 
+    core_utils::check_resource_lock(lock.as_ref()).await?;
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
         .update_trackers(
@@ -134,7 +148,25 @@ where
         .await?;
 
     if should_call_connector(&operation, &payment_data) {
+        core_utils::check_resource_lock(lock.as_ref()).await?;
+        let previous_status = payment_data.payment_intent.status;
         payment_data = match connector_details {
+            api::ConnectorCallType::Single(connector)
+                if retry::should_retry_payment(&operation, &call_connector_action) =>
+            {
+                retry::call_connector_service_with_retries(
+                    state,
+                    &merchant_account,
+                    &validate_result.payment_id,
+                    connector,
+                    fallback_connectors,
+                    &operation,
+                    payment_data,
+                    &customer,
+                    lock.as_ref(),
+                )
+                .await?
+            }
             api::ConnectorCallType::Single(connector) => {
                 call_connector_service(
                     state,
@@ -145,6 +177,7 @@ where
                     payment_data,
                     &customer,
                     call_connector_action,
+                    lock.as_ref(),
                 )
                 .await?
             }
@@ -184,15 +217,61 @@ where
                     payment_data,
                     &customer,
                     call_connector_action,
+                    lock.as_ref(),
                 )
                 .await?
             }
         };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await;
+
+        if payment_data.payment_intent.status != previous_status {
+            if payment_data.payment_intent.status == storage_enums::IntentStatus::RequiresCapture {
+                if let Err(error) =
+                    capture_policy::add_capture_policy_tasks(state, &payment_data.payment_attempt)
+                        .await
+                {
+                    logger::error!(
+                        ?error,
+                        "Failed to schedule the capture policy of the payment"
+                    );
+                }
+            }
+
+            webhooks::trigger_payments_outgoing_webhook(
+                state,
+                &merchant_account,
+                payment_data.clone(),
+                customer.clone(),
+                &operation,
+            )
+            .await;
+        }
     }
     Ok((payment_data, req, customer))
 }
 
+/// Lock the resource of the operation, waiting for a concurrent operation on the resource to
+/// complete for up to the configured number of attempts
+async fn acquire_operation_lock(
+    state: &AppState,
+    lock_on: operations::LockOn,
+    validate_result: &operations::ValidateResult<'_>,
+) -> RouterResult<services::locking::Lock> {
+    let resource_id = match lock_on {
+        operations::LockOn::PaymentId => {
+            api::PaymentIdTypeExt::get_payment_intent_id(&validate_result.payment_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Expected payment intent ID to lock the payment on")?
+        }
+    };
+
+    core_utils::acquire_resource_lock(
+        state,
+        &format!("lock_{}_{resource_id}", validate_result.merchant_id),
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn payments_core<F, Res, Req, Op, FData>(
     state: &AppState,
@@ -329,6 +408,7 @@ pub async fn call_connector_service<F, Op, Req>(
     payment_data: PaymentData<F>,
     customer: &Option<storage::Customer>,
     call_connector_action: CallConnectorAction,
+    lock: Option<&services::locking::Lock>,
 ) -> RouterResult<PaymentData<F>>
 where
     Op: Debug + Sync,
@@ -380,6 +460,9 @@ where
 
     let response = router_data_res
         .async_and_then(|response| async {
+            // The lock may have expired while waiting for the connector, in which case the
+            // operation which acquired it since owns the writes to the payment
+            core_utils::check_resource_lock(lock).await?;
             let operation = helpers::response_operation::<F, Req>();
             let payment_data = operation
                 .to_post_update_tracker()?
@@ -506,6 +589,12 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethod>,
     pub refunds: Vec<storage::Refund>,
+    /// Captures made from the payment attempt, in the order they were made
+    pub captures: Vec<storage::Capture>,
+    /// Incremental authorization being made with the connector
+    pub incremental_authorization: Option<storage::IncrementalAuthorization>,
+    /// Result of a 3DS authentication performed outside of the router, provided by the merchant
+    pub external_authentication_details: Option<api::AuthenticationDetails>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
@@ -591,8 +680,13 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCaptured
             )
         }
+        "PaymentIncrementalAuthorization" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCapture
+        ),
         "PaymentSession" => true,
         _ => false,
     }
@@ -662,12 +756,18 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Decide the connector the payment is processed with. Payments routed by the routing algorithm
+/// of the merchant also get the remaining connectors in the order of preference, which the
+/// payment can be retried with if the chosen connector declines it.
 pub async fn route_connector<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
     payment_data: &mut PaymentData<F>,
     connector_call_type: api::ConnectorCallType,
-) -> RouterResult<api::ConnectorCallType>
+) -> RouterResult<(
+    api::ConnectorCallType,
+    Vec<api_models::enums::RoutableConnectors>,
+)>
 where
     F: Send + Clone,
 {
@@ -675,20 +775,27 @@ where
         api::ConnectorCallType::Single(connector) => {
             payment_data.payment_attempt.connector = Some(connector.connector_name.to_string());
 
-            Ok(api::ConnectorCallType::Single(connector))
+            Ok((api::ConnectorCallType::Single(connector), Vec::new()))
         }
 
         api::ConnectorCallType::Routing => {
-            let routing_algorithm: api::RoutingAlgorithm = merchant_account
-                .routing_algorithm
-                .clone()
-                .parse_value("RoutingAlgorithm")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Could not decode merchant routing rules")?;
-
-            let connector_name = match routing_algorithm {
-                api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-            };
+            let routing_algorithm = routing::get_routing_algorithm(merchant_account)?;
+            let routing_input = get_routing_input(payment_data);
+
+            let mut routed_connectors = routing::perform_dynamic_routing(
+                &*state.store,
+                &merchant_account.merchant_id,
+                &routing_algorithm,
+                &routing_input,
+            )
+            .await
+            .into_iter();
+            let connector_name = routed_connectors
+                .next()
+                .map(|connector| connector.to_string())
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable("Routing algorithm did not return any connector")?;
 
             let connector_data = api::ConnectorData::get_connector_by_name(
                 &state.conf.connectors,
@@ -700,9 +807,57 @@ where
 
             payment_data.payment_attempt.connector = Some(connector_name);
 
-            Ok(api::ConnectorCallType::Single(connector_data))
+            Ok((
+                api::ConnectorCallType::Single(connector_data),
+                routed_connectors.collect(),
+            ))
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        call_type @ api::ConnectorCallType::Multiple(_) => Ok((call_type, Vec::new())),
+    }
+}
+
+fn get_routing_input<F>(payment_data: &PaymentData<F>) -> routing::RoutingInput<'_>
+where
+    F: Clone,
+{
+    let card_number = match &payment_data.payment_method_data {
+        Some(api::PaymentMethod::Card(card)) => Some(card.card_number.peek().as_str()),
+        _ => None,
+    };
+    let payment_method = payment_data
+        .payment_attempt
+        .payment_method
+        .map(ForeignInto::foreign_into)
+        .or_else(|| {
+            payment_data
+                .payment_method_data
+                .as_ref()
+                .map(|payment_method_data| match payment_method_data {
+                    api::PaymentMethod::Card(_) => api_models::enums::PaymentMethodType::Card,
+                    api::PaymentMethod::BankTransfer => {
+                        api_models::enums::PaymentMethodType::BankTransfer
+                    }
+                    api::PaymentMethod::Wallet(_) => api_models::enums::PaymentMethodType::Wallet,
+                    api::PaymentMethod::PayLater(_) => {
+                        api_models::enums::PaymentMethodType::PayLater
+                    }
+                    api::PaymentMethod::Paypal => api_models::enums::PaymentMethodType::Paypal,
+                })
+        });
+
+    routing::RoutingInput {
+        payment_id: Some(payment_data.payment_intent.payment_id.as_str()),
+        currency: Some(payment_data.currency.foreign_into()),
+        amount: Some(payment_data.amount.into()),
+        card_number,
+        billing_country: payment_data
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country.as_deref()),
+        payment_method,
+        metadata: payment_data.payment_intent.metadata.as_ref(),
     }
 }
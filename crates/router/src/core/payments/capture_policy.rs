@@ -0,0 +1,322 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::{operations, payments_operation_core, CallConnectorAction};
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    types::{
+        api,
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, StringExt, ValueExt},
+};
+
+const AUTO_CAPTURE_RUNNER: &str = "PAYMENTS_AUTO_CAPTURE_WORKFLOW";
+const AUTO_CAPTURE_TASK: &str = "PAYMENTS_AUTO_CAPTURE";
+const AUTH_EXPIRY_RUNNER: &str = "PAYMENTS_AUTH_EXPIRY_WORKFLOW";
+const AUTH_EXPIRY_TASK: &str = "PAYMENTS_AUTH_EXPIRY_VOID";
+
+/// Number of times a scheduled capture or void is retried if it could not be performed, such as
+/// when the payment is locked by a concurrent operation
+const MAX_SCHEDULED_ACTION_RETRIES: i32 = 3;
+const SCHEDULED_ACTION_RETRY_DELAY_IN_SECS: i64 = 300;
+
+const AUTO_VOID_CANCELLATION_REASON: &str = "authorization_expiring";
+
+fn get_capture_policy_key(merchant_id: &str) -> String {
+    format!("payment_capture_policy_{merchant_id}")
+}
+
+/// Authorized payments are left alone for merchants who have not configured a capture policy
+#[instrument(skip_all)]
+pub async fn get_capture_policy(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<api::PaymentCapturePolicy>> {
+    let config = match db
+        .find_config_by_key(&get_capture_policy_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment capture policy")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("PaymentCapturePolicy")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize the payment capture policy")
+        .map(Some)
+}
+
+/// The times at which a payment authorized at `authorized_at` is to be captured and voided
+/// according to the capture policy. No capture is scheduled after the authorization expires.
+pub fn get_scheduled_action_times(
+    capture_policy: &api::PaymentCapturePolicy,
+    authorized_at: PrimitiveDateTime,
+    authorization_validity: time::Duration,
+) -> (Option<PrimitiveDateTime>, Option<PrimitiveDateTime>) {
+    let expires_at = authorized_at.saturating_add(authorization_validity);
+
+    let capture_at = capture_policy
+        .capture_after_hours
+        .map(|hours| authorized_at.saturating_add(time::Duration::hours(hours.into())))
+        .filter(|capture_at| *capture_at < expires_at);
+    let void_at = capture_policy.void_before_expiry_hours.map(|hours| {
+        expires_at
+            .saturating_sub(time::Duration::hours(hours.into()))
+            .max(authorized_at)
+    });
+
+    (capture_at, void_at)
+}
+
+/// Schedule the capture and the void of a payment which was just authorized, according to the
+/// capture policy of the merchant. The action scheduled later finds the payment no longer
+/// awaiting capture and does nothing.
+#[instrument(skip_all)]
+pub async fn add_capture_policy_tasks(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let capture_policy = match get_capture_policy(db, &payment_attempt.merchant_id).await? {
+        Some(capture_policy) => capture_policy,
+        None => return Ok(()),
+    };
+
+    let connector_name = payment_attempt
+        .connector
+        .as_deref()
+        .get_required_value("connector")?;
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed construction of ConnectorData")?;
+
+    let (capture_at, void_at) = get_scheduled_action_times(
+        &capture_policy,
+        common_utils::date_time::now(),
+        connector_data.connector.get_authorization_validity(),
+    );
+
+    let tracking_data = storage::ScheduledPaymentActionTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+    let scheduled_actions = [
+        (AUTO_CAPTURE_RUNNER, AUTO_CAPTURE_TASK, capture_at),
+        (AUTH_EXPIRY_RUNNER, AUTH_EXPIRY_TASK, void_at),
+    ];
+
+    for (runner, task, schedule_time) in scheduled_actions {
+        let schedule_time = match schedule_time {
+            Some(schedule_time) => schedule_time,
+            None => continue,
+        };
+        let process_tracker_id = pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &payment_attempt.attempt_id,
+            &payment_attempt.merchant_id,
+        );
+        let process_tracker_entry =
+            <storage::ProcessTracker as ProcessTrackerExt>::make_process_tracker_new(
+                process_tracker_id,
+                task,
+                runner,
+                &tracking_data,
+                schedule_time,
+            )
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        db.insert_process(process_tracker_entry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to schedule {task} for the payment"))?;
+    }
+
+    Ok(())
+}
+
+/// Capture a payment which the merchant did not capture within the time allowed by their
+/// capture policy
+#[instrument(skip_all)]
+pub async fn start_auto_capture_workflow(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::ScheduledPaymentActionTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ScheduledPaymentActionTrackingData")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+        .await?;
+
+    if !is_awaiting_capture(db, &merchant_account, &tracking_data).await? {
+        return process
+            .finish_with_status(db, "PAYMENT_NOT_AWAITING_CAPTURE".to_string())
+            .await;
+    }
+
+    let request = api::PaymentsCaptureRequest {
+        payment_id: Some(tracking_data.payment_id),
+        merchant_id: Some(tracking_data.merchant_id),
+        ..Default::default()
+    };
+    let result = payments_operation_core::<api::Capture, _, _, _>(
+        state,
+        merchant_account,
+        operations::PaymentCapture,
+        request,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .map(|_| ());
+
+    finish_scheduled_action(db, process, result).await
+}
+
+/// Void a payment which is still not captured shortly before its authorization expires, so that
+/// the funds held on the payment method of the customer are released
+#[instrument(skip_all)]
+pub async fn start_auth_expiry_workflow(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::ScheduledPaymentActionTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ScheduledPaymentActionTrackingData")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+        .await?;
+
+    if !is_awaiting_capture(db, &merchant_account, &tracking_data).await? {
+        return process
+            .finish_with_status(db, "PAYMENT_NOT_AWAITING_CAPTURE".to_string())
+            .await;
+    }
+
+    let request = api::PaymentsCancelRequest {
+        payment_id: tracking_data.payment_id,
+        cancellation_reason: Some(AUTO_VOID_CANCELLATION_REASON.to_string()),
+    };
+    let result = payments_operation_core::<api::Void, _, _, _>(
+        state,
+        merchant_account,
+        operations::PaymentCancel,
+        request,
+        CallConnectorAction::Trigger,
+    )
+    .await
+    .map(|_| ());
+
+    finish_scheduled_action(db, process, result).await
+}
+
+/// Scheduled actions apply only to the attempt they were scheduled for, and only while the
+/// payment has been neither captured nor voided
+async fn is_awaiting_capture(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    tracking_data: &storage::ScheduledPaymentActionTrackingData,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    if payment_intent.status != storage_enums::IntentStatus::RequiresCapture {
+        return Ok(false);
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id(
+            &tracking_data.payment_id,
+            &tracking_data.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+    Ok(payment_attempt.attempt_id == tracking_data.attempt_id)
+}
+
+/// Complete the process once the connector has been called, whatever the outcome of the call.
+/// Actions which could not be performed at all are retried after a delay.
+async fn finish_scheduled_action(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    result: RouterResult<()>,
+) -> Result<(), errors::ProcessTrackerError> {
+    match result {
+        Ok(()) => {
+            let id = process.id.clone();
+            process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await
+        }
+        Err(error) if process.retry_count < MAX_SCHEDULED_ACTION_RETRIES => {
+            logger::warn!(?error, pt.id = %process.id, "Scheduled payment action failed, retrying");
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(SCHEDULED_ACTION_RETRY_DELAY_IN_SECS),
+            );
+            process.retry(db, schedule_time).await
+        }
+        Err(error) => Err(error)?,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_scheduled_action_times() {
+        let authorized_at = time::macros::datetime!(2023-03-01 10:00);
+        let capture_policy = api::PaymentCapturePolicy {
+            capture_after_hours: Some(48),
+            void_before_expiry_hours: Some(12),
+        };
+
+        let (capture_at, void_at) =
+            get_scheduled_action_times(&capture_policy, authorized_at, time::Duration::days(7));
+        assert_eq!(capture_at, Some(time::macros::datetime!(2023-03-03 10:00)));
+        assert_eq!(void_at, Some(time::macros::datetime!(2023-03-07 22:00)));
+
+        // No capture is scheduled after the authorization expires
+        let (capture_at, void_at) =
+            get_scheduled_action_times(&capture_policy, authorized_at, time::Duration::days(1));
+        assert_eq!(capture_at, None);
+        assert_eq!(void_at, Some(time::macros::datetime!(2023-03-01 22:00)));
+
+        let capture_policy = api::PaymentCapturePolicy {
+            capture_after_hours: None,
+            void_before_expiry_hours: Some(48),
+        };
+        let (capture_at, void_at) =
+            get_scheduled_action_times(&capture_policy, authorized_at, time::Duration::days(1));
+        assert_eq!(capture_at, None);
+        assert_eq!(void_at, Some(authorized_at));
+    }
+}
@@ -40,12 +40,13 @@ pub async fn get_address_for_payment_request(
     address_id: Option<&str>,
     merchant_id: &str,
     customer_id: &Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Address>, errors::ApiErrorResponse> {
     Ok(match req_address {
         Some(address) => {
             match address_id {
                 Some(id) => Some(
-                    db.update_address(id.to_owned(), address.foreign_into())
+                    db.update_address(id.to_owned(), address.foreign_into(), storage_scheme)
                         .await
                         .map_err(|err| {
                             err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
@@ -60,17 +61,20 @@ pub async fn get_address_for_payment_request(
 
                     let address_details = address.address.clone().unwrap_or_default();
                     Some(
-                        db.insert_address(storage::AddressNew {
-                            phone_number: address.phone.as_ref().and_then(|a| a.number.clone()),
-                            country_code: address
-                                .phone
-                                .as_ref()
-                                .and_then(|a| a.country_code.clone()),
-                            customer_id: customer_id.to_string(),
-                            merchant_id: merchant_id.to_string(),
-
-                            ..address_details.foreign_into()
-                        })
+                        db.insert_address(
+                            storage::AddressNew {
+                                phone_number: address.phone.as_ref().and_then(|a| a.number.clone()),
+                                country_code: address
+                                    .phone
+                                    .as_ref()
+                                    .and_then(|a| a.country_code.clone()),
+                                customer_id: customer_id.to_string(),
+                                merchant_id: merchant_id.to_string(),
+
+                                ..address_details.foreign_into()
+                            },
+                            storage_scheme,
+                        )
                         .await
                         .map_err(|_| errors::ApiErrorResponse::InternalServerError)?,
                     )
@@ -78,9 +82,11 @@ pub async fn get_address_for_payment_request(
             }
         }
         None => match address_id {
-            Some(id) => Some(db.find_address(id).await).transpose().map_err(|err| {
-                err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
-            })?,
+            Some(id) => Some(db.find_address(id, storage_scheme).await)
+                .transpose()
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
+                })?,
             None => None,
         },
     })
@@ -89,10 +95,11 @@ pub async fn get_address_for_payment_request(
 pub async fn get_address_by_id(
     db: &dyn StorageInterface,
     address_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Address>, errors::ApiErrorResponse> {
     match address_id {
         None => Ok(None),
-        Some(address_id) => Ok(db.find_address(&address_id).await.ok()),
+        Some(address_id) => Ok(db.find_address(&address_id, storage_scheme).await.ok()),
     }
 }
 
@@ -140,7 +147,11 @@ pub async fn get_token_for_recurring_mandate(
     let mandate_id = req.mandate_id.clone().get_required_value("mandate_id")?;
 
     let mandate = db
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, mandate_id.as_str())
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            mandate_id.as_str(),
+            merchant_account.storage_scheme,
+        )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
@@ -444,6 +455,32 @@ pub fn payment_intent_status_fsm(
     }
 }
 
+/// Status of a payment attempt captured in one or more parts, from the status of its captures.
+/// The attempt is charged once the authorized amount or a final capture was captured, and can be
+/// captured further until then.
+pub fn multiple_capture_attempt_status_fsm(
+    authorized_amount: i64,
+    captures: &[storage::Capture],
+) -> storage_enums::AttemptStatus {
+    let amount_captured = get_amount_captured(captures);
+    let final_capture_charged = captures.iter().any(|capture| {
+        capture.final_capture && capture.status == storage_enums::CaptureStatus::Charged
+    });
+
+    if captures
+        .iter()
+        .any(|capture| capture.status == storage_enums::CaptureStatus::Pending)
+    {
+        storage_enums::AttemptStatus::CaptureInitiated
+    } else if final_capture_charged || amount_captured >= authorized_amount {
+        storage_enums::AttemptStatus::Charged
+    } else if amount_captured > 0 {
+        storage_enums::AttemptStatus::PartialCharged
+    } else {
+        storage_enums::AttemptStatus::Authorized
+    }
+}
+
 pub async fn add_domain_task_to_pt<Op>(
     operation: &Op,
     state: &AppState,
@@ -583,11 +620,12 @@ pub async fn get_customer_from_details(
     db: &dyn StorageInterface,
     customer_id: Option<String>,
     merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
     match customer_id {
         None => Ok(None),
         Some(c_id) => {
-            db.find_customer_optional_by_customer_id_merchant_id(&c_id, merchant_id)
+            db.find_customer_optional_by_customer_id_merchant_id(&c_id, merchant_id, storage_scheme)
                 .await
         }
     }
@@ -617,6 +655,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     payment_data: &mut PaymentData<F>,
     req: Option<CustomerDetails>,
     merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<storage::Customer>), errors::StorageError> {
     let req = req
         .get_required_value("customer")
@@ -624,7 +663,11 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     let optional_customer = match req.customer_id.as_ref() {
         Some(customer_id) => {
             let customer_data = db
-                .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+                .find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    storage_scheme,
+                )
                 .await?;
             Some(match customer_data {
                 Some(c) => Ok(c),
@@ -639,14 +682,18 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                         ..storage::CustomerNew::default()
                     };
 
-                    db.insert_customer(new_customer).await
+                    db.insert_customer(new_customer, storage_scheme).await
                 }
             })
         }
         None => match &payment_data.payment_intent.customer_id {
             None => None,
             Some(customer_id) => db
-                .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+                .find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    storage_scheme,
+                )
                 .await?
                 .map(Ok),
         },
@@ -794,13 +841,17 @@ pub(crate) fn validate_capture_method(
 #[instrument(skip_all)]
 pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResult<()> {
     utils::when(
-        status != storage_enums::IntentStatus::RequiresCapture,
+        !matches!(
+            status,
+            storage_enums::IntentStatus::RequiresCapture
+                | storage_enums::IntentStatus::PartiallyCaptured
+        ),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 field_name: "payment.status".to_string(),
                 current_flow: "captured".to_string(),
                 current_value: status.to_string(),
-                states: "requires_capture".to_string()
+                states: "requires_capture, partially_captured".to_string()
             }))
         },
     )
@@ -808,17 +859,135 @@ pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResu
 
 #[instrument(skip_all)]
 pub(crate) fn validate_amount_to_capture(
+    amount_capturable: i64,
+    amount_to_capture: i64,
+) -> RouterResult<()> {
+    utils::when(amount_to_capture > amount_capturable, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount_to_capture is greater than the amount left to capture".to_string()
+        }))
+    })?;
+    utils::when(amount_to_capture <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount_to_capture must be greater than 0".to_string()
+        }))
+    })
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_incremental_authorization(
+    status: storage_enums::IntentStatus,
+    request_incremental_authorization: bool,
     amount: i64,
-    amount_to_capture: Option<i64>,
 ) -> RouterResult<()> {
     utils::when(
-        amount_to_capture.is_some() && (Some(amount) < amount_to_capture),
+        status != storage_enums::IntentStatus::RequiresCapture,
         || {
-            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
-                message: "amount_to_capture is greater than amount".to_string()
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                field_name: "payment.status".to_string(),
+                current_flow: "incrementally authorized".to_string(),
+                current_value: status.to_string(),
+                states: "requires_capture".to_string()
             }))
         },
-    )
+    )?;
+    utils::when(!request_incremental_authorization, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Incremental authorization was not requested for the payment".to_string()
+        }))
+    })?;
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string()
+        }))
+    })
+}
+
+/// Record the outcome of an incremental authorization of a payment. The payment is authorized for
+/// the increased amount once the increment succeeds, and stays authorized for the previous amount
+/// otherwise.
+#[instrument(skip_all)]
+pub async fn update_incremental_authorization(
+    db: &dyn StorageInterface,
+    authorization: storage::IncrementalAuthorization,
+    authorization_update: storage::IncrementalAuthorizationUpdate,
+    payment_attempt: storage::PaymentAttempt,
+    payment_intent: storage::PaymentIntent,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<(
+    storage::IncrementalAuthorization,
+    storage::PaymentAttempt,
+    storage::PaymentIntent,
+)> {
+    let authorization = db
+        .update_incremental_authorization(authorization, authorization_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update incremental authorization")?;
+
+    if authorization.status != storage_enums::AuthorizationStatus::Success {
+        return Ok((authorization, payment_attempt, payment_intent));
+    }
+
+    let payment_attempt = db
+        .update_payment_attempt(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::AmountUpdate {
+                amount: authorization.amount,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::AmountUpdate {
+                amount: authorization.amount,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok((authorization, payment_attempt, payment_intent))
+}
+
+/// Amount captured from a payment attempt by its successful captures
+pub fn get_amount_captured(captures: &[storage::Capture]) -> i64 {
+    captures
+        .iter()
+        .filter(|capture| capture.status == storage_enums::CaptureStatus::Charged)
+        .map(|capture| capture.amount)
+        .sum()
+}
+
+/// Whether a payment attempt is captured in more than one part, in which case the status of each
+/// capture is synced separately with the connector
+pub fn is_multiple_capture(captures: &[storage::Capture]) -> bool {
+    captures.iter().any(|capture| !capture.final_capture)
+        || captures
+            .iter()
+            .filter(|capture| capture.status != storage_enums::CaptureStatus::Failed)
+            .count()
+            > 1
+}
+
+/// Amount which can still be captured from a payment attempt, which excludes the amount of the
+/// captures still in progress
+pub fn get_amount_capturable(authorized_amount: i64, captures: &[storage::Capture]) -> i64 {
+    let amount_captured_or_capturing: i64 = captures
+        .iter()
+        .filter(|capture| {
+            matches!(
+                capture.status,
+                storage_enums::CaptureStatus::Charged | storage_enums::CaptureStatus::Pending
+            )
+        })
+        .map(|capture| capture.amount)
+        .sum();
+    authorized_amount - amount_captured_or_capturing
 }
 
 #[instrument(skip_all)]
@@ -1220,4 +1389,130 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    fn capture(
+        amount: i64,
+        status: storage_enums::CaptureStatus,
+        final_capture: bool,
+    ) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            id: 0,
+            capture_id: "cap_test".to_string(),
+            payment_id: "pay_test".to_string(),
+            merchant_id: "merchant_test".to_string(),
+            attempt_id: "attempt_test".to_string(),
+            status,
+            amount,
+            currency: storage_enums::Currency::USD,
+            connector: "adyen".to_string(),
+            connector_capture_id: None,
+            error_message: None,
+            error_code: None,
+            final_capture,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_multiple_capture_attempt_status_fsm() {
+        use storage_enums::{AttemptStatus, CaptureStatus};
+
+        let partial = [capture(400, CaptureStatus::Charged, false)];
+        assert_eq!(
+            multiple_capture_attempt_status_fsm(1000, &partial),
+            AttemptStatus::PartialCharged
+        );
+
+        let full = [
+            capture(400, CaptureStatus::Charged, false),
+            capture(600, CaptureStatus::Charged, false),
+        ];
+        assert_eq!(
+            multiple_capture_attempt_status_fsm(1000, &full),
+            AttemptStatus::Charged
+        );
+
+        let final_capture = [
+            capture(400, CaptureStatus::Charged, false),
+            capture(100, CaptureStatus::Charged, true),
+        ];
+        assert_eq!(
+            multiple_capture_attempt_status_fsm(1000, &final_capture),
+            AttemptStatus::Charged
+        );
+
+        let pending = [
+            capture(400, CaptureStatus::Charged, false),
+            capture(100, CaptureStatus::Pending, false),
+        ];
+        assert_eq!(
+            multiple_capture_attempt_status_fsm(1000, &pending),
+            AttemptStatus::CaptureInitiated
+        );
+
+        let failed = [capture(400, CaptureStatus::Failed, true)];
+        assert_eq!(
+            multiple_capture_attempt_status_fsm(1000, &failed),
+            AttemptStatus::Authorized
+        );
+    }
+
+    #[test]
+    fn test_amount_capturable_excludes_failed_captures() {
+        let captures = [
+            capture(400, storage_enums::CaptureStatus::Charged, false),
+            capture(100, storage_enums::CaptureStatus::Pending, false),
+            capture(300, storage_enums::CaptureStatus::Failed, false),
+        ];
+        assert_eq!(get_amount_capturable(1000, &captures), 500);
+        assert_eq!(get_amount_captured(&captures), 400);
+        assert!(validate_amount_to_capture(500, 600).is_err());
+        assert!(validate_amount_to_capture(500, 0).is_err());
+        assert!(validate_amount_to_capture(500, 500).is_ok());
+    }
+
+    #[test]
+    fn test_is_multiple_capture() {
+        use storage_enums::CaptureStatus;
+
+        assert!(!is_multiple_capture(&[capture(
+            1000,
+            CaptureStatus::Pending,
+            true
+        )]));
+        assert!(!is_multiple_capture(&[
+            capture(1000, CaptureStatus::Failed, true),
+            capture(1000, CaptureStatus::Pending, true),
+        ]));
+        assert!(is_multiple_capture(&[capture(
+            400,
+            CaptureStatus::Pending,
+            false
+        )]));
+        assert!(is_multiple_capture(&[
+            capture(400, CaptureStatus::Charged, true),
+            capture(600, CaptureStatus::Pending, true),
+        ]));
+    }
+
+    #[test]
+    fn test_validate_incremental_authorization() {
+        use storage_enums::IntentStatus;
+
+        assert!(
+            validate_incremental_authorization(IntentStatus::RequiresCapture, true, 500).is_ok()
+        );
+        assert!(
+            validate_incremental_authorization(IntentStatus::PartiallyCaptured, true, 500).is_err()
+        );
+        assert!(validate_incremental_authorization(IntentStatus::Succeeded, true, 500).is_err());
+        assert!(
+            validate_incremental_authorization(IntentStatus::RequiresCapture, false, 500).is_err()
+        );
+        assert!(
+            validate_incremental_authorization(IntentStatus::RequiresCapture, true, 0).is_err()
+        );
+    }
 }
@@ -2,6 +2,7 @@ pub mod payment_cancel;
 pub mod payment_capture;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
 pub mod payment_response;
 pub mod payment_session;
@@ -16,6 +17,7 @@ use router_env::{instrument, tracing};
 pub use self::{
     payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
     payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
     payment_method_validate::PaymentMethodValidate, payment_response::PaymentResponse,
     payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
     payment_update::PaymentUpdate,
@@ -34,6 +36,13 @@ use crate::{
 
 pub type BoxedOperation<'a, F, T> = Box<dyn Operation<F, T> + Send + Sync + 'a>;
 
+/// The resource locked while an operation runs, so that concurrent operations on the resource are
+/// serialized across application instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockOn {
+    PaymentId,
+}
+
 pub trait Operation<F: Clone, T>: Send + std::fmt::Debug {
     fn to_validate_request(&self) -> RouterResult<&(dyn ValidateRequest<F, T> + Send + Sync)> {
         Err(report!(errors::ApiErrorResponse::InternalServerError))
@@ -62,6 +71,10 @@ pub trait Operation<F: Clone, T>: Send + std::fmt::Debug {
             format!("post connector update tracker not found for {self:?}")
         })
     }
+    /// The resource locked while the operation runs, if any
+    fn lock_on(&self) -> Option<LockOn> {
+        None
+    }
 }
 
 pub struct ValidateResult<'a> {
@@ -102,6 +115,7 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<storage::Customer>), errors::StorageError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -170,6 +184,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRetrieveRequest>,
@@ -183,6 +198,7 @@ where
                 db,
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
+                storage_scheme,
             )
             .await?,
         ))
@@ -225,6 +241,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCaptureRequest>,
@@ -238,6 +255,7 @@ where
                 db,
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
+                storage_scheme,
             )
             .await?,
         ))
@@ -266,6 +284,64 @@ where
     }
 }
 
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+                storage_scheme,
+            )
+            .await?,
+        ))
+    }
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        Option<api::PaymentMethod>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, previously_used_connector).await
+    }
+}
+
 #[async_trait]
 impl<F: Clone + Send, Op: Send + Sync + Operation<F, api::PaymentsCancelRequest>>
     Domain<F, api::PaymentsCancelRequest> for Op
@@ -279,6 +355,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCancelRequest>,
@@ -292,6 +369,7 @@ where
                 db,
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
+                storage_scheme,
             )
             .await?,
         ))
@@ -22,7 +22,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
-#[operation(ops = "all", flow = "cancel")]
+#[operation(ops = "all", flow = "cancel", lock = "payment_id")]
 pub struct PaymentCancel;
 
 #[async_trait]
@@ -71,6 +71,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -79,6 +80,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -126,6 +128,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
+                    incremental_authorization: None,
+                    external_authentication_details: None,
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
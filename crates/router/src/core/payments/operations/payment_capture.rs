@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations},
@@ -17,11 +18,11 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
-#[operation(ops = "all", flow = "capture")]
+#[operation(ops = "all", flow = "capture", lock = "payment_id")]
 pub struct PaymentCapture;
 
 #[async_trait]
@@ -44,7 +45,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
         let db = &*state.store;
         let merchant_id = &merchant_account.merchant_id;
         let storage_scheme = merchant_account.storage_scheme;
-        let (payment_intent, mut payment_attempt, currency, amount);
+        let (payment_intent, payment_attempt, currency, amount);
 
         let payment_id = payment_id
             .get_payment_intent_id()
@@ -59,8 +60,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status(payment_intent.status)?;
 
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
-
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id(
                 &payment_id,
@@ -72,20 +71,79 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_capture_method(capture_method)?;
 
+        let mut captures = db
+            .find_captures_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                &payment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the captures of the payment")?;
+
+        let amount_capturable = helpers::get_amount_capturable(payment_attempt.amount, &captures);
+        let amount_to_capture = request.amount_to_capture.unwrap_or(amount_capturable);
+        helpers::validate_amount_to_capture(amount_capturable, amount_to_capture)?;
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.amount.into();
 
+        let connector = payment_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?;
+        // No more captures can be made once the amount left was captured
+        let final_capture =
+            request.final_capture.unwrap_or(true) || amount_to_capture == amount_capturable;
+
+        // Connectors which cannot report the status of each capture only report the status of the
+        // payment as a whole, so a payment can only be captured once with them
+        let is_multiple_capture = !final_capture
+            || captures
+                .iter()
+                .any(|capture| capture.status != enums::CaptureStatus::Failed);
+        if is_multiple_capture {
+            let connector_data = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &connector,
+                api::GetToken::Connector,
+            )?;
+            utils::when(
+                !connector_data.connector.supports_multiple_captures(),
+                || {
+                    Err(report!(errors::ApiErrorResponse::NotImplemented {
+                        message: errors::api_error_response::NotImplementedMessage::Reason(
+                            format!("Multiple captures of a payment with {connector}")
+                        ),
+                    }))
+                },
+            )?;
+        }
+
+        let capture = db
+            .insert_capture(storage::CaptureNew {
+                capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+                payment_id: payment_id.clone(),
+                merchant_id: merchant_id.to_owned(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                status: enums::CaptureStatus::Started,
+                amount: amount_to_capture,
+                currency,
+                connector,
+                final_capture,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert capture")?;
+        captures.push(capture);
+
         let connector_response = db
             .find_connector_response_by_payment_id_merchant_id_attempt_id(
                 &payment_attempt.payment_id,
@@ -104,6 +162,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -113,6 +172,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -136,6 +196,9 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                captures,
+                incremental_authorization: None,
+                external_authentication_details: None,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
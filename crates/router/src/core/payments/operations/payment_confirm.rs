@@ -24,7 +24,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
-#[operation(ops = "all", flow = "authorize")]
+#[operation(ops = "all", flow = "authorize", lock = "payment_id")]
 pub struct PaymentConfirm;
 
 #[async_trait]
@@ -131,6 +131,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -139,6 +140,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -179,6 +181,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 payment_method_data: request.payment_method_data.clone(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorization: None,
+                external_authentication_details: request.external_authentication_details.clone(),
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
             },
@@ -202,6 +207,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -215,6 +221,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -75,6 +75,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             None,
             merchant_id,
             &request.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -84,6 +85,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             None,
             merchant_id,
             &request.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -152,7 +154,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .as_ref()
             .async_and_then(|mandate_id| async {
                 let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
                     .await
                     .change_context(errors::ApiErrorResponse::MandateNotFound);
                 Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
@@ -188,6 +190,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorization: None,
+                external_authentication_details: request.external_authentication_details.clone(),
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
@@ -213,6 +218,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -226,6 +232,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -472,12 +479,16 @@ impl PaymentCreate {
             client_secret: Some(client_secret),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
+            request_incremental_authorization: request
+                .request_incremental_authorization
+                .unwrap_or(false),
             return_url: request.return_url.clone(),
             shipping_address_id,
             billing_address_id,
             statement_descriptor_name: request.statement_descriptor_name.clone(),
             statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
             metadata,
+            attempt_count: 1,
             ..storage::PaymentIntentNew::default()
         })
     }
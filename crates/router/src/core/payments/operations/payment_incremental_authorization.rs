@@ -0,0 +1,234 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{self, helpers, operations},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "incrementalauthorization", lock = "payment_id")]
+pub struct PaymentIncrementalAuthorization;
+
+#[async_trait]
+impl<F: Send + Clone>
+    GetTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+        Option<payments::CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        helpers::validate_incremental_authorization(
+            payment_intent.status,
+            payment_intent.request_incremental_authorization,
+            request.amount,
+        )?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                &payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        // Increments add to the amount authorized so far, which is not known until the outcome of
+        // an increment still being processed by the connector is reported
+        let authorizations = db
+            .find_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                &payment_id,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the incremental authorizations of the payment")?;
+        utils::when(
+            authorizations.iter().any(|authorization| {
+                authorization.status == enums::AuthorizationStatus::Processing
+            }),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "An incremental authorization of the payment is still being processed"
+                        .to_string()
+                }))
+            },
+        )?;
+
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        let amount = payment_attempt.amount.into();
+
+        let incremental_authorization = db
+            .insert_incremental_authorization(storage::IncrementalAuthorizationNew {
+                authorization_id: utils::generate_id(consts::ID_LENGTH, "auth"),
+                payment_id: payment_id.clone(),
+                merchant_id: merchant_id.to_owned(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                status: enums::AuthorizationStatus::Started,
+                amount: payment_attempt.amount + request.amount,
+                previously_authorized_amount: payment_attempt.amount,
+                currency,
+                connector: payment_attempt
+                    .connector
+                    .clone()
+                    .get_required_value("connector")?,
+                reason: request.reason.clone(),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert incremental authorization")?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+            storage_scheme,
+        )
+        .await?;
+
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+            storage_scheme,
+        )
+        .await?;
+
+        Ok((
+            Box::new(self),
+            payments::PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                force_sync: None,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                token: None,
+                address: payments::PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                refunds: vec![],
+                captures: vec![],
+                incremental_authorization: Some(incremental_authorization),
+                external_authentication_details: None,
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone>
+    UpdateTracker<F, payments::PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: payments::PaymentData<F>,
+        _customer: Option<storage::Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        payments::PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+            },
+        ))
+    }
+}
@@ -149,6 +149,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorization: None,
+                external_authentication_details: None,
                 sessions_token: vec![],
                 card_cvc: None,
             },
@@ -219,6 +222,7 @@ where
         payment_data: &mut PaymentData<F>,
         request: Option<payments::CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::VerifyRequest>,
@@ -232,6 +236,7 @@ where
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -312,6 +317,7 @@ impl PaymentMethodValidate {
             client_secret: Some(client_secret),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
+            attempt_count: 1,
             ..Default::default()
         }
     }
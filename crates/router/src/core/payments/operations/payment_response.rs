@@ -1,12 +1,13 @@
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_derive;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{helpers, PaymentData},
+        routing,
     },
     db::StorageInterface,
     services::RedirectForm,
@@ -21,7 +22,7 @@ use crate::{
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,incrementalauthorizationdata,verifydata,sessiondata"
 )]
 pub struct PaymentResponse;
 
@@ -66,8 +67,12 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
-            .await
+        if payment_data.captures.is_empty() {
+            payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+                .await
+        } else {
+            captures_sync_update_tracker(db, payment_data, response, storage_scheme).await
+        }
     }
 }
 
@@ -98,16 +103,137 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
     async fn update_tracker<'b>(
         &'b self,
         db: &dyn StorageInterface,
-        payment_id: &api::PaymentIdType,
-        payment_data: PaymentData<F>,
-        response: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        router_data: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
-        payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
+        let capture_update = match &router_data.response {
+            Err(error) => storage::CaptureUpdate::ErrorUpdate {
+                status: enums::CaptureStatus::Failed,
+                error_message: Some(error.message.clone()),
+                error_code: Some(error.code.clone()),
+            },
+            Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+                storage::CaptureUpdate::ResponseUpdate {
+                    status: router_data.status.foreign_into(),
+                    // Connectors which do not reference captures separately respond with the
+                    // reference of the payment
+                    connector_capture_id: resource_id.get_connector_transaction_id().ok(),
+                }
+            }
+            Ok(types::PaymentsResponseData::SessionResponse { .. })
+            | Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => {
+                storage::CaptureUpdate::StatusUpdate {
+                    status: router_data.status.foreign_into(),
+                }
+            }
+        };
+
+        let capture = payment_data
+            .captures
+            .iter_mut()
+            .find(|capture| capture.capture_id == router_data.request.capture_id)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Capture made with the connector was not found")?;
+        *capture = db
+            .update_capture(capture.clone(), capture_update)
             .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update capture")?;
+
+        let payment_data = update_payment_with_captures(db, payment_data, storage_scheme).await?;
+
+        router_data.response.map_err(|error_response| {
+            errors::ApiErrorResponse::ExternalConnectorError {
+                message: error_response.message,
+                code: error_response.code,
+                status_code: error_response.status_code,
+                connector: router_data.connector,
+            }
+        })?;
+
+        Ok(payment_data)
+    }
+}
+
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        router_data: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        let authorization_update = match &router_data.response {
+            Err(error) => storage::IncrementalAuthorizationUpdate::ErrorUpdate {
+                status: enums::AuthorizationStatus::Failure,
+                error_message: Some(error.message.clone()),
+                error_code: Some(error.code.clone()),
+            },
+            Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+                storage::IncrementalAuthorizationUpdate::ResponseUpdate {
+                    status: router_data.status.foreign_into(),
+                    connector_authorization_id: resource_id.get_connector_transaction_id().ok(),
+                }
+            }
+            Ok(types::PaymentsResponseData::SessionResponse { .. })
+            | Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => {
+                storage::IncrementalAuthorizationUpdate::ResponseUpdate {
+                    status: router_data.status.foreign_into(),
+                    connector_authorization_id: None,
+                }
+            }
+        };
+
+        let authorization = payment_data
+            .incremental_authorization
+            .take()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Incremental authorization made with the connector was not found")?;
+        // Connectors which acknowledge the increment before the issuer approves it leave the
+        // authorization processing, until its outcome is reported by a webhook
+        let (authorization, payment_attempt, payment_intent) =
+            helpers::update_incremental_authorization(
+                db,
+                authorization,
+                authorization_update,
+                payment_data.payment_attempt,
+                payment_data.payment_intent,
+                storage_scheme,
+            )
+            .await?;
+        payment_data.amount = payment_attempt.amount.into();
+        payment_data.payment_attempt = payment_attempt;
+        payment_data.payment_intent = payment_intent;
+        payment_data.incremental_authorization = Some(authorization);
+
+        router_data.response.map_err(|error_response| {
+            errors::ApiErrorResponse::ExternalConnectorError {
+                message: error_response.message,
+                code: error_response.code,
+                status_code: error_response.status_code,
+                connector: router_data.connector,
+            }
+        })?;
+
+        Ok(payment_data)
     }
 }
 
@@ -180,6 +306,8 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 redirection_data,
                 redirect,
                 connector_metadata,
+                authentication_details,
+                next_action_data,
                 ..
             } => {
                 let connector_transaction_id = match resource_id {
@@ -197,6 +325,31 @@ async fn payment_response_update_tracker<F: Clone, T>(
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Could not parse the connector response")?;
 
+                // Connectors report the result of the authentication they performed, and
+                // otherwise the result of the external authentication is recorded
+                let authentication_details = authentication_details
+                    .or_else(|| payment_data.external_authentication_details.clone())
+                    .map(|details| {
+                        utils::Encode::<api::AuthenticationDetails>::encode_to_value(&details)
+                    })
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not encode the authentication details")?;
+
+                let next_action_data = next_action_data
+                    .map(|data| utils::Encode::<api::NextActionData>::encode_to_value(&data))
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not encode the next action data")?;
+                // Connectors don't report the next action again on every sync, so the previous one
+                // is kept as long as the status of the attempt stays the same, and cleared once
+                // the status changes, such as when the payment succeeds or the QR code expires
+                let next_action_data = match next_action_data {
+                    Some(data) => Some(Some(data)),
+                    None if router_data.status != payment_data.payment_attempt.status => Some(None),
+                    None => None,
+                };
+
                 let payment_attempt_update = storage::PaymentAttemptUpdate::ResponseUpdate {
                     status: router_data.status,
                     connector: Some(router_data.connector),
@@ -209,6 +362,8 @@ async fn payment_response_update_tracker<F: Clone, T>(
                         .clone()
                         .map(|mandate| mandate.mandate_id),
                     connector_metadata,
+                    authentication_details,
+                    next_action_data,
                 };
 
                 let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
@@ -224,10 +379,12 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 )
             }
 
-            types::PaymentsResponseData::SessionResponse { .. } => (None, None),
+            types::PaymentsResponseData::SessionResponse { .. }
+            | types::PaymentsResponseData::MultipleCaptureResponse { .. } => (None, None),
         },
     };
 
+    let previous_attempt_status = payment_data.payment_attempt.status;
     payment_data.payment_attempt = match payment_attempt_update {
         Some(payment_attempt_update) => db
             .update_payment_attempt(
@@ -241,6 +398,14 @@ async fn payment_response_update_tracker<F: Clone, T>(
             })?,
         None => payment_data.payment_attempt,
     };
+    // Every flow of the payment which updates its attempt from the connector response reaches
+    // this point, including the syncs which complete redirections and handle webhooks
+    routing::success_rate::record_payment_outcome(
+        db,
+        previous_attempt_status,
+        &payment_data.payment_attempt,
+    )
+    .await;
 
     payment_data.connector_response = match connector_response_update {
         Some(connector_response_update) => db
@@ -287,3 +452,104 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     Ok(payment_data)
 }
+
+/// Settle the captures still in progress with the status of the payment reported by the connector
+async fn captures_sync_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsSyncData, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let capture_statuses = match router_data.response {
+        Err(error_response) => {
+            return Err(errors::ApiErrorResponse::ExternalConnectorError {
+                message: error_response.message,
+                code: error_response.code,
+                status_code: error_response.status_code,
+                connector: router_data.connector,
+            }
+            .into())
+        }
+        Ok(types::PaymentsResponseData::MultipleCaptureResponse { captures }) => captures,
+        // Connectors which cannot report the status of each capture only make a single capture
+        // of the payment, whose status is reported as the status of the payment
+        Ok(_) => {
+            let capture_status: enums::CaptureStatus = router_data.status.foreign_into();
+            payment_data
+                .captures
+                .iter()
+                .filter(|capture| capture.status == enums::CaptureStatus::Pending)
+                .filter_map(|capture| capture.connector_capture_id.clone())
+                .map(|connector_capture_id| (connector_capture_id, capture_status))
+                .collect()
+        }
+    };
+
+    for capture in payment_data
+        .captures
+        .iter_mut()
+        .filter(|capture| capture.status == enums::CaptureStatus::Pending)
+    {
+        let capture_status = match capture
+            .connector_capture_id
+            .as_ref()
+            .and_then(|connector_capture_id| capture_statuses.get(connector_capture_id))
+        {
+            Some(&capture_status) if capture_status != enums::CaptureStatus::Pending => {
+                capture_status
+            }
+            _ => continue,
+        };
+        *capture = db
+            .update_capture(
+                capture.clone(),
+                storage::CaptureUpdate::StatusUpdate {
+                    status: capture_status,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update capture")?;
+    }
+
+    update_payment_with_captures(db, payment_data, storage_scheme).await
+}
+
+/// Update the status of the payment and the amount captured from the captures of the payment
+/// attempt
+async fn update_payment_with_captures<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    let attempt_status = helpers::multiple_capture_attempt_status_fsm(
+        payment_data.payment_attempt.amount,
+        &payment_data.captures,
+    );
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::StatusUpdate {
+                status: attempt_status,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::ResponseUpdate {
+                status: attempt_status.foreign_into(),
+                amount_captured: Some(helpers::get_amount_captured(&payment_data.captures)),
+                return_url: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(payment_data)
+}
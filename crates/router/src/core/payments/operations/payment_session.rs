@@ -97,6 +97,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -106,6 +107,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -154,6 +156,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorization: None,
+                external_authentication_details: None,
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
@@ -242,6 +247,7 @@ where
         payment_data: &mut PaymentData<F>,
         request: Option<payments::CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> errors::CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsSessionRequest>,
@@ -255,6 +261,7 @@ where
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
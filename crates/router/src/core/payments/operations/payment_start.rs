@@ -87,6 +87,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
         let billing_address = helpers::get_address_for_payment_request(
@@ -95,6 +96,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -141,6 +143,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
+                incremental_authorization: None,
+                external_authentication_details: None,
                 sessions_token: vec![],
                 card_cvc: None,
             },
@@ -214,6 +219,7 @@ where
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsStartRequest>,
@@ -227,6 +233,7 @@ where
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
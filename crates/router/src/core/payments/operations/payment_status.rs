@@ -57,6 +57,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -70,6 +71,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -227,10 +229,18 @@ async fn get_tracker_for_sync<
     currency = payment_attempt.currency.get_required_value("currency")?;
     amount = payment_attempt.amount.into();
 
-    let shipping_address =
-        helpers::get_address_by_id(db, payment_intent.shipping_address_id.clone()).await?;
-    let billing_address =
-        helpers::get_address_by_id(db, payment_intent.billing_address_id.clone()).await?;
+    let shipping_address = helpers::get_address_by_id(
+        db,
+        payment_intent.shipping_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
+    let billing_address = helpers::get_address_by_id(
+        db,
+        payment_intent.billing_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
 
     let refunds = db
         .find_refund_by_payment_id_merchant_id(&payment_id_str, merchant_id, storage_scheme)
@@ -243,6 +253,16 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let captures = db
+        .find_captures_by_merchant_id_payment_id_attempt_id(
+            merchant_id,
+            &payment_id_str,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the captures of the payment")?;
+
     Ok((
         Box::new(operation),
         PaymentData {
@@ -266,6 +286,9 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            captures,
+            incremental_authorization: None,
+            external_authentication_details: None,
             sessions_token: vec![],
             card_cvc: None,
         },
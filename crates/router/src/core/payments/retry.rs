@@ -0,0 +1,347 @@
+use std::fmt::Debug;
+
+use api_models::enums as api_enums;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use uuid::Uuid;
+
+use super::{
+    call_connector_service,
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::Operation,
+    CallConnectorAction, PaymentCreate, PaymentData, PaymentResponse,
+};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api, storage, storage::enums as storage_enums},
+    utils::StringExt,
+};
+
+/// Payments are retried only while being confirmed, since that is when the payment is
+/// authorized with the connector
+pub fn should_retry_payment<Op: Debug>(
+    operation: &Op,
+    call_connector_action: &CallConnectorAction,
+) -> bool {
+    matches!(call_connector_action, CallConnectorAction::Trigger)
+        && format!("{operation:?}") == "PaymentConfirm"
+}
+
+fn get_retry_policy_key(merchant_id: &str) -> String {
+    format!("payment_retry_policy_{merchant_id}")
+}
+
+/// Retries are disabled for merchants who have not configured a retry policy
+#[instrument(skip_all)]
+pub async fn get_retry_policy(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<api::PaymentRetryPolicy>> {
+    let config = match db
+        .find_config_by_key(&get_retry_policy_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment retry policy")
+        }
+    };
+
+    config
+        .config
+        .parse_struct("PaymentRetryPolicy")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to deserialize the payment retry policy")
+        .map(Some)
+}
+
+/// A failed attempt is retryable if the connector classifies its error code as a soft decline,
+/// or if the merchant has configured the error code to be retried for that connector
+pub fn is_retryable_failure(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+    retry_policy: &api::PaymentRetryPolicy,
+) -> bool {
+    if payment_attempt.status != storage_enums::AttemptStatus::Failure {
+        return false;
+    }
+
+    let (connector_name, error_code) = match (
+        payment_attempt.connector.as_deref(),
+        payment_attempt.error_code.as_deref(),
+    ) {
+        (Some(connector_name), Some(error_code)) => (connector_name, error_code),
+        _ => return false,
+    };
+
+    let is_retryable_for_merchant = connector_name
+        .parse::<api_enums::RoutableConnectors>()
+        .ok()
+        .and_then(|connector| retry_policy.retryable_error_codes.get(&connector))
+        .map_or(false, |error_codes| {
+            error_codes.iter().any(|code| code == error_code)
+        });
+
+    is_retryable_for_merchant
+        || api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            connector_name,
+            api::GetToken::Connector,
+        )
+        .map_or(false, |connector_data| {
+            connector_data.connector.is_retryable_error_code(error_code)
+        })
+}
+
+/// Call the connector, and on retryable failures retry the payment with the fallback connectors
+/// in order, creating a new payment attempt for every retry
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn call_connector_service_with_retries<F, Op, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    connector: api::ConnectorData,
+    fallback_connectors: Vec<api_enums::RoutableConnectors>,
+    operation: &Op,
+    payment_data: PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    lock: Option<&services::locking::Lock>,
+) -> RouterResult<PaymentData<F>>
+where
+    Op: Debug + Sync,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let mut payment_data = call_connector_service(
+        state,
+        merchant_account,
+        payment_id,
+        connector,
+        operation,
+        payment_data,
+        customer,
+        CallConnectorAction::Trigger,
+        lock,
+    )
+    .await?;
+
+    if fallback_connectors.is_empty() {
+        return Ok(payment_data);
+    }
+
+    let retry_policy = match get_retry_policy(&*state.store, &merchant_account.merchant_id).await? {
+        Some(retry_policy) => retry_policy,
+        None => return Ok(payment_data),
+    };
+
+    for next_connector in fallback_connectors
+        .into_iter()
+        .take(retry_policy.max_retries.into())
+    {
+        if !is_retryable_failure(state, &payment_data.payment_attempt, &retry_policy) {
+            break;
+        }
+
+        let connector_name = next_connector.to_string();
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector_name,
+            api::GetToken::Connector,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Routing algorithm gave invalid connector")?;
+
+        logger::info!(
+            previous_attempt_id = %payment_data.payment_attempt.attempt_id,
+            previous_connector = ?payment_data.payment_attempt.connector,
+            error_code = ?payment_data.payment_attempt.error_code,
+            next_connector = %connector_name,
+            "Retrying payment with the next connector"
+        );
+
+        core_utils::check_resource_lock(lock).await?;
+        payment_data =
+            create_retry_attempt(state, merchant_account, payment_data, connector_name).await?;
+
+        payment_data = call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            connector_data,
+            operation,
+            payment_data,
+            customer,
+            CallConnectorAction::Trigger,
+            lock,
+        )
+        .await?;
+    }
+
+    Ok(payment_data)
+}
+
+/// Create a new payment attempt against the given connector, keeping the details of the
+/// previous attempt, and make it the active attempt of the payment
+async fn create_retry_attempt<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    connector_name: String,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Clone,
+{
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_attempt = db
+        .insert_payment_attempt(
+            make_retry_payment_attempt(&payment_data.payment_attempt, connector_name),
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_data.payment_attempt.payment_id.clone(),
+            })
+        })?;
+
+    let connector_response = db
+        .insert_connector_response(
+            PaymentCreate::make_connector_response(&payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: payment_attempt.payment_id.clone(),
+            })
+        })?;
+
+    let attempt_count = payment_data.payment_intent.attempt_count + 1;
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::AttemptCountUpdate { attempt_count },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the attempt count of the payment")?;
+
+    payment_data.payment_attempt = payment_attempt;
+    payment_data.connector_response = connector_response;
+
+    Ok(payment_data)
+}
+
+fn make_retry_payment_attempt(
+    previous_attempt: &storage::PaymentAttempt,
+    connector_name: String,
+) -> storage::PaymentAttemptNew {
+    let created_at @ modified_at = Some(common_utils::date_time::now());
+
+    storage::PaymentAttemptNew {
+        payment_id: previous_attempt.payment_id.clone(),
+        merchant_id: previous_attempt.merchant_id.clone(),
+        attempt_id: Uuid::new_v4().to_string(),
+        status: storage_enums::AttemptStatus::Pending,
+        amount: previous_attempt.amount,
+        currency: previous_attempt.currency,
+        save_to_locker: previous_attempt.save_to_locker,
+        connector: Some(connector_name),
+        offer_amount: previous_attempt.offer_amount,
+        surcharge_amount: previous_attempt.surcharge_amount,
+        tax_amount: previous_attempt.tax_amount,
+        payment_method_id: previous_attempt.payment_method_id.clone(),
+        payment_method: previous_attempt.payment_method,
+        payment_flow: previous_attempt.payment_flow,
+        capture_method: previous_attempt.capture_method,
+        capture_on: previous_attempt.capture_on,
+        confirm: previous_attempt.confirm,
+        authentication_type: previous_attempt.authentication_type,
+        created_at,
+        modified_at,
+        amount_to_capture: previous_attempt.amount_to_capture,
+        mandate_id: previous_attempt.mandate_id.clone(),
+        browser_info: previous_attempt.browser_info.clone(),
+        payment_token: previous_attempt.payment_token.clone(),
+        ..storage::PaymentAttemptNew::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_retry_payment_attempt() {
+        let now = common_utils::date_time::now();
+        let previous_attempt = storage::PaymentAttempt {
+            id: 1,
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            attempt_id: "attempt_1".to_string(),
+            status: storage_enums::AttemptStatus::Failure,
+            amount: 100,
+            currency: Some(storage_enums::Currency::USD),
+            save_to_locker: None,
+            connector: Some("stripe".to_string()),
+            error_message: Some("Rate limited".to_string()),
+            offer_amount: None,
+            surcharge_amount: None,
+            tax_amount: None,
+            payment_method_id: None,
+            payment_method: Some(storage_enums::PaymentMethodType::Card),
+            payment_flow: None,
+            redirect: None,
+            connector_transaction_id: Some("txn_1".to_string()),
+            capture_method: Some(storage_enums::CaptureMethod::Automatic),
+            capture_on: None,
+            confirm: true,
+            authentication_type: Some(storage_enums::AuthenticationType::NoThreeDs),
+            created_at: now,
+            modified_at: now,
+            last_synced: None,
+            cancellation_reason: None,
+            amount_to_capture: None,
+            mandate_id: None,
+            browser_info: None,
+            error_code: Some("rate_limit".to_string()),
+            payment_token: None,
+            connector_metadata: None,
+            authentication_details: None,
+            next_action_data: None,
+        };
+
+        let retry_attempt = make_retry_payment_attempt(&previous_attempt, "adyen".to_string());
+
+        assert_ne!(retry_attempt.attempt_id, previous_attempt.attempt_id);
+        assert_eq!(retry_attempt.payment_id, previous_attempt.payment_id);
+        assert_eq!(retry_attempt.status, storage_enums::AttemptStatus::Pending);
+        assert_eq!(retry_attempt.connector.as_deref(), Some("adyen"));
+        assert_eq!(retry_attempt.amount, previous_attempt.amount);
+        assert!(retry_attempt.error_code.is_none());
+        assert!(retry_attempt.connector_transaction_id.is_none());
+    }
+}
@@ -68,6 +68,8 @@ where
             redirect: false,
             mandate_reference: None,
             connector_metadata: None,
+            authentication_details: None,
+            next_action_data: None,
         });
 
     let router_return_url = Some(helpers::create_redirect_url(
@@ -138,6 +140,7 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.captures,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -227,6 +230,7 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    captures: Vec<storage::Capture>,
     payment_method_data: Option<api::PaymentMethod>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -250,6 +254,30 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let authentication_details = payment_attempt
+        .authentication_details
+        .clone()
+        .map(|details| details.parse_value("AuthenticationDetails"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the authentication details of the payment attempt")?;
+    let next_action_data: Option<api::NextActionData> = payment_attempt
+        .next_action_data
+        .clone()
+        .map(|data| data.parse_value("NextActionData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the next action data of the payment attempt")?;
+    let captures_response = if captures.is_empty() {
+        None
+    } else {
+        Some(
+            captures
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
 
     Ok(match payment_request {
         Some(request) => {
@@ -264,14 +292,20 @@ where
                     .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
                 let mut next_action_response = None;
                 if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
-                    next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
-                            server,
-                            &payment_attempt,
-                            &payment_intent,
-                        )),
-                    })
+                    // Customers are redirected unless the connector asked for another action
+                    next_action_response = Some(next_action_data.map_or_else(
+                        || api::NextAction {
+                            next_action_type: api::NextActionType::RedirectToUrl,
+                            redirect_to_url: Some(helpers::create_startpay_url(
+                                server,
+                                &payment_attempt,
+                                &payment_intent,
+                            )),
+                            qr_code_information: None,
+                            sdk_client_information: None,
+                        },
+                        api::NextAction::from,
+                    ))
                 }
 
                 services::ApplicationResponse::Json(
@@ -305,6 +339,7 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_captures(captures_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -329,6 +364,7 @@ where
                                 .authentication_type
                                 .map(ForeignInto::foreign_into),
                         )
+                        .set_authentication_details(authentication_details)
                         .set_statement_descriptor_name(payment_intent.statement_descriptor_name)
                         .set_statement_descriptor_suffix(payment_intent.statement_descriptor_suffix)
                         .set_setup_future_usage(
@@ -358,6 +394,7 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            captures: captures_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -381,6 +418,7 @@ where
             billing: address.billing,
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
+            authentication_details,
             ..Default::default()
         }),
     })
@@ -431,6 +469,10 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             browser_info,
             email: payment_data.email,
             order_details,
+            request_incremental_authorization: payment_data
+                .payment_intent
+                .request_incremental_authorization,
+            external_authentication_details: payment_data.external_authentication_details,
         })
     }
 }
@@ -448,6 +490,18 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSyncData {
             },
             encoded_data: payment_data.connector_response.encoded_data,
             capture_method: payment_data.payment_attempt.capture_method,
+            sync_type: if helpers::is_multiple_capture(&payment_data.captures) {
+                types::SyncRequestType::MultipleCaptureSync(
+                    payment_data
+                        .captures
+                        .iter()
+                        .filter(|capture| capture.status == enums::CaptureStatus::Pending)
+                        .filter_map(|capture| capture.connector_capture_id.clone())
+                        .collect(),
+                )
+            } else {
+                types::SyncRequestType::SinglePaymentSync
+            },
         })
     }
 }
@@ -456,14 +510,44 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
     type Error = errors::ApiErrorResponse;
 
     fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        // The capture being made is the last capture of the payment
+        let capture = payment_data
+            .captures
+            .last()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)?;
         Ok(Self {
-            amount_to_capture: payment_data.payment_attempt.amount_to_capture,
+            amount_to_capture: Some(capture.amount),
             currency: payment_data.currency,
             connector_transaction_id: payment_data
                 .payment_attempt
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            capture_id: capture.capture_id.clone(),
+            final_capture: capture.final_capture,
+        })
+    }
+}
+
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsIncrementalAuthorizationData {
+    type Error = errors::ApiErrorResponse;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let authorization = payment_data
+            .incremental_authorization
+            .ok_or(errors::ApiErrorResponse::InternalServerError)?;
+        Ok(Self {
+            total_amount: authorization.amount,
+            additional_amount: authorization.amount - authorization.previously_authorized_amount,
+            currency: authorization.currency,
+            connector_transaction_id: payment_data
+                .payment_attempt
+                .connector_transaction_id
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "connector_transaction_id",
+                })?,
+            reason: authorization.reason,
+            authorization_id: authorization.authorization_id,
         })
     }
 }
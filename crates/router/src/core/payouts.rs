@@ -0,0 +1,779 @@
+use std::fmt::Debug;
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments, routing, utils as core_utils,
+    },
+    db, logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, payouts},
+        storage::{self, enums},
+        transformers::{Foreign, ForeignInto},
+    },
+    utils,
+};
+
+/// Statuses in which a payout can be updated, before it is sent to the connector
+const UPDATABLE_STATUSES: &[enums::PayoutStatus] = &[enums::PayoutStatus::RequiresConfirmation];
+
+/// Statuses in which a payout can be confirmed, before it is sent to the connector
+const CONFIRMABLE_STATUSES: &[enums::PayoutStatus] = &[enums::PayoutStatus::RequiresConfirmation];
+
+/// Statuses in which a payout can be cancelled, before it is executed by the connector
+const CANCELLABLE_STATUSES: &[enums::PayoutStatus] = &[
+    enums::PayoutStatus::RequiresConfirmation,
+    enums::PayoutStatus::RequiresFulfillment,
+];
+
+/// Statuses in which a payout can be reversed, once it was executed by the connector
+const REVERSIBLE_STATUSES: &[enums::PayoutStatus] = &[enums::PayoutStatus::Success];
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })?;
+
+    let confirm = req.confirm.unwrap_or(false);
+    utils::when(confirm && req.payout_method_data.is_none(), || {
+        Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_method_data",
+        })
+    })?;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+    let connector = get_connector_name(&merchant_account, &req)?;
+
+    let payout_new = storage::PayoutNew::default()
+        .set_payout_id(payout_id.clone())
+        .set_merchant_id(merchant_account.merchant_id.clone())
+        .set_customer_id(req.customer_id)
+        .set_connector(connector)
+        .set_payout_type(
+            req.payout_method_data
+                .as_ref()
+                .map(|payout_method_data| payout_method_data.get_payout_type().foreign_into()),
+        )
+        .set_amount(req.amount)
+        .set_currency(req.currency.foreign_into())
+        .set_status(enums::PayoutStatus::RequiresConfirmation)
+        .set_description(req.description)
+        .set_metadata(req.metadata)
+        .set_created_at(Some(common_utils::date_time::now()))
+        .set_modified_at(Some(common_utils::date_time::now()))
+        .to_owned();
+
+    let payout = db.insert_payout(payout_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+    })?;
+
+    let payout = match req.payout_method_data {
+        Some(payout_method_data) if confirm => {
+            confirm_payout(
+                state,
+                &merchant_account,
+                &payout.payout_id,
+                payout_method_data,
+                req.email,
+            )
+            .await?
+        }
+        _ => payout,
+    };
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(&*state.store, &merchant_account, &payout_id).await?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT UPDATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_update_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: &str,
+    req: payouts::PayoutUpdateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let lock = acquire_payout_lock(state, &merchant_account, payout_id).await?;
+    let payout = find_payout(db, &merchant_account, payout_id).await?;
+
+    validate_payout_status(&payout, UPDATABLE_STATUSES, "updated")?;
+
+    utils::when(req.amount.map_or(false, |amount| amount <= 0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than or equal to zero"))
+    })?;
+
+    let payout_update = storage::PayoutUpdate::Update {
+        amount: req.amount,
+        currency: req.currency.map(ForeignInto::foreign_into),
+        payout_type: None,
+        description: req.description,
+        metadata: req.metadata,
+    };
+
+    core_utils::check_resource_lock(Some(&lock)).await?;
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update payout with payout_id: {payout_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT CONFIRM **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_confirm_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: &str,
+    req: payouts::PayoutConfirmRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = confirm_payout(
+        state,
+        &merchant_account,
+        payout_id,
+        req.payout_method_data,
+        req.email,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+/// Creates the payout at the connector and, for connectors which separate the creation of a
+/// payout from its execution, fulfills it right after.
+#[instrument(skip_all)]
+pub async fn confirm_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+    payout_method_data: payouts::PayoutMethodData,
+    email: Option<masking::Secret<String, common_utils::pii::Email>>,
+) -> RouterResult<storage::Payout> {
+    let db = &*state.store;
+    let lock = acquire_payout_lock(state, merchant_account, payout_id).await?;
+    let payout = find_payout(db, merchant_account, payout_id).await?;
+
+    validate_payout_status(&payout, CONFIRMABLE_STATUSES, "confirmed")?;
+
+    let payout_type = payout_method_data.get_payout_type().foreign_into();
+    let payout = if payout.payout_type != Some(payout_type) {
+        core_utils::check_resource_lock(Some(&lock)).await?;
+        db.update_payout(
+            payout,
+            storage::PayoutUpdate::Update {
+                amount: None,
+                currency: None,
+                payout_type: Some(payout_type),
+                description: None,
+                metadata: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating payout type")?
+    } else {
+        payout
+    };
+
+    let router_data_res = call_connector_payout::<api::PoCreate>(
+        state,
+        merchant_account,
+        &payout,
+        Some(payout_method_data.clone()),
+        email.clone(),
+    )
+    .await?;
+    core_utils::check_resource_lock(Some(&lock)).await?;
+    let payout = update_payout_with_connector_response(
+        db,
+        payout,
+        router_data_res.response,
+        enums::PayoutStatus::Failed,
+    )
+    .await?;
+
+    if payout.status != enums::PayoutStatus::RequiresFulfillment {
+        return Ok(payout);
+    }
+
+    let router_data_res = call_connector_payout::<api::PoFulfill>(
+        state,
+        merchant_account,
+        &payout,
+        Some(payout_method_data),
+        email,
+    )
+    .await?;
+    core_utils::check_resource_lock(Some(&lock)).await?;
+    update_payout_with_connector_response(
+        db,
+        payout,
+        router_data_res.response,
+        enums::PayoutStatus::Failed,
+    )
+    .await
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let lock = acquire_payout_lock(state, &merchant_account, &payout_id).await?;
+    let payout = find_payout(db, &merchant_account, &payout_id).await?;
+
+    validate_payout_status(&payout, CANCELLABLE_STATUSES, "cancelled")?;
+
+    let payout = if payout.status == enums::PayoutStatus::RequiresConfirmation {
+        // The payout was never sent to the connector, there is nothing to cancel there
+        core_utils::check_resource_lock(Some(&lock)).await?;
+        db.update_payout(
+            payout,
+            storage::PayoutUpdate::StatusUpdate {
+                connector_payout_id: None,
+                status: enums::PayoutStatus::Cancelled,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to cancel payout with payout_id: {payout_id}"))?
+    } else {
+        let router_data_res =
+            call_connector_payout::<api::PoCancel>(state, &merchant_account, &payout, None, None)
+                .await?;
+        let fallback_status = payout.status;
+        core_utils::check_resource_lock(Some(&lock)).await?;
+        update_payout_with_connector_response(db, payout, router_data_res.response, fallback_status)
+            .await?
+    };
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT REVERSE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_reverse_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let lock = acquire_payout_lock(state, &merchant_account, &payout_id).await?;
+    let payout = find_payout(db, &merchant_account, &payout_id).await?;
+
+    validate_payout_status(&payout, REVERSIBLE_STATUSES, "reversed")?;
+
+    let router_data_res =
+        call_connector_payout::<api::PoReverse>(state, &merchant_account, &payout, None, None)
+            .await?;
+    let fallback_status = payout.status;
+    core_utils::check_resource_lock(Some(&lock)).await?;
+    let payout = update_payout_with_connector_response(
+        db,
+        payout,
+        router_data_res.response,
+        fallback_status,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(payout.foreign_into()))
+}
+
+// ********************************************** PAYOUT LIST **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn payouts_list_core(
+    db: &dyn db::StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    let limit = constraints.limit.unwrap_or(10).clamp(1, 100);
+
+    let payouts = db
+        .list_payouts_by_merchant_id(
+            &merchant_account.merchant_id,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payouts")?;
+
+    let data: Vec<payouts::PayoutResponse> =
+        payouts.into_iter().map(ForeignInto::foreign_into).collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** PAYOUT ACCOUNTS **********************************************
+
+#[instrument(skip_all)]
+#[cfg(feature = "olap")]
+pub async fn payouts_accounts_core(
+    db: &dyn db::StorageInterface,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<payouts::PayoutAccountsResponse> {
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant connector accounts")?;
+
+    let accounts = merchant_connector_accounts
+        .into_iter()
+        .filter(|account| account.disabled != Some(true))
+        .map(|account| payouts::PayoutAccount {
+            connector: account.connector_name,
+            merchant_connector_id: account.merchant_connector_id,
+            test_mode: account.test_mode,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAccountsResponse { accounts },
+    ))
+}
+
+// ********************************************** HELPERS **********************************************
+
+async fn find_payout(
+    db: &dyn db::StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<storage::Payout> {
+    db.find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))
+}
+
+/// Lock the payout for the duration of an operation, so that concurrent operations cannot both
+/// pass the validation of its status and send it to the connector. The payout has to be read
+/// again once the lock is acquired.
+async fn acquire_payout_lock(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<services::locking::Lock> {
+    core_utils::acquire_resource_lock(
+        state,
+        &format!("lock_payout_{}_{payout_id}", merchant_account.merchant_id),
+    )
+    .await
+}
+
+fn get_connector_name(
+    merchant_account: &storage::MerchantAccount,
+    req: &payouts::PayoutCreateRequest,
+) -> RouterResult<String> {
+    if let Some(connector) = req.connector {
+        return Ok(connector.to_string());
+    }
+
+    // The other routing algorithms are evaluated against the attributes of a payment
+    match routing::get_routing_algorithm(merchant_account)? {
+        api::RoutingAlgorithm::Single(connector) => Ok(connector.to_string()),
+        api::RoutingAlgorithm::Rules(_)
+        | api::RoutingAlgorithm::VolumeSplit(_)
+        | api::RoutingAlgorithm::SuccessRate(_) => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The connector must be provided, since the routing algorithm of the merchant account cannot be used for payouts".to_string(),
+            }))
+        }
+    }
+}
+
+fn validate_payout_status(
+    payout: &storage::Payout,
+    allowed_statuses: &[enums::PayoutStatus],
+    action: &str,
+) -> RouterResult<()> {
+    utils::when(!allowed_statuses.contains(&payout.status), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This payout cannot be {action} because it has a status of {}. The expected state is {}",
+                payout.status,
+                allowed_statuses
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }))
+    })
+}
+
+#[instrument(skip_all)]
+async fn call_connector_payout<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payout,
+    payout_method_data: Option<payouts::PayoutMethodData>,
+    email: Option<masking::Secret<String, common_utils::pii::Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>>
+where
+    F: Clone + Debug + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let router_data = core_utils::construct_payout_router_data::<F>(
+        state,
+        merchant_account,
+        payout,
+        payout_method_data,
+        email,
+    )
+    .await?;
+
+    logger::debug!(payout_router_data=?router_data);
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    // Connectors which do not support the flow build no request for it
+    let request = connector_integration
+        .build_request(&router_data, &state.conf.connectors)
+        .map_err(|error| error.to_payout_failed_response())?;
+    utils::when(request.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::NotImplemented {
+            message: errors::api_error_response::NotImplementedMessage::Reason(format!(
+                "This payout operation with {}",
+                payout.connector
+            )),
+        }))
+    })?;
+
+    services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payout_failed_response())
+}
+
+async fn update_payout_with_connector_response(
+    db: &dyn db::StorageInterface,
+    payout: storage::Payout,
+    response: Result<types::PayoutsResponseData, types::ErrorResponse>,
+    status_on_error: enums::PayoutStatus,
+) -> RouterResult<storage::Payout> {
+    let payout_id = payout.payout_id.clone();
+    let payout_update = match response {
+        Err(error) => storage::PayoutUpdate::ErrorUpdate {
+            status: status_on_error,
+            error_code: Some(error.code),
+            error_message: Some(error.message),
+        },
+        Ok(response) => storage::PayoutUpdate::StatusUpdate {
+            connector_payout_id: Some(response.connector_payout_id),
+            status: response.status,
+        },
+    };
+
+    db.update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed while updating payout: payout_id: {payout_id}"))
+}
+
+impl From<Foreign<storage::Payout>> for Foreign<payouts::PayoutResponse> {
+    fn from(payout: Foreign<storage::Payout>) -> Self {
+        let payout = payout.0;
+        payouts::PayoutResponse {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.currency.to_string(),
+            connector: payout.connector,
+            connector_payout_id: payout.connector_payout_id,
+            customer_id: payout.customer_id,
+            payout_type: payout.payout_type.map(ForeignInto::foreign_into),
+            status: payout.status.foreign_into(),
+            description: payout.description,
+            metadata: payout.metadata,
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            created_at: payout.created_at,
+            modified_at: payout.modified_at,
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::payouts::PayoutInterface};
+
+    const ALL_STATUSES: [enums::PayoutStatus; 7] = [
+        enums::PayoutStatus::RequiresConfirmation,
+        enums::PayoutStatus::RequiresFulfillment,
+        enums::PayoutStatus::Pending,
+        enums::PayoutStatus::Success,
+        enums::PayoutStatus::Failed,
+        enums::PayoutStatus::Cancelled,
+        enums::PayoutStatus::Reversed,
+    ];
+
+    fn mk_payout(status: enums::PayoutStatus) -> storage::PayoutNew {
+        storage::PayoutNew::default()
+            .set_payout_id(format!("payout_{}", uuid::Uuid::new_v4().simple()))
+            .set_merchant_id("merchant_payouts_test".to_string())
+            .set_connector("adyen".to_string())
+            .set_payout_type(Some(enums::PayoutType::Bank))
+            .set_amount(100)
+            .set_currency(enums::Currency::EUR)
+            .set_status(status)
+            .to_owned()
+    }
+
+    /// Statuses of the payouts which pass the validation against `allowed_statuses`
+    fn allowed_statuses(allowed_statuses: &[enums::PayoutStatus]) -> Vec<enums::PayoutStatus> {
+        ALL_STATUSES
+            .into_iter()
+            .filter(|status| {
+                let payout = storage::Payout {
+                    id: 0,
+                    payout_id: "payout_test".to_string(),
+                    merchant_id: "merchant_payouts_test".to_string(),
+                    customer_id: None,
+                    connector: "adyen".to_string(),
+                    connector_payout_id: None,
+                    payout_type: Some(enums::PayoutType::Bank),
+                    amount: 100,
+                    currency: enums::Currency::EUR,
+                    status: *status,
+                    description: None,
+                    metadata: None,
+                    error_code: None,
+                    error_message: None,
+                    created_at: common_utils::date_time::now(),
+                    modified_at: common_utils::date_time::now(),
+                };
+                validate_payout_status(&payout, allowed_statuses, "tested").is_ok()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_payout_status_transitions() {
+        assert_eq!(
+            allowed_statuses(UPDATABLE_STATUSES),
+            vec![enums::PayoutStatus::RequiresConfirmation]
+        );
+        assert_eq!(
+            allowed_statuses(CONFIRMABLE_STATUSES),
+            vec![enums::PayoutStatus::RequiresConfirmation]
+        );
+        assert_eq!(
+            allowed_statuses(CANCELLABLE_STATUSES),
+            vec![
+                enums::PayoutStatus::RequiresConfirmation,
+                enums::PayoutStatus::RequiresFulfillment
+            ]
+        );
+        assert_eq!(
+            allowed_statuses(REVERSIBLE_STATUSES),
+            vec![enums::PayoutStatus::Success]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_invalid_payout_status_transition_is_rejected() {
+        let conf = Settings::new().expect("invalid settings");
+        let db = db::MockDb::new(&conf).await;
+        let payout = db
+            .insert_payout(mk_payout(enums::PayoutStatus::Cancelled))
+            .await
+            .unwrap();
+
+        let error = validate_payout_status(&payout, REVERSIBLE_STATUSES, "reversed").unwrap_err();
+        match error.current_context() {
+            errors::ApiErrorResponse::PreconditionFailed { message } => assert_eq!(
+                message,
+                "This payout cannot be reversed because it has a status of cancelled. The expected state is success"
+            ),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_concurrent_operations_on_a_payout_are_serialized() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = AppState::with_storage(conf, db::StorageImpl::Mock).await;
+        let merchant_account = state
+            .store
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: "merchant_payouts_test".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let payout = state
+            .store
+            .insert_payout(mk_payout(enums::PayoutStatus::RequiresConfirmation))
+            .await
+            .unwrap();
+
+        let (first, second) = futures::future::join(
+            payouts_cancel_core(&state, merchant_account.clone(), payout.payout_id.clone()),
+            payouts_cancel_core(&state, merchant_account, payout.payout_id.clone()),
+        )
+        .await;
+
+        // The operation which acquired the lock last reads the payout cancelled by the other one
+        let error = match (first, second) {
+            (Ok(_), Err(error)) | (Err(error), Ok(_)) => error,
+            (first, second) => {
+                panic!("expected exactly one cancellation, got {first:?} and {second:?}")
+            }
+        };
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+        let stored = state
+            .store
+            .find_payout_by_merchant_id_payout_id(&payout.merchant_id, &payout.payout_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.status, enums::PayoutStatus::Cancelled);
+    }
+
+    #[actix_rt::test]
+    async fn test_update_payout_with_successful_connector_response() {
+        let conf = Settings::new().expect("invalid settings");
+        let db = db::MockDb::new(&conf).await;
+        let payout = db
+            .insert_payout(mk_payout(enums::PayoutStatus::RequiresConfirmation))
+            .await
+            .unwrap();
+
+        let response = Ok(types::PayoutsResponseData {
+            connector_payout_id: "connector_payout_123".to_string(),
+            status: enums::PayoutStatus::RequiresFulfillment,
+        });
+        let payout = update_payout_with_connector_response(
+            &db,
+            payout,
+            response,
+            enums::PayoutStatus::Failed,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(payout.status, enums::PayoutStatus::RequiresFulfillment);
+        assert_eq!(
+            payout.connector_payout_id.as_deref(),
+            Some("connector_payout_123")
+        );
+        assert_eq!(payout.error_code, None);
+        let stored = db
+            .find_payout_by_merchant_id_payout_id(&payout.merchant_id, &payout.payout_id)
+            .await
+            .unwrap();
+        assert_eq!(stored, payout);
+    }
+
+    #[actix_rt::test]
+    async fn test_update_payout_with_connector_error() {
+        let conf = Settings::new().expect("invalid settings");
+        let db = db::MockDb::new(&conf).await;
+        let payout = db
+            .insert_payout(mk_payout(enums::PayoutStatus::Success))
+            .await
+            .unwrap();
+
+        let response = Err(types::ErrorResponse {
+            code: "transfer_already_reversed".to_string(),
+            message: "The transfer is already fully reversed".to_string(),
+            reason: None,
+            status_code: 400,
+        });
+        // A failed reversal leaves the payout in its current status
+        let payout = update_payout_with_connector_response(
+            &db,
+            payout,
+            response,
+            enums::PayoutStatus::Success,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(payout.status, enums::PayoutStatus::Success);
+        assert_eq!(payout.connector_payout_id, None);
+        assert_eq!(
+            payout.error_code.as_deref(),
+            Some("transfer_already_reversed")
+        );
+        assert_eq!(
+            payout.error_message.as_deref(),
+            Some("The transfer is already fully reversed")
+        );
+    }
+}
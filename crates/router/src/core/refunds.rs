@@ -8,7 +8,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token},
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::AppState,
@@ -45,17 +45,6 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    // Amount is not passed in request refer from payment attempt.
-    amount = req.amount.unwrap_or(payment_attempt.amount); // [#298]: Need to that capture amount
-                                                           //[#299]: Can we change the flow based on some workflow idea
-    utils::when(amount <= 0, || {
-        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "amount".to_string(),
-            expected_format: "positive integer".to_string()
-        })
-        .attach_printable("amount less than zero"))
-    })?;
-
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &req.payment_id,
@@ -65,19 +54,36 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Payments captured in part can be refunded up to the amount captured
     utils::when(
-        payment_intent.status != enums::IntentStatus::Succeeded,
+        !matches!(
+            payment_intent.status,
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+        ),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentNotSucceeded)
                 .attach_printable("unable to refund for a unsuccessful payment intent"))
         },
     )?;
 
+    let amount_captured = get_amount_captured(db, &payment_intent, &payment_attempt).await?;
+
+    // Amount is not passed in request refer from the amount captured.
+    amount = req.amount.unwrap_or(amount_captured); //[#299]: Can we change the flow based on some workflow idea
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
     validate_and_create_refund(
         state,
         &merchant_account,
         &payment_attempt,
         &payment_intent,
+        amount_captured,
         amount,
         req,
     )
@@ -188,6 +194,11 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+
+    if response.refund_status != refund.refund_status {
+        webhooks::trigger_refund_outgoing_webhook(state, merchant_account, &response).await;
+    }
+
     Ok(response)
 }
 
@@ -353,6 +364,11 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+
+    if response.refund_status != refund.refund_status {
+        webhooks::trigger_refund_outgoing_webhook(state, merchant_account, &response).await;
+    }
+
     Ok(response)
 }
 
@@ -391,12 +407,39 @@ pub async fn refund_update_core(
 
 // ********************************************** VALIDATIONS **********************************************
 
+/// Amount captured from the payment attempt, which is the most that can be refunded. The amount
+/// of payments captured in multiple parts is the sum of their successful captures.
+async fn get_amount_captured(
+    db: &dyn db::StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<i64> {
+    let captures = db
+        .find_captures_by_merchant_id_payment_id_attempt_id(
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            &payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the captures of the payment attempt")?;
+
+    Ok(if captures.is_empty() {
+        payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.amount)
+    } else {
+        payments::helpers::get_amount_captured(&captures)
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn validate_and_create_refund(
     state: &AppState,
     merchant_account: &storage::merchant_account::MerchantAccount,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
+    amount_captured: i64,
     refund_amount: i64,
     req: refunds::RefundRequest,
 ) -> RouterResult<refunds::RefundResponse> {
@@ -469,7 +512,7 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
+            validator::validate_refund_amount(amount_captured, &all_refunds, refund_amount)
                 .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
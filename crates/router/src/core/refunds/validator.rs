@@ -38,7 +38,7 @@ pub fn validate_success_transaction(
 
 #[instrument(skip_all)]
 pub fn validate_refund_amount(
-    payment_attempt_amount: i64, // &storage::PaymentAttempt,
+    amount_captured: i64,
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
@@ -56,7 +56,7 @@ pub fn validate_refund_amount(
         .sum();
 
     utils::when(
-        refund_amount > (payment_attempt_amount - total_refunded_amount),
+        refund_amount > (amount_captured - total_refunded_amount),
         || {
             Err(report!(
                 RefundValidationError::RefundAmountExceedsPaymentAmount
@@ -170,3 +170,16 @@ pub fn validate_for_valid_refunds(
     )?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refund_amount_is_limited_to_the_amount_captured() {
+        // A payment of 1000 of which 400 were captured
+        assert!(validate_refund_amount(400, &[], 1000).is_err());
+        assert!(validate_refund_amount(400, &[], 401).is_err());
+        assert!(validate_refund_amount(400, &[], 400).is_ok());
+    }
+}
@@ -0,0 +1,634 @@
+pub mod success_rate;
+
+use std::collections::HashSet;
+
+use api_models::{
+    admin::{
+        ConnectorVolumeSplit, RoutingConditions, RoutingRule, RoutingRuleOutput, RoutingRules,
+        SuccessRateRouting,
+    },
+    enums as api_enums,
+};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+    types::{api, storage},
+    utils::{self, OptionExt, ValueExt},
+};
+
+/// Maximum length of a card BIN prefix in a routing rule
+const MAX_CARD_BIN_LENGTH: usize = 8;
+
+/// The total of the splits of a volume split routing algorithm
+const TOTAL_VOLUME_SPLIT: u64 = 100;
+
+/// Attributes of a payment which the routing rules of a merchant are evaluated against
+#[derive(Debug, Default)]
+pub struct RoutingInput<'a> {
+    /// Used to deterministically pick a connector when splitting volume across connectors, so
+    /// that retries of a payment are routed to the same connector
+    pub payment_id: Option<&'a str>,
+    pub currency: Option<api_enums::Currency>,
+    pub amount: Option<i64>,
+    pub card_number: Option<&'a str>,
+    pub billing_country: Option<&'a str>,
+    pub payment_method: Option<api_enums::PaymentMethodType>,
+    pub metadata: Option<&'a serde_json::Value>,
+}
+
+/// Decode the routing algorithm configured for the merchant account
+pub fn get_routing_algorithm(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<api::RoutingAlgorithm> {
+    merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing rules")
+}
+
+/// Validate a routing algorithm provided by the merchant, before it is stored in the merchant
+/// account
+pub fn validate_routing_algorithm(
+    routing_algorithm: &serde_json::Value,
+) -> RouterResult<api::RoutingAlgorithm> {
+    let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "routing_algorithm",
+        })
+        .attach_printable("Invalid routing algorithm given")?;
+
+    match &routing_algorithm {
+        api::RoutingAlgorithm::Single(_) => (),
+        api::RoutingAlgorithm::Rules(routing_rules) => validate_routing_rules(routing_rules)?,
+        api::RoutingAlgorithm::VolumeSplit(volume_splits) => validate_volume_splits(volume_splits)?,
+        api::RoutingAlgorithm::SuccessRate(success_rate_routing) => {
+            validate_success_rate_routing(success_rate_routing)?
+        }
+    }
+
+    Ok(routing_algorithm)
+}
+
+/// Evaluate the routing algorithm for a payment, returning the connectors to be used in
+/// decreasing order of preference
+pub fn perform_routing(
+    routing_algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput<'_>,
+) -> Vec<api_enums::RoutableConnectors> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::Single(connector) => vec![*connector],
+        api::RoutingAlgorithm::Rules(routing_rules) => {
+            match routing_rules
+                .rules
+                .iter()
+                .find(|rule| conditions_match(&rule.conditions, input))
+            {
+                Some(rule) => {
+                    logger::debug!(routing_rule = %rule.name, "Routing rule matched");
+                    rule.output.connectors()
+                }
+                None => {
+                    logger::debug!("No routing rule matched, using the default connectors");
+                    routing_rules.default.connectors()
+                }
+            }
+        }
+        api::RoutingAlgorithm::VolumeSplit(volume_splits) => {
+            perform_volume_split(volume_splits, input.payment_id.unwrap_or_default())
+        }
+        // Success rates are only available to `perform_dynamic_routing`
+        api::RoutingAlgorithm::SuccessRate(success_rate_routing) => {
+            success_rate_routing.connectors.clone()
+        }
+    }
+}
+
+/// Evaluate the routing algorithm for a payment like [`perform_routing`], additionally using the
+/// recent success rates of the connectors if the merchant routes payments by success rate
+pub async fn perform_dynamic_routing(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    routing_algorithm: &api::RoutingAlgorithm,
+    input: &RoutingInput<'_>,
+) -> Vec<api_enums::RoutableConnectors> {
+    match routing_algorithm {
+        api::RoutingAlgorithm::SuccessRate(success_rate_routing) => {
+            success_rate::perform_success_rate_routing(db, merchant_id, success_rate_routing, input)
+                .await
+        }
+        _ => perform_routing(routing_algorithm, input),
+    }
+}
+
+/// Pick a connector based on the configured splits, using a hash of the payment ID so that the
+/// same payment is always routed to the same connector. The remaining connectors follow the
+/// chosen connector in decreasing order of their splits.
+fn perform_volume_split(
+    volume_splits: &[ConnectorVolumeSplit],
+    payment_id: &str,
+) -> Vec<api_enums::RoutableConnectors> {
+    let hash = blake3::hash(payment_id.as_bytes());
+    let mut hash_prefix = [0u8; 8];
+    hash_prefix.copy_from_slice(&hash.as_bytes()[..8]);
+    let bucket = u64::from_le_bytes(hash_prefix) % TOTAL_VOLUME_SPLIT;
+
+    let mut cumulative_split = 0;
+    let chosen_connector = volume_splits
+        .iter()
+        .find(|volume_split| {
+            cumulative_split += u64::from(volume_split.split);
+            bucket < cumulative_split
+        })
+        .map(|volume_split| volume_split.connector);
+
+    let mut remaining_splits: Vec<_> = volume_splits
+        .iter()
+        .filter(|volume_split| {
+            volume_split.split > 0 && Some(volume_split.connector) != chosen_connector
+        })
+        .collect();
+    remaining_splits.sort_by(|a, b| b.split.cmp(&a.split));
+
+    chosen_connector
+        .into_iter()
+        .chain(
+            remaining_splits
+                .into_iter()
+                .map(|volume_split| volume_split.connector),
+        )
+        .collect()
+}
+
+fn conditions_match(conditions: &RoutingConditions, input: &RoutingInput<'_>) -> bool {
+    let card_bin_matches = conditions.card_bin.as_ref().map_or(true, |card_bins| {
+        input.card_number.map_or(false, |card_number| {
+            card_bins
+                .iter()
+                .any(|card_bin| card_number.starts_with(card_bin.as_str()))
+        })
+    });
+
+    let billing_country_matches = conditions
+        .billing_country
+        .as_ref()
+        .map_or(true, |countries| {
+            input.billing_country.map_or(false, |billing_country| {
+                countries
+                    .iter()
+                    .any(|country| country.eq_ignore_ascii_case(billing_country))
+            })
+        });
+
+    let amount_matches = conditions.amount.as_ref().map_or(true, |amount_range| {
+        input.amount.map_or(false, |amount| {
+            amount_range.min.map_or(true, |min| amount >= min)
+                && amount_range.max.map_or(true, |max| amount <= max)
+        })
+    });
+
+    let metadata_matches = conditions
+        .metadata
+        .as_ref()
+        .map_or(true, |required_metadata| {
+            input.metadata.map_or(false, |metadata| {
+                required_metadata
+                    .iter()
+                    .all(|(key, value)| metadata.get(key) == Some(value))
+            })
+        });
+
+    value_matches(&conditions.currency, input.currency)
+        && amount_matches
+        && value_matches(
+            &conditions.card_network,
+            input.card_number.and_then(get_card_network),
+        )
+        && card_bin_matches
+        && billing_country_matches
+        && value_matches(&conditions.payment_method, input.payment_method)
+        && metadata_matches
+}
+
+/// A condition which is not provided always matches, otherwise the value must be present and be
+/// one of the allowed values
+fn value_matches<T: PartialEq>(allowed_values: &Option<Vec<T>>, value: Option<T>) -> bool {
+    allowed_values.as_ref().map_or(true, |allowed_values| {
+        value.map_or(false, |value| allowed_values.contains(&value))
+    })
+}
+
+/// Determine the card network from the leading digits of the card number
+pub fn get_card_network(card_number: &str) -> Option<api_enums::CardNetwork> {
+    let prefix = |length: usize| -> Option<u32> {
+        card_number
+            .get(..length)
+            .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))
+            .and_then(|digits| digits.parse().ok())
+    };
+    let prefix_in = |length: usize, start: u32, end: u32| {
+        prefix(length).map_or(false, |prefix| (start..=end).contains(&prefix))
+    };
+
+    if prefix_in(1, 4, 4) {
+        Some(api_enums::CardNetwork::Visa)
+    } else if prefix_in(2, 51, 55) || prefix_in(4, 2221, 2720) {
+        Some(api_enums::CardNetwork::Mastercard)
+    } else if prefix_in(2, 34, 34) || prefix_in(2, 37, 37) {
+        Some(api_enums::CardNetwork::AmericanExpress)
+    } else if prefix_in(4, 6011, 6011) || prefix_in(3, 644, 649) || prefix_in(2, 65, 65) {
+        Some(api_enums::CardNetwork::Discover)
+    } else if prefix_in(4, 3528, 3589) {
+        Some(api_enums::CardNetwork::Jcb)
+    } else if prefix_in(3, 300, 305) || prefix_in(2, 36, 36) || prefix_in(2, 38, 39) {
+        Some(api_enums::CardNetwork::DinersClub)
+    } else if prefix_in(2, 62, 62) {
+        Some(api_enums::CardNetwork::UnionPay)
+    } else if prefix_in(2, 50, 50)
+        || prefix_in(2, 56, 58)
+        || prefix_in(4, 6304, 6304)
+        || prefix_in(4, 6759, 6759)
+        || prefix_in(4, 6761, 6763)
+    {
+        Some(api_enums::CardNetwork::Maestro)
+    } else {
+        None
+    }
+}
+
+fn invalid_routing_rule(
+    rule_name: &str,
+    reason: &str,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid routing rule `{rule_name}`: {reason}"),
+    })
+}
+
+fn validate_routing_rules(routing_rules: &RoutingRules) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in &routing_rules.rules {
+        utils::when(rule.name.trim().is_empty(), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Routing rule names must not be empty".to_string(),
+            }))
+        })?;
+        utils::when(!rule_names.insert(rule.name.as_str()), || {
+            Err(invalid_routing_rule(
+                &rule.name,
+                "rule names must be unique",
+            ))
+        })?;
+        validate_routing_rule(rule)?;
+    }
+
+    validate_routing_rule_output("default", &routing_rules.default)
+}
+
+fn validate_routing_rule(rule: &RoutingRule) -> RouterResult<()> {
+    let conditions = &rule.conditions;
+    let has_empty_condition = conditions.currency.as_ref().map_or(false, Vec::is_empty)
+        || conditions
+            .card_network
+            .as_ref()
+            .map_or(false, Vec::is_empty)
+        || conditions.card_bin.as_ref().map_or(false, Vec::is_empty)
+        || conditions
+            .billing_country
+            .as_ref()
+            .map_or(false, Vec::is_empty)
+        || conditions
+            .payment_method
+            .as_ref()
+            .map_or(false, Vec::is_empty)
+        || conditions
+            .metadata
+            .as_ref()
+            .map_or(false, |metadata| metadata.is_empty());
+    utils::when(has_empty_condition, || {
+        Err(invalid_routing_rule(
+            &rule.name,
+            "conditions must not be empty, omit the condition instead",
+        ))
+    })?;
+
+    if let Some(amount_range) = &conditions.amount {
+        match (amount_range.min, amount_range.max) {
+            (None, None) => Err(invalid_routing_rule(
+                &rule.name,
+                "amount range must have a minimum or a maximum",
+            )),
+            (Some(min), Some(max)) if min > max => Err(invalid_routing_rule(
+                &rule.name,
+                "amount range minimum must not be greater than the maximum",
+            )),
+            _ => Ok(()),
+        }?;
+    }
+
+    let is_invalid_card_bin = |card_bin: &String| {
+        card_bin.is_empty()
+            || card_bin.len() > MAX_CARD_BIN_LENGTH
+            || !card_bin.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    if let Some(card_bin) = conditions
+        .card_bin
+        .iter()
+        .flatten()
+        .find(|card_bin| is_invalid_card_bin(card_bin))
+    {
+        Err(invalid_routing_rule(
+            &rule.name,
+            &format!("card BIN `{card_bin}` must have between 1 and {MAX_CARD_BIN_LENGTH} digits"),
+        ))?;
+    }
+
+    if let Some(country) = conditions.billing_country.iter().flatten().find(|country| {
+        country.len() != 2 || !country.bytes().all(|byte| byte.is_ascii_alphabetic())
+    }) {
+        Err(invalid_routing_rule(
+            &rule.name,
+            &format!("billing country `{country}` must be a two-letter ISO country code"),
+        ))?;
+    }
+
+    validate_routing_rule_output(&rule.name, &rule.output)
+}
+
+fn validate_routing_rule_output(rule_name: &str, output: &RoutingRuleOutput) -> RouterResult<()> {
+    match output {
+        RoutingRuleOutput::Single(_) => Ok(()),
+        RoutingRuleOutput::Priority(connectors) => {
+            utils::when(connectors.is_empty(), || {
+                Err(invalid_routing_rule(
+                    rule_name,
+                    "priority list must contain at least one connector",
+                ))
+            })?;
+            let has_duplicates = connectors
+                .iter()
+                .enumerate()
+                .any(|(index, connector)| connectors[..index].contains(connector));
+            utils::when(has_duplicates, || {
+                Err(invalid_routing_rule(
+                    rule_name,
+                    "priority list must not contain duplicate connectors",
+                ))
+            })
+        }
+    }
+}
+
+fn validate_volume_splits(volume_splits: &[ConnectorVolumeSplit]) -> RouterResult<()> {
+    let invalid_volume_split = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid volume split routing algorithm: {message}"),
+        }))
+    };
+
+    let total_split: u64 = volume_splits
+        .iter()
+        .map(|volume_split| u64::from(volume_split.split))
+        .sum();
+    utils::when(total_split != TOTAL_VOLUME_SPLIT, || {
+        invalid_volume_split(&format!(
+            "the splits must add up to {TOTAL_VOLUME_SPLIT}, got {total_split}"
+        ))
+    })?;
+
+    let has_duplicates = volume_splits
+        .iter()
+        .enumerate()
+        .any(|(index, volume_split)| {
+            volume_splits[..index]
+                .iter()
+                .any(|other| other.connector == volume_split.connector)
+        });
+    utils::when(has_duplicates, || {
+        invalid_volume_split("each connector must appear only once")
+    })
+}
+
+fn validate_success_rate_routing(success_rate_routing: &SuccessRateRouting) -> RouterResult<()> {
+    let invalid_success_rate_routing = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid success rate routing algorithm: {message}"),
+        }))
+    };
+
+    let connectors = &success_rate_routing.connectors;
+    utils::when(connectors.is_empty(), || {
+        invalid_success_rate_routing("at least one connector must be provided")
+    })?;
+
+    let has_duplicates = connectors
+        .iter()
+        .enumerate()
+        .any(|(index, connector)| connectors[..index].contains(connector));
+    utils::when(has_duplicates, || {
+        invalid_success_rate_routing("each connector must appear only once")
+    })?;
+
+    utils::when(success_rate_routing.exploration_percentage > 100, || {
+        invalid_success_rate_routing("the exploration percentage must not be greater than 100")
+    })?;
+
+    utils::when(
+        success_rate_routing.window_in_mins == 0
+            || success_rate_routing.window_in_mins > success_rate::MAX_WINDOW_IN_MINS,
+        || {
+            invalid_success_rate_routing(&format!(
+                "the window must be between 1 and {} minutes",
+                success_rate::MAX_WINDOW_IN_MINS
+            ))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routing_algorithm() -> api::RoutingAlgorithm {
+        serde_json::from_value(serde_json::json!({
+            "type": "rules",
+            "data": {
+                "rules": [
+                    {
+                        "name": "eur_cards",
+                        "conditions": {
+                            "currency": ["EUR"],
+                            "payment_method": ["card"]
+                        },
+                        "output": { "type": "single", "data": "adyen" }
+                    },
+                    {
+                        "name": "usd_wallets",
+                        "conditions": {
+                            "currency": ["USD"],
+                            "payment_method": ["wallet"],
+                            "amount": { "max": 10000 }
+                        },
+                        "output": { "type": "priority", "data": ["stripe", "checkout"] }
+                    }
+                ],
+                "default": { "type": "single", "data": "checkout" }
+            }
+        }))
+        .expect("Failed to deserialize routing algorithm")
+    }
+
+    #[test]
+    fn test_perform_routing_with_rules() {
+        let routing_algorithm = routing_algorithm();
+
+        let eur_card = RoutingInput {
+            currency: Some(api_enums::Currency::EUR),
+            amount: Some(100),
+            card_number: Some("4242424242424242"),
+            payment_method: Some(api_enums::PaymentMethodType::Card),
+            ..Default::default()
+        };
+        assert_eq!(
+            perform_routing(&routing_algorithm, &eur_card),
+            vec![api_enums::RoutableConnectors::Adyen]
+        );
+
+        let usd_wallet = RoutingInput {
+            currency: Some(api_enums::Currency::USD),
+            amount: Some(5000),
+            payment_method: Some(api_enums::PaymentMethodType::Wallet),
+            ..Default::default()
+        };
+        assert_eq!(
+            perform_routing(&routing_algorithm, &usd_wallet),
+            vec![
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Checkout
+            ]
+        );
+
+        let large_usd_wallet = RoutingInput {
+            amount: Some(20000),
+            ..usd_wallet
+        };
+        assert_eq!(
+            perform_routing(&routing_algorithm, &large_usd_wallet),
+            vec![api_enums::RoutableConnectors::Checkout]
+        );
+    }
+
+    #[test]
+    fn test_validate_routing_algorithm() {
+        let valid = serde_json::to_value(routing_algorithm()).expect("Failed to serialize");
+        assert!(validate_routing_algorithm(&valid).is_ok());
+
+        let invalid = serde_json::json!({
+            "type": "rules",
+            "data": {
+                "rules": [{
+                    "name": "invalid_amount",
+                    "conditions": { "amount": { "min": 100, "max": 10 } },
+                    "output": { "type": "single", "data": "adyen" }
+                }],
+                "default": { "type": "priority", "data": [] }
+            }
+        });
+        assert!(validate_routing_algorithm(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_perform_volume_split() {
+        let volume_splits = vec![
+            ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 30,
+            },
+            ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 70,
+            },
+        ];
+        let routing_algorithm = api::RoutingAlgorithm::VolumeSplit(volume_splits);
+
+        let mut stripe_payments = 0;
+        for index in 0..1000 {
+            let payment_id = format!("pay_{index}");
+            let input = RoutingInput {
+                payment_id: Some(&payment_id),
+                ..Default::default()
+            };
+            let connectors = perform_routing(&routing_algorithm, &input);
+            assert_eq!(connectors.len(), 2);
+            // The same payment must always be routed to the same connector
+            assert_eq!(connectors, perform_routing(&routing_algorithm, &input));
+            if connectors[0] == api_enums::RoutableConnectors::Stripe {
+                stripe_payments += 1;
+            }
+        }
+        assert!((200..400).contains(&stripe_payments));
+
+        let invalid = serde_json::json!({
+            "type": "volume_split",
+            "data": [
+                { "connector": "stripe", "split": 30 },
+                { "connector": "adyen", "split": 60 }
+            ]
+        });
+        assert!(validate_routing_algorithm(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_validate_success_rate_routing() {
+        let valid = serde_json::json!({
+            "type": "success_rate",
+            "data": { "connectors": ["stripe", "adyen", "checkout"] }
+        });
+        let routing_algorithm =
+            validate_routing_algorithm(&valid).expect("Failed to validate routing algorithm");
+        // Without success rates, the connectors are used in the configured order
+        assert_eq!(
+            perform_routing(&routing_algorithm, &RoutingInput::default()),
+            vec![
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Checkout,
+            ]
+        );
+
+        let invalid = serde_json::json!({
+            "type": "success_rate",
+            "data": { "connectors": ["stripe", "adyen"], "exploration_percentage": 120 }
+        });
+        assert!(validate_routing_algorithm(&invalid).is_err());
+
+        let invalid = serde_json::json!({
+            "type": "success_rate",
+            "data": { "connectors": ["stripe", "stripe"] }
+        });
+        assert!(validate_routing_algorithm(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_get_card_network() {
+        assert_eq!(
+            get_card_network("4242424242424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            get_card_network("5555555555554444"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_card_network("378282246310005"),
+            Some(api_enums::CardNetwork::AmericanExpress)
+        );
+        assert_eq!(get_card_network("1234"), None);
+    }
+}
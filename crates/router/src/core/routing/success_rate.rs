@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+
+use api_models::{admin::SuccessRateRouting, enums as api_enums};
+use futures::future::join_all;
+use rand::Rng;
+use router_env::{instrument, tracing};
+
+use super::RoutingInput;
+use crate::{
+    db::{connector_success_rate::ConnectorPaymentCounts, StorageInterface},
+    logger,
+    types::{storage, storage::enums as storage_enums, transformers::ForeignInto},
+};
+
+/// Payment counts are tracked in buckets of this length, in seconds
+const BUCKET_SIZE_IN_SECS: i64 = 60;
+
+/// The longest window a success rate can be computed over, in minutes. Payment counts are kept
+/// for this long.
+pub const MAX_WINDOW_IN_MINS: u16 = 24 * 60;
+
+/// Payment counts are tracked separately for each merchant, connector, payment method and
+/// currency
+fn get_success_rate_key(
+    merchant_id: &str,
+    connector: &str,
+    payment_method: Option<api_enums::PaymentMethodType>,
+    currency: Option<api_enums::Currency>,
+) -> String {
+    format!(
+        "success_rate_{merchant_id}_{connector}_{}_{}",
+        payment_method.map_or_else(|| "any".to_string(), |pm| pm.to_string()),
+        currency.map_or_else(|| "any".to_string(), |currency| currency.to_string()),
+    )
+}
+
+fn get_current_bucket() -> u64 {
+    u64::try_from(common_utils::date_time::now_unix_timestamp() / BUCKET_SIZE_IN_SECS)
+        .unwrap_or_default()
+}
+
+/// Whether the attempt status is a final authorization status, and if so whether the
+/// authorization succeeded
+fn get_authorization_outcome(status: storage_enums::AttemptStatus) -> Option<bool> {
+    match status {
+        storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::Authorized => Some(true),
+        storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::AuthorizationFailed => Some(false),
+        _ => None,
+    }
+}
+
+/// Record the outcome of an authorization with the connector of the payment attempt, when the
+/// attempt reaches a final authorization status from `previous_status`. Authorizations completed
+/// after the initial response, such as on redirection or through webhooks, are recorded when the
+/// payment is synced, and each attempt is counted once.
+#[instrument(skip_all)]
+pub async fn record_payment_outcome(
+    db: &dyn StorageInterface,
+    previous_status: storage_enums::AttemptStatus,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let is_success = match (
+        get_authorization_outcome(previous_status),
+        get_authorization_outcome(payment_attempt.status),
+    ) {
+        (None, Some(is_success)) => is_success,
+        _ => return,
+    };
+    let connector = match &payment_attempt.connector {
+        Some(connector) => connector,
+        None => return,
+    };
+
+    let key = get_success_rate_key(
+        &payment_attempt.merchant_id,
+        connector,
+        payment_attempt
+            .payment_method
+            .map(ForeignInto::foreign_into),
+        payment_attempt.currency.map(ForeignInto::foreign_into),
+    );
+    let ttl_in_secs = i64::from(MAX_WINDOW_IN_MINS) * 60 + BUCKET_SIZE_IN_SECS;
+
+    // Success rates are best effort, failing to record one must not fail the payment
+    if let Err(error) = db
+        .increment_connector_payment_counts(&key, get_current_bucket(), is_success, ttl_in_secs)
+        .await
+    {
+        logger::error!(?error, "Failed to record connector payment outcome");
+    }
+}
+
+/// Order the connectors by their success rates within the window, with a random connector
+/// picked first for `exploration_percentage` percent of the payments
+#[instrument(skip_all)]
+pub async fn perform_success_rate_routing(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    success_rate_routing: &SuccessRateRouting,
+    input: &RoutingInput<'_>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let window_buckets =
+        u64::from(success_rate_routing.window_in_mins) * 60 / BUCKET_SIZE_IN_SECS.unsigned_abs();
+    let oldest_bucket = (get_current_bucket() + 1).saturating_sub(window_buckets);
+
+    let payment_counts = join_all(success_rate_routing.connectors.iter().map(|connector| {
+        let key = get_success_rate_key(
+            merchant_id,
+            &connector.to_string(),
+            input.payment_method,
+            input.currency,
+        );
+        async move {
+            db.get_connector_payment_counts(&key, oldest_bucket)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, %connector, "Failed to get connector payment counts");
+                })
+                .ok()
+        }
+    }))
+    .await;
+
+    let mut connectors = rank_connectors(
+        &success_rate_routing.connectors,
+        &payment_counts,
+        success_rate_routing.min_volume,
+    );
+
+    let mut rng = rand::thread_rng();
+    if connectors.len() > 1 && rng.gen_range(0..100) < success_rate_routing.exploration_percentage {
+        let explored_connector = connectors.remove(rng.gen_range(1..connectors.len()));
+        logger::debug!(%explored_connector, "Exploring connector for success rate routing");
+        connectors.insert(0, explored_connector);
+    }
+
+    connectors
+}
+
+/// Connectors with enough payments in the window are ordered by decreasing success rate, followed
+/// by the remaining connectors in their configured order
+fn rank_connectors(
+    connectors: &[api_enums::RoutableConnectors],
+    payment_counts: &[Option<ConnectorPaymentCounts>],
+    min_volume: u32,
+) -> Vec<api_enums::RoutableConnectors> {
+    let mut ranked_connectors: Vec<_> = connectors
+        .iter()
+        .zip(payment_counts)
+        .map(|(connector, payment_counts)| {
+            let payment_counts = payment_counts
+                .filter(|payment_counts| payment_counts.total >= u64::from(min_volume.max(1)));
+            (*connector, payment_counts)
+        })
+        .collect();
+
+    // The sort is stable, so connectors with equal success rates keep their configured order
+    ranked_connectors.sort_by(|(_, a), (_, b)| match (a, b) {
+        (Some(a), Some(b)) => compare_success_rates(b, a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+
+    ranked_connectors
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// Compare `success / total` of both the counts without loss of precision
+fn compare_success_rates(a: &ConnectorPaymentCounts, b: &ConnectorPaymentCounts) -> Ordering {
+    (u128::from(a.success) * u128::from(b.total))
+        .cmp(&(u128::from(b.success) * u128::from(a.total)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_authorization_outcome() {
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Charged),
+            Some(true)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Authorized),
+            Some(true)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::AuthorizationFailed),
+            Some(false)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::AuthenticationPending),
+            None
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Pending),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rank_connectors() {
+        let connectors = [
+            api_enums::RoutableConnectors::Stripe,
+            api_enums::RoutableConnectors::Adyen,
+            api_enums::RoutableConnectors::Checkout,
+        ];
+
+        let payment_counts = [
+            Some(ConnectorPaymentCounts {
+                total: 100,
+                success: 60,
+            }),
+            Some(ConnectorPaymentCounts {
+                total: 50,
+                success: 45,
+            }),
+            Some(ConnectorPaymentCounts {
+                total: 5,
+                success: 5,
+            }),
+        ];
+        assert_eq!(
+            rank_connectors(&connectors, &payment_counts, 20),
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Checkout,
+            ]
+        );
+
+        // Without enough volume, the configured order is used
+        assert_eq!(
+            rank_connectors(&connectors, &[None, None, None], 20),
+            connectors.to_vec()
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::payments::PaymentAddress;
@@ -8,11 +8,12 @@ use crate::{
     consts,
     core::errors::{self, RouterResult},
     routes::AppState,
+    services,
     types::{
-        self,
+        self, api,
         storage::{self, enums},
     },
-    utils::{generate_id, OptionExt, ValueExt},
+    utils::{self, generate_id, OptionExt, ValueExt},
 };
 
 #[instrument(skip_all)]
@@ -87,6 +88,182 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<'a, F>(
+    state: &'a AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &'a storage::Payout,
+    payout_method_data: Option<api::PayoutMethodData>,
+    email: Option<masking::Secret<String, common_utils::pii::Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &payout.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = match payout.payout_type {
+        Some(enums::PayoutType::Card) => enums::PaymentMethodType::Card,
+        Some(enums::PayoutType::Bank) | Some(enums::PayoutType::ConnectedAccount) | None => {
+            enums::PaymentMethodType::BankTransfer
+        }
+    };
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        // Payouts are not tied to a payment, the payout ID identifies the transaction instead
+        payment_id: payout.payout_id.clone(),
+        attempt_id: None,
+        status: enums::AttemptStatus::Pending,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            customer_id: payout.customer_id.clone(),
+            email,
+            payout_method_data,
+            connector_payout_id: payout.connector_payout_id.clone(),
+            description: payout.description.clone(),
+        },
+        // Connectors which do not implement the payout flows leave this untouched
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_dispute_router_data<'a, F, Req>(
+    state: &'a AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute: &'a storage::Dispute,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, types::DisputeResponseData>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &dispute.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request,
+        // Connectors which do not implement the dispute flows leave this untouched
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
+#[instrument(skip_all)]
+pub async fn construct_upload_file_router_data<'a>(
+    state: &'a AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_id: &str,
+    create_file_request: &api::CreateFileRequest,
+    file_key: String,
+) -> RouterResult<types::UploadFileRouterData> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            connector_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::UploadFileRequestData {
+            file_key,
+            file: create_file_request.file.clone(),
+            file_type: create_file_request.file_type.clone(),
+            file_size: create_file_request.file_size,
+            purpose: create_file_request.purpose,
+        },
+        // Connectors which do not implement the file upload flow leave this untouched
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -144,3 +321,38 @@ mod tests {
         assert_eq!(generated_id.len(), consts::ID_LENGTH + 4)
     }
 }
+
+/// Lock the resource identified by the key, waiting for a concurrent operation on the resource to
+/// complete for up to the configured number of attempts
+pub async fn acquire_resource_lock(
+    state: &AppState,
+    key: &str,
+) -> RouterResult<services::locking::Lock> {
+    services::locking::acquire(state.store.get_redis_conn(), key, &state.conf.lock_settings)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to acquire lock {key}"))?
+        .ok_or(errors::ApiErrorResponse::ResourceBusy)
+        .into_report()
+        .attach_printable_lazy(|| format!("Lock {key} is held by a concurrent operation"))
+}
+
+/// Reject the writes of an operation whose lock expired and was acquired by a concurrent
+/// operation meanwhile
+pub async fn check_resource_lock(lock: Option<&services::locking::Lock>) -> RouterResult<()> {
+    let lock = match lock {
+        Some(lock) => lock,
+        None => return Ok(()),
+    };
+    let accepted = lock
+        .fence_write()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check the fencing token of the operation lock")?;
+
+    utils::when(!accepted, || {
+        Err(errors::ApiErrorResponse::ResourceBusy)
+            .into_report()
+            .attach_printable("Operation lock expired and was acquired by a concurrent operation")
+    })
+}
@@ -1,11 +1,15 @@
 pub mod transformers;
 pub mod utils;
 
+use std::fmt::Debug;
+
+use common_utils::webhooks as webhook_utils;
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
+    configs::settings,
     consts,
     core::{
         errors::{self, CustomResult, RouterResponse},
@@ -14,10 +18,14 @@ use crate::{
     db::StorageInterface,
     logger,
     routes::AppState,
+    scheduler::utils as pt_utils,
     services,
     types::{
-        api,
-        storage::{self, enums},
+        api::{
+            self,
+            mandates::{self, MandateResponseExt},
+        },
+        storage::{self, enums, ProcessTrackerExt},
         transformers::{ForeignInto, ForeignTryInto},
     },
     utils::{generate_id, Encode, OptionExt, ValueExt},
@@ -38,7 +46,8 @@ async fn payments_incoming_webhook_flow(
         payments::CallConnectorAction::Trigger
     };
 
-    let payments_response = payments::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+    // The payments core triggers the outgoing webhook if the status of the payment changes
+    payments::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
         &state,
         merchant_account.clone(),
         payments::operations::PaymentStatus,
@@ -57,42 +66,402 @@ async fn payments_incoming_webhook_flow(
     .await
     .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
 
-    match payments_response {
-        services::ApplicationResponse::Json(payments_response) => {
-            let payment_id = payments_response
-                .payment_id
-                .clone()
-                .get_required_value("payment_id")
-                .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
-
-            let event_type: enums::EventType = payments_response
-                .status
-                .foreign_try_into()
-                .into_report()
-                .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+    Ok(())
+}
 
-            create_event_and_trigger_outgoing_webhook(
-                state,
-                merchant_account,
-                event_type,
-                enums::EventClass::Payments,
-                None,
-                payment_id,
-                enums::EventObjectType::PaymentDetails,
-                api::OutgoingWebhookContent::PaymentDetails(payments_response),
-            )
-            .await?;
-        }
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn disputes_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    body: &[u8],
+    connector_name: &str,
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Disputes cannot be synced with the connector, so only trust verified webhooks
+    if !source_verified {
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let dispute_details = connector
+        .get_dispute_details(body)
+        .change_context(errors::WebhooksFlowError::WebhookEventObjectNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+
+    let dispute_status = get_dispute_status(event_type)?;
+    let (dispute, is_status_changed) = upsert_dispute(
+        db,
+        &payment_attempt,
+        dispute_details,
+        dispute_status,
+        connector_name,
+    )
+    .await?;
 
-        _ => Err(errors::WebhooksFlowError::PaymentsCoreFailed).into_report()?,
+    if is_status_changed {
+        trigger_dispute_outgoing_webhook(&state, &merchant_account, &dispute).await;
     }
 
     Ok(())
 }
 
+#[instrument(skip_all)]
+async fn incremental_authorizations_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Incremental authorizations cannot be synced with the connector, so only trust verified
+    // webhooks
+    if !source_verified {
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let storage_scheme = merchant_account.storage_scheme;
+    let authorization = db
+        .find_incremental_authorization_by_merchant_id_connector_authorization_id(
+            merchant_id,
+            &webhook_details.object_reference_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+
+    // The outcome of the incremental authorization was already recorded
+    if authorization.status != enums::AuthorizationStatus::Processing {
+        return Ok(());
+    }
+
+    let authorization_status = get_incremental_authorization_status(event_type)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            merchant_id,
+            &authorization.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &authorization.payment_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)?;
+
+    payments::helpers::update_incremental_authorization(
+        db,
+        authorization,
+        storage::IncrementalAuthorizationUpdate::ResponseUpdate {
+            status: authorization_status,
+            connector_authorization_id: None,
+        },
+        payment_attempt,
+        payment_intent,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::IncrementalAuthorizationCoreFailed)?;
+
+    Ok(())
+}
+
+/// Insert the dispute reported by a webhook, or update it if it was reported before. Returns the
+/// dispute along with whether its status changed.
+#[instrument(skip_all)]
+pub async fn upsert_dispute(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute_details: api::disputes::DisputePayload,
+    dispute_status: enums::DisputeStatus,
+    connector_name: &str,
+) -> CustomResult<(storage::Dispute, bool), errors::WebhooksFlowError> {
+    let existing_dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            &dispute_details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let is_status_changed = existing_dispute
+        .as_ref()
+        .map_or(true, |dispute| dispute.dispute_status != dispute_status);
+
+    let dispute = match existing_dispute {
+        Some(dispute) => {
+            logger::info!("Dispute already exists, updating the dispute details");
+            let dispute_update = storage::DisputeUpdate::Update {
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                connector_status: dispute_details.connector_status,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                connector_updated_at: dispute_details.updated_at,
+            };
+            db.update_dispute(dispute, dispute_update)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?
+        }
+        None => {
+            logger::info!("Dispute does not exist, inserting a new dispute");
+            let new_dispute = storage::DisputeNew {
+                dispute_id: generate_id(consts::ID_LENGTH, "dp"),
+                amount: dispute_details.amount,
+                currency: dispute_details.currency,
+                dispute_stage: dispute_details.dispute_stage.foreign_into(),
+                dispute_status,
+                payment_id: payment_attempt.payment_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                merchant_id: payment_attempt.merchant_id.clone(),
+                connector: connector_name.to_string(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                connector_created_at: dispute_details.created_at,
+                connector_updated_at: dispute_details.updated_at,
+            };
+            db.insert_dispute(new_dispute)
+                .await
+                .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?
+        }
+    };
+
+    Ok((dispute, is_status_changed))
+}
+
+pub fn get_dispute_status(
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<enums::DisputeStatus, errors::WebhooksFlowError> {
+    match event_type {
+        api::IncomingWebhookEvent::DisputeOpened => Ok(enums::DisputeStatus::DisputeOpened),
+        api::IncomingWebhookEvent::DisputeExpired => Ok(enums::DisputeStatus::DisputeExpired),
+        api::IncomingWebhookEvent::DisputeAccepted => Ok(enums::DisputeStatus::DisputeAccepted),
+        api::IncomingWebhookEvent::DisputeCancelled => Ok(enums::DisputeStatus::DisputeCancelled),
+        api::IncomingWebhookEvent::DisputeChallenged => Ok(enums::DisputeStatus::DisputeChallenged),
+        api::IncomingWebhookEvent::DisputeWon => Ok(enums::DisputeStatus::DisputeWon),
+        api::IncomingWebhookEvent::DisputeLost => Ok(enums::DisputeStatus::DisputeLost),
+        _ => Err(errors::WebhooksFlowError::DisputeWebhookValidationFailed).into_report(),
+    }
+}
+
+pub fn get_incremental_authorization_status(
+    event_type: api::IncomingWebhookEvent,
+) -> CustomResult<enums::AuthorizationStatus, errors::WebhooksFlowError> {
+    match event_type {
+        api::IncomingWebhookEvent::IncrementalAuthorizationSuccess => {
+            Ok(enums::AuthorizationStatus::Success)
+        }
+        api::IncomingWebhookEvent::IncrementalAuthorizationFailure => {
+            Ok(enums::AuthorizationStatus::Failure)
+        }
+        _ => Err(errors::WebhooksFlowError::IncrementalAuthorizationWebhookValidationFailed)
+            .into_report(),
+    }
+}
+
+/// Notify the merchant of the current status of a payment, if the status has an associated
+/// event
+#[instrument(skip_all)]
+pub(crate) async fn trigger_payments_outgoing_webhook<F, Op>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: payments::PaymentData<F>,
+    customer: Option<storage::Customer>,
+    operation: Op,
+) where
+    F: Clone,
+    Op: Debug,
+{
+    let payments_response = match payments::transformers::payments_to_payments_response(
+        None::<api::PaymentsRequest>,
+        payment_data.payment_attempt,
+        payment_data.payment_intent,
+        payment_data.refunds,
+        payment_data.captures,
+        payment_data.payment_method_data,
+        customer,
+        services::AuthFlow::Merchant,
+        payment_data.address,
+        &state.conf.server,
+        payment_data.connector_response.authentication_data,
+        operation,
+    ) {
+        Ok(services::ApplicationResponse::Json(payments_response)) => payments_response,
+        Ok(_) => return,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to construct the payment for the outgoing webhook"
+            );
+            return;
+        }
+    };
+
+    let event_type: Result<enums::EventType, _> = payments_response.status.foreign_try_into();
+    let (payment_id, event_type) = match (payments_response.payment_id.clone(), event_type) {
+        (Some(payment_id), Ok(event_type)) => (payment_id, event_type),
+        _ => return,
+    };
+
+    if let Err(error) = create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to trigger outgoing webhook for payment");
+    }
+}
+
+/// Notify the merchant of the current status of a refund, if the status has an associated event
+#[instrument(skip_all)]
+pub(crate) async fn trigger_refund_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    refund: &storage::Refund,
+) {
+    let event_type: enums::EventType = match refund.refund_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+
+    if let Err(error) = create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::Refunds,
+        Some(refund.payment_id.clone()),
+        refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund.clone().foreign_into()),
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to trigger outgoing webhook for refund");
+    }
+}
+
+/// Notify the merchant of the current status of a dispute
+#[instrument(skip_all)]
+pub(crate) async fn trigger_dispute_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+) {
+    if let Err(error) = create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        dispute.dispute_status.foreign_into(),
+        enums::EventClass::Disputes,
+        Some(dispute.payment_id.clone()),
+        dispute.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(dispute.clone().foreign_into()),
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to trigger outgoing webhook for dispute");
+    }
+}
+
+/// Notify the merchant of the current status of a mandate, if the status has an associated
+/// event
+#[instrument(skip_all)]
+pub(crate) async fn trigger_mandate_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) {
+    let event_type: enums::EventType = match mandate.mandate_status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return,
+    };
+    let mandate_id = mandate.mandate_id.clone();
+
+    let mandate_response =
+        match mandates::MandateResponse::from_db_mandate(state, mandate, merchant_account).await {
+            Ok(mandate_response) => mandate_response,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to construct the mandate for the outgoing webhook"
+                );
+                return;
+            }
+        };
+
+    if let Err(error) = create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::Mandates,
+        None,
+        mandate_id,
+        enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(mandate_response),
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to trigger outgoing webhook for mandate");
+    }
+}
+
+/// Events are sent to the merchant unless the merchant has disabled them in the webhook details
+fn is_outgoing_webhook_enabled(
+    webhook_details: &api::WebhookDetails,
+    event_type: enums::EventType,
+) -> bool {
+    let enabled = match event_type {
+        enums::EventType::PaymentSucceeded => webhook_details.payment_succeeded_enabled,
+        enums::EventType::PaymentFailed => webhook_details.payment_failed_enabled,
+        enums::EventType::PaymentProcessing => webhook_details.payment_processing_enabled,
+        enums::EventType::PaymentCancelled => webhook_details.payment_cancelled_enabled,
+        enums::EventType::ActionRequired => webhook_details.action_required_enabled,
+        enums::EventType::RefundSucceeded => webhook_details.refund_succeeded_enabled,
+        enums::EventType::RefundFailed => webhook_details.refund_failed_enabled,
+        enums::EventType::MandateActive => webhook_details.mandate_active_enabled,
+        enums::EventType::MandateRevoked => webhook_details.mandate_revoked_enabled,
+        enums::EventType::DisputeOpened
+        | enums::EventType::DisputeExpired
+        | enums::EventType::DisputeAccepted
+        | enums::EventType::DisputeCancelled
+        | enums::EventType::DisputeChallenged
+        | enums::EventType::DisputeWon
+        | enums::EventType::DisputeLost => webhook_details.dispute_status_changed_enabled,
+    };
+    enabled.unwrap_or(true)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook(
+pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     event_type: enums::EventType,
@@ -102,6 +471,19 @@ async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
+    let webhook_details: Option<api::WebhookDetails> = merchant_account
+        .webhook_details
+        .clone()
+        .map(|webhook_details| webhook_details.parse_value("WebhookDetails"))
+        .transpose()
+        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+    if let Some(webhook_details) = webhook_details {
+        if !is_outgoing_webhook_enabled(&webhook_details, event_type) {
+            logger::debug!(%event_type, "Outgoing webhook disabled by the merchant");
+            return Ok(());
+        }
+    }
+
     let new_event = storage::EventNew {
         event_id: generate_id(consts::ID_LENGTH, "evt"),
         event_type,
@@ -119,38 +501,250 @@ async fn create_event_and_trigger_outgoing_webhook(
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
     if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-            .into_report()?;
-
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
+            event_id: event.event_id.clone(),
             event_type: event.event_type.foreign_into(),
             content,
             timestamp: event.created_at,
         };
+        let webhook = Encode::<api::OutgoingWebhook>::encode_to_value(&outgoing_webhook)
+            .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
-        arbiter.spawn(async move {
-            let result =
-                trigger_webhook_to_merchant(merchant_account, outgoing_webhook, state.store).await;
+        match actix::Arbiter::try_current() {
+            Some(arbiter) => {
+                arbiter.spawn(async move {
+                    let result = trigger_webhook_and_schedule_retry(
+                        &state,
+                        &merchant_account,
+                        event,
+                        webhook,
+                    )
+                    .await;
 
-            if let Err(e) = result {
-                logger::error!(?e);
+                    if let Err(e) = result {
+                        logger::error!(?e);
+                    }
+                });
+            }
+            // Outside the actix runtime, such as in the scheduler, the webhook is delivered by
+            // the process tracker instead
+            None => {
+                add_outgoing_webhook_retry_task(
+                    &*state.store,
+                    &merchant_account.merchant_id,
+                    &event.event_id,
+                    webhook,
+                    common_utils::date_time::now(),
+                )
+                .await?
             }
-        });
+        }
     }
 
     Ok(())
 }
 
+/// Attempt the first delivery of the webhook, and hand it over to the process tracker if the
+/// delivery has to be retried
+async fn trigger_webhook_and_schedule_retry(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event: storage::Event,
+    webhook: serde_json::Value,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let (event, retry_schedule_time) =
+        deliver_outgoing_webhook(state, merchant_account, event, &webhook).await?;
+
+    match retry_schedule_time {
+        Some(schedule_time) => {
+            add_outgoing_webhook_retry_task(
+                &*state.store,
+                &merchant_account.merchant_id,
+                &event.event_id,
+                webhook,
+                schedule_time,
+            )
+            .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Send the webhook to the merchant and record the outcome of the delivery attempt on the event.
+/// If the delivery failed and can be retried, the time of the next attempt is returned as well.
+async fn deliver_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event: storage::Event,
+    webhook: &serde_json::Value,
+) -> CustomResult<(storage::Event, Option<time::PrimitiveDateTime>), errors::WebhooksFlowError> {
+    let delivery_attempts = event.delivery_attempts.saturating_add(1);
+
+    let (delivery_status, retry_schedule_time) =
+        match trigger_webhook_to_merchant(merchant_account, webhook).await {
+            Ok(()) => (enums::WebhookDeliveryStatus::Delivered, None),
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    event_id = %event.event_id,
+                    delivery_attempts,
+                    "Failed to deliver outgoing webhook"
+                );
+
+                // Only failures of the merchant endpoint are retried, retrying does not help when
+                // the merchant has not configured a webhook URL
+                let is_retryable = matches!(
+                    error.current_context(),
+                    errors::WebhooksFlowError::CallToMerchantFailed
+                        | errors::WebhooksFlowError::NotReceivedByMerchant
+                );
+                let retry_schedule_time = if is_retryable {
+                    pt_utils::get_time_from_delta(get_outgoing_webhook_retry_delay(
+                        &state.conf.webhooks,
+                        delivery_attempts,
+                    ))
+                } else {
+                    None
+                };
+
+                match retry_schedule_time {
+                    Some(_) => (
+                        enums::WebhookDeliveryStatus::RetryScheduled,
+                        retry_schedule_time,
+                    ),
+                    None => (enums::WebhookDeliveryStatus::Failed, None),
+                }
+            }
+        };
+
+    let event = state
+        .store
+        .update_event(
+            event,
+            storage::EventUpdate::DeliveryAttemptUpdate {
+                delivery_status,
+                delivery_attempts,
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdateFailed)?;
+
+    Ok((event, retry_schedule_time))
+}
+
+/// Delay before retrying an outgoing webhook that has failed `delivery_attempts` times, doubling
+/// with every failed attempt. Returns `None` once the maximum number of attempts is reached.
+fn get_outgoing_webhook_retry_delay(
+    webhooks_settings: &settings::WebhooksSettings,
+    delivery_attempts: i16,
+) -> Option<i32> {
+    if delivery_attempts >= webhooks_settings.max_delivery_attempts {
+        return None;
+    }
+
+    let backoff_multiplier = u32::try_from(delivery_attempts.saturating_sub(1))
+        .ok()
+        .and_then(|exponent| 2_i32.checked_pow(exponent))
+        .unwrap_or(i32::MAX);
+
+    Some(
+        webhooks_settings
+            .retry_base_delay_in_secs
+            .saturating_mul(backoff_multiplier),
+    )
+}
+
+#[instrument(skip_all)]
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    event_id: &str,
+    webhook: serde_json::Value,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let current_time = common_utils::date_time::now();
+    let tracking_data = storage::OutgoingWebhookTrackingData {
+        merchant_id: merchant_id.to_string(),
+        event_id: event_id.to_string(),
+        webhook,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(runner, task, event_id, merchant_id),
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: Encode::<storage::OutgoingWebhookTrackingData>::encode_to_value(
+            &tracking_data,
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)
+        .attach_printable_lazy(|| {
+            format!("Failed to schedule retry of outgoing webhook for event: {event_id}")
+        })?;
+
+    Ok(())
+}
+
+/// Retry the delivery of an outgoing webhook scheduled by the process tracker, rescheduling the
+/// task with an increased delay if the delivery fails again
+#[instrument(skip_all)]
+pub async fn start_outgoing_webhook_retry_workflow(
+    state: &AppState,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let tracking_data: storage::OutgoingWebhookTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("OutgoingWebhookTrackingData")?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+        .await?;
+    let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+
+    let (event, retry_schedule_time) =
+        deliver_outgoing_webhook(state, &merchant_account, event, &tracking_data.webhook).await?;
+
+    match (retry_schedule_time, event.delivery_status) {
+        (Some(schedule_time), _) => process.retry(db, schedule_time).await,
+        (None, enums::WebhookDeliveryStatus::Delivered) => {
+            let id = process.id.clone();
+            process
+                .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+                .await
+        }
+        (None, _) => {
+            process
+                .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
 async fn trigger_webhook_to_merchant(
-    merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
+    merchant_account: &storage::MerchantAccount,
+    webhook: &serde_json::Value,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = merchant_account
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -165,10 +759,42 @@ async fn trigger_webhook_to_merchant(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let response = reqwest::Client::new()
+    let payload = serde_json::to_vec(webhook)
+        .into_report()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to serialize the outgoing webhook")?;
+
+    let mut request = reqwest::Client::new()
         .post(&webhook_url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+
+    // The webhook secret takes precedence over the payment response hash key, webhooks are sent
+    // unsigned only if the merchant has configured neither
+    let signing_key = webhook_details
+        .webhook_secret
+        .map(ExposeInterface::expose)
+        .or_else(|| merchant_account.payment_response_hash_key.clone());
+    match signing_key {
+        Some(signing_key) => {
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+            let signature =
+                webhook_utils::sign_webhook(signing_key.as_bytes(), timestamp, &payload)
+                    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+            request = request
+                .header(
+                    webhook_utils::WEBHOOK_TIMESTAMP_HEADER,
+                    timestamp.to_string(),
+                )
+                .header(webhook_utils::WEBHOOK_SIGNATURE_HEADER, signature);
+        }
+        None => logger::warn!(
+            merchant_id = %merchant_account.merchant_id,
+            "Sending unsigned outgoing webhook, no signing key configured"
+        ),
+    }
+
+    let response = request
+        .body(payload)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
@@ -177,15 +803,17 @@ async fn trigger_webhook_to_merchant(
 
     match response {
         Err(e) => {
-            // [#217]: Schedule webhook for retry.
             Err(e)
                 .into_report()
                 .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
         }
         Ok(res) => {
             if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant)
+                    .into_report()
+                    .attach_printable_lazy(|| {
+                        format!("Merchant responded with status: {}", res.status())
+                    })?;
             }
         }
     }
@@ -266,7 +894,7 @@ pub async fn webhooks_core(
                 )?,
         };
 
-        let flow_type: api::WebhookFlow = event_type.into();
+        let flow_type: api::WebhookFlow = event_type.clone().into();
         match flow_type {
             api::WebhookFlow::Payment => payments_incoming_webhook_flow(
                 state.clone(),
@@ -277,6 +905,31 @@ pub async fn webhooks_core(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Incoming webhook flow for payments failed")?,
+            api::WebhookFlow::Dispute => disputes_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                *connector,
+                &decoded_body,
+                connector_name,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for disputes failed")?,
+            api::WebhookFlow::IncrementalAuthorization => {
+                incremental_authorizations_incoming_webhook_flow(
+                    state.clone(),
+                    merchant_account,
+                    webhook_details,
+                    source_verified,
+                    event_type,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Incoming webhook flow for incremental authorizations failed")?
+            }
             _ => Err(errors::ApiErrorResponse::InternalServerError)
                 .into_report()
                 .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
@@ -290,3 +943,259 @@ pub async fn webhooks_core(
 
     Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::db::StorageImpl;
+
+    async fn mk_state_and_event(
+        webhook_details: Option<serde_json::Value>,
+    ) -> (AppState, storage::MerchantAccount, storage::Event) {
+        let mut conf = settings::Settings::new().unwrap();
+        conf.webhooks = settings::WebhooksSettings {
+            outgoing_enabled: true,
+            max_delivery_attempts: 2,
+            retry_base_delay_in_secs: 60,
+        };
+        let state = AppState::with_storage(conf, StorageImpl::Mock).await;
+
+        let merchant_account = state
+            .store
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: generate_id(consts::ID_LENGTH, "merchant"),
+                webhook_details,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let event = state
+            .store
+            .insert_event(storage::EventNew {
+                event_id: generate_id(consts::ID_LENGTH, "evt"),
+                event_type: enums::EventType::PaymentSucceeded,
+                event_class: enums::EventClass::Payments,
+                is_webhook_notified: false,
+                intent_reference_id: None,
+                primary_object_id: "pay_webhook_delivery".to_string(),
+                primary_object_type: enums::EventObjectType::PaymentDetails,
+            })
+            .await
+            .unwrap();
+
+        (state, merchant_account, event)
+    }
+
+    #[actix_rt::test]
+    async fn test_failed_delivery_is_retried_until_attempts_are_exhausted() {
+        // Nothing listens on the discard port, so every delivery fails to connect
+        let (state, merchant_account, event) = mk_state_and_event(Some(serde_json::json!({
+            "webhook_url": "http://127.0.0.1:9/webhooks"
+        })))
+        .await;
+        let webhook = serde_json::json!({ "event_id": event.event_id });
+        let event_id = event.event_id.clone();
+
+        trigger_webhook_and_schedule_retry(&state, &merchant_account, event, webhook.clone())
+            .await
+            .unwrap();
+
+        let event = state.store.find_event_by_event_id(&event_id).await.unwrap();
+        assert_eq!(
+            event.delivery_status,
+            enums::WebhookDeliveryStatus::RetryScheduled
+        );
+        assert_eq!(event.delivery_attempts, 1);
+        assert!(!event.is_webhook_notified);
+        assert!(event.last_delivery_attempted_at.is_some());
+
+        let process_id = pt_utils::get_process_tracker_id(
+            "OUTGOING_WEBHOOK_RETRY_WORKFLOW",
+            "OUTGOING_WEBHOOK_RETRY",
+            &event_id,
+            &merchant_account.merchant_id,
+        );
+        let process = state
+            .store
+            .find_process_by_id(&process_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .parse_value("OutgoingWebhookTrackingData")
+            .unwrap();
+        assert_eq!(tracking_data.event_id, event_id);
+        assert_eq!(tracking_data.webhook, webhook);
+
+        let (event, retry_schedule_time) =
+            deliver_outgoing_webhook(&state, &merchant_account, event, &webhook)
+                .await
+                .unwrap();
+        assert!(retry_schedule_time.is_none());
+        assert_eq!(event.delivery_status, enums::WebhookDeliveryStatus::Failed);
+        assert_eq!(event.delivery_attempts, 2);
+    }
+
+    #[actix_rt::test]
+    async fn test_delivery_without_webhook_url_is_not_retried() {
+        let (state, merchant_account, event) = mk_state_and_event(None).await;
+        let webhook = serde_json::json!({ "event_id": event.event_id });
+
+        let (event, retry_schedule_time) =
+            deliver_outgoing_webhook(&state, &merchant_account, event, &webhook)
+                .await
+                .unwrap();
+        assert!(retry_schedule_time.is_none());
+        assert_eq!(event.delivery_status, enums::WebhookDeliveryStatus::Failed);
+        assert_eq!(event.delivery_attempts, 1);
+
+        let stored = state
+            .store
+            .find_event_by_event_id(&event.event_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.delivery_status, enums::WebhookDeliveryStatus::Failed);
+    }
+
+    #[actix_rt::test]
+    async fn test_incremental_authorization_webhook_resolves_processing_authorization() {
+        let (state, merchant_account, _) = mk_state_and_event(None).await;
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+
+        db.insert_payment_intent(
+            storage::PaymentIntentNew {
+                payment_id: "pay_incremental".to_string(),
+                merchant_id: merchant_id.clone(),
+                status: enums::IntentStatus::RequiresCapture,
+                amount: 1000,
+                request_incremental_authorization: true,
+                ..Default::default()
+            },
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        db.insert_payment_attempt(
+            storage::PaymentAttemptNew {
+                payment_id: "pay_incremental".to_string(),
+                merchant_id: merchant_id.clone(),
+                attempt_id: "attempt_incremental".to_string(),
+                status: enums::AttemptStatus::Authorized,
+                amount: 1000,
+                ..Default::default()
+            },
+            storage_scheme,
+        )
+        .await
+        .unwrap();
+        let authorization = db
+            .insert_incremental_authorization(storage::IncrementalAuthorizationNew {
+                authorization_id: "auth_incremental".to_string(),
+                payment_id: "pay_incremental".to_string(),
+                merchant_id: merchant_id.clone(),
+                attempt_id: "attempt_incremental".to_string(),
+                status: enums::AuthorizationStatus::Started,
+                amount: 1500,
+                previously_authorized_amount: 1000,
+                connector: "adyen".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        db.update_incremental_authorization(
+            authorization,
+            storage::IncrementalAuthorizationUpdate::ResponseUpdate {
+                status: enums::AuthorizationStatus::Processing,
+                connector_authorization_id: Some("psp_adjustment".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+        let webhook_details = || api::IncomingWebhookDetails {
+            object_reference_id: "psp_adjustment".to_string(),
+            resource_object: vec![],
+        };
+        let report_outcome = |source_verified, event_type| {
+            incremental_authorizations_incoming_webhook_flow(
+                state.clone(),
+                merchant_account.clone(),
+                webhook_details(),
+                source_verified,
+                event_type,
+            )
+        };
+
+        assert!(report_outcome(
+            false,
+            api::IncomingWebhookEvent::IncrementalAuthorizationSuccess
+        )
+        .await
+        .is_err());
+        report_outcome(
+            true,
+            api::IncomingWebhookEvent::IncrementalAuthorizationSuccess,
+        )
+        .await
+        .unwrap();
+        // The outcome is only recorded once
+        report_outcome(
+            true,
+            api::IncomingWebhookEvent::IncrementalAuthorizationFailure,
+        )
+        .await
+        .unwrap();
+
+        let authorizations = db
+            .find_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                "pay_incremental",
+                "attempt_incremental",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            authorizations[0].status,
+            enums::AuthorizationStatus::Success
+        );
+        let payment_attempt = db
+            .find_payment_attempt_by_merchant_id_attempt_id(
+                merchant_id,
+                "attempt_incremental",
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_attempt.amount, 1500);
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                "pay_incremental",
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_intent.amount, 1500);
+    }
+
+    #[test]
+    fn test_get_outgoing_webhook_retry_delay() {
+        let webhooks_settings = settings::WebhooksSettings {
+            outgoing_enabled: true,
+            max_delivery_attempts: 4,
+            retry_base_delay_in_secs: 60,
+        };
+
+        let retry_delays: Vec<_> = (1..=4)
+            .map(|delivery_attempts| {
+                get_outgoing_webhook_retry_delay(&webhooks_settings, delivery_attempts)
+            })
+            .collect();
+        assert_eq!(retry_delays, vec![Some(60), Some(120), Some(240), None]);
+    }
+}
@@ -3,8 +3,15 @@ use crate::{
     types::api,
 };
 
+/// Events processed for every merchant, the dispute events have to be enabled by the merchant.
+/// The outcome of incremental authorizations is only reported by webhooks, so their events are
+/// always processed.
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::IncrementalAuthorizationSuccess,
+        api::IncomingWebhookEvent::IncrementalAuthorizationFailure,
+    ])
 }
 
 pub async fn lookup_webhook_event(
@@ -1,11 +1,16 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod connector_success_rate;
 pub mod customers;
+pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
+pub mod file;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,6 +18,7 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
 pub mod refund;
@@ -38,11 +44,16 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
+    + connector_success_rate::ConnectorSuccessRateInterface
     + customers::CustomerInterface
+    + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + file::FileMetadataInterface
+    + incremental_authorization::IncrementalAuthorizationInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -51,6 +62,7 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
     + refund::RefundInterface
@@ -58,6 +70,8 @@ pub trait StorageInterface:
     + 'static
 {
     async fn close(&mut self) {}
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool>;
 }
 
 #[async_trait::async_trait]
@@ -69,6 +83,10 @@ impl StorageInterface for Store {
             .close_connections()
             .await;
     }
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool> {
+        self.redis_conn.clone()
+    }
 }
 
 #[derive(Clone)]
@@ -79,6 +97,12 @@ pub struct MockDb {
     payment_intents: Arc<Mutex<Vec<storage::PaymentIntent>>>,
     customers: Arc<Mutex<Vec<storage::Customer>>>,
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
+    payouts: Arc<Mutex<Vec<storage::Payout>>>,
+    disputes: Arc<Mutex<Vec<storage::Dispute>>>,
+    captures: Arc<Mutex<Vec<storage::Capture>>>,
+    incremental_authorizations: Arc<Mutex<Vec<storage::IncrementalAuthorization>>>,
+    files: Arc<Mutex<Vec<storage::FileMetadata>>>,
+    events: Arc<Mutex<Vec<storage::Event>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
@@ -93,6 +117,12 @@ impl MockDb {
             payment_intents: Default::default(),
             customers: Default::default(),
             refunds: Default::default(),
+            payouts: Default::default(),
+            disputes: Default::default(),
+            captures: Default::default(),
+            incremental_authorizations: Default::default(),
+            files: Default::default(),
+            events: Default::default(),
             processes: Default::default(),
             connector_response: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
@@ -109,6 +139,10 @@ impl StorageInterface for MockDb {
             .close_connections()
             .await;
     }
+
+    fn get_redis_conn(&self) -> Arc<redis_interface::RedisConnectionPool> {
+        self.redis.clone()
+    }
 }
 
 pub async fn get_and_deserialize_key<T>(
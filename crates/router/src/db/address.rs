@@ -1,10 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,16 +10,19 @@ pub trait AddressInterface {
         &self,
         address_id: String,
         address: storage::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn insert_address(
         &self,
         address: storage::AddressNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn find_address(
         &self,
         address_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn update_address_by_merchant_id_customer_id(
@@ -30,62 +30,373 @@ pub trait AddressInterface {
         customer_id: &str,
         merchant_id: &str,
         address: storage::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl AddressInterface for Store {
-    async fn find_address(
-        &self,
-        address_id: &str,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Address::find_by_address_id(&conn, address_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::date_time;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::AddressInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    impl Store {
+        /// Addresses are stored in the hash of their customer, and are looked up by their ID
+        /// through a reverse lookup. Addresses created before the merchant moved to the KV store
+        /// have no reverse lookup, and are only present in the database.
+        async fn find_address_lookup(
+            &self,
+            address_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::ReverseLookup>, errors::StorageError> {
+            match self
+                .get_lookup_by_lookup_id(&format!("address_{address_id}"), storage_scheme)
+                .await
+            {
+                Ok(lookup) => Ok(Some(lookup)),
+                Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                Err(error) => Err(error),
+            }
+        }
+
+        async fn update_address_in_redis(
+            &self,
+            this: storage_types::Address,
+            address: storage_types::AddressUpdate,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let key = format!("{}_{}", this.merchant_id, this.customer_id);
+            let field = format!("add_{}", this.address_id);
+            let updated_address = address.clone().apply_changeset(this.clone());
+
+            let redis_value =
+                utils::Encode::<storage_types::Address>::encode_to_string_of_json(&updated_address)
+                    .change_context(errors::StorageError::SerializationFailed)?;
+
+            self.redis_conn
+                .set_hash_fields(&key, (&field, &redis_value))
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            let redis_entry = kv::DBOperation::Update {
+                updatable: kv::Updateable::AddressUpdate(kv::AddressUpdateMems {
+                    orig: this,
+                    update_data: address,
+                }),
+            };
+            self.push_to_drainer_stream::<storage_types::Address>(
+                redis_entry,
+                PartitionKey::MerchantIdCustomerId {
+                    merchant_id: &updated_address.merchant_id,
+                    customer_id: &updated_address.customer_id,
+                },
+            )
+            .await?;
+
+            Ok(updated_address)
+        }
     }
 
-    async fn update_address(
-        &self,
-        address_id: String,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Address::update_by_address_id(&conn, address_id, address)
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage_types::Address::find_by_address_id(&conn, address_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    match self.find_address_lookup(address_id, storage_scheme).await? {
+                        Some(lookup) => {
+                            db_utils::try_redis_get_else_try_database_get(
+                                self.redis_conn.get_hash_field_and_deserialize(
+                                    &lookup.pk_id,
+                                    &lookup.sk_id,
+                                    "Address",
+                                ),
+                                database_call,
+                            )
+                            .await
+                        }
+                        None => database_call().await,
+                    }
+                }
+            }
+        }
+
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage_types::AddressUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let lookup = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => None,
+                enums::MerchantStorageScheme::RedisKv => {
+                    self.find_address_lookup(&address_id, storage_scheme)
+                        .await?
+                }
+            };
+
+            match lookup {
+                Some(lookup) => {
+                    let database_call = || async {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage_types::Address::find_by_address_id(&conn, &address_id)
+                            .await
+                            .map_err(Into::into)
+                            .into_report()
+                    };
+                    let this = db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn.get_hash_field_and_deserialize(
+                            &lookup.pk_id,
+                            &lookup.sk_id,
+                            "Address",
+                        ),
+                        database_call,
+                    )
+                    .await?;
+                    self.update_address_in_redis(this, address).await
+                }
+                None => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    storage_types::Address::update_by_address_id(&conn, address_id, address)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+            }
+        }
+
+        async fn insert_address(
+            &self,
+            address: storage_types::AddressNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    address
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", address.merchant_id, address.customer_id);
+                    let field = format!("add_{}", address.address_id);
+                    db_utils::check_database_absence("address", &address.address_id, || async {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage_types::Address::find_by_address_id(&conn, &address.address_id)
+                            .await
+                            .map_err(Into::into)
+                            .into_report()
+                    })
+                    .await?;
+
+                    let now = date_time::now();
+                    let created_address = storage_types::Address {
+                        id: 0i32,
+                        address_id: address.address_id.clone(),
+                        city: address.city.clone(),
+                        country: address.country.clone(),
+                        line1: address.line1.clone(),
+                        line2: address.line2.clone(),
+                        line3: address.line3.clone(),
+                        state: address.state.clone(),
+                        zip: address.zip.clone(),
+                        first_name: address.first_name.clone(),
+                        last_name: address.last_name.clone(),
+                        phone_number: address.phone_number.clone(),
+                        country_code: address.country_code.clone(),
+                        created_at: now,
+                        modified_at: now,
+                        customer_id: address.customer_id.clone(),
+                        merchant_id: address.merchant_id.clone(),
+                    };
+
+                    match self
+                        .redis_conn
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_address)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "address",
+                            key: Some(created_address.address_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            self.insert_reverse_lookup(
+                                storage_types::ReverseLookupNew {
+                                    lookup_id: format!("address_{}", created_address.address_id),
+                                    pk_id: key,
+                                    sk_id: field,
+                                    source: "address".to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await?;
+
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::Address(address),
+                            };
+                            self.push_to_drainer_stream::<storage_types::Address>(
+                                redis_entry,
+                                PartitionKey::MerchantIdCustomerId {
+                                    merchant_id: &created_address.merchant_id,
+                                    customer_id: &created_address.customer_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_address)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage_types::AddressUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let database_result = storage_types::Address::update_by_merchant_id_customer_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                address.clone(),
+            )
             .await
-            .map_err(Into::into)
-            .into_report()
+            .map_err(Into::<errors::StorageError>::into)
+            .into_report();
+
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_result,
+                enums::MerchantStorageScheme::RedisKv => {
+                    // The database holds the addresses created before the merchant moved to the
+                    // KV store, while the hash of the customer holds the remaining ones, some of
+                    // which may not have been drained to the database yet
+                    let mut addresses = match database_result {
+                        Ok(addresses) => addresses,
+                        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+                        Err(error) => return Err(error),
+                    };
+
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let redis_addresses = self
+                        .redis_conn
+                        .hscan_and_deserialize::<storage_types::Address>(&key, "add_*", None)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    for redis_address in redis_addresses {
+                        let updated_address = self
+                            .update_address_in_redis(redis_address, address.clone())
+                            .await?;
+                        addresses
+                            .retain(|existing| existing.address_id != updated_address.address_id);
+                        addresses.push(updated_address);
+                    }
+
+                    Ok(addresses)
+                }
+            }
+        }
     }
+}
 
-    async fn insert_address(
-        &self,
-        address: storage::AddressNew,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        address
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::AddressInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage_types::AddressUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Address::update_by_address_id(&conn, address_id, address)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn insert_address(
+            &self,
+            address: storage_types::AddressNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            address
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage_types::AddressUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Address::update_by_merchant_id_customer_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                address,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
-
-    async fn update_address_by_merchant_id_customer_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Address::update_by_merchant_id_customer_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            address,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        }
     }
 }
 
@@ -94,6 +405,7 @@ impl AddressInterface for MockDb {
     async fn find_address(
         &self,
         _address_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -103,6 +415,7 @@ impl AddressInterface for MockDb {
         &self,
         _address_id: String,
         _address: storage::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -111,6 +424,7 @@ impl AddressInterface for MockDb {
     async fn insert_address(
         &self,
         _address: storage::AddressNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -121,6 +435,7 @@ impl AddressInterface for MockDb {
         _customer_id: &str,
         _merchant_id: &str,
         _address: storage::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -1,54 +1,466 @@
-use error_stack::ResultExt;
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, PoisonError},
+    time::{Duration, Instant},
+};
+
+use common_utils::ext_traits::ByteSliceExt;
+use error_stack::{report, IntoReport, ResultExt};
+use futures::{
+    channel::oneshot,
+    future::{FutureExt, Shared},
+    StreamExt,
+};
+use storage_models::errors::DatabaseError;
 
 use super::Store;
-use crate::core::errors::{self, CustomResult};
+use crate::{
+    configs::settings::CacheSettings,
+    core::errors::{self, CustomResult},
+};
+
+/// Channel on which the redacted keys are published, so that every application instance drops
+/// them from its in-memory cache
+const CACHE_INVALIDATION_CHANNEL: &str = "cache_invalidation";
+
+/// Value stored in the Redis cache for keys which were not found in the database
+const NOT_FOUND_MARKER: &[u8] = b"__not_found__";
+
+/// Entry loaded by a pending load, or `None` when the load failed
+type PendingLoad = Shared<oneshot::Receiver<Option<CacheEntry>>>;
+
+/// Bounded in-memory cache in front of the Redis cache, holding the deserialized values
+pub struct Cache {
+    in_memory: moka::future::Cache<String, CacheEntry>,
+    /// Loads in progress, awaited by the concurrent loads of the same key instead of loading it
+    /// again
+    loads: std::sync::Mutex<HashMap<String, PendingLoad>>,
+    ttl: Duration,
+    not_found_ttl: Duration,
+    redis_ttl: i64,
+    redis_not_found_ttl: i64,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    /// `None` for keys which were not found in the database
+    value: Option<Arc<dyn Any + Send + Sync>>,
+    expires_at: Instant,
+}
+
+enum Cached<T> {
+    Found(T),
+    NotFound,
+}
+
+enum Load<'a> {
+    /// No other load of the key is in progress, the caller loads it
+    Leader(LoadGuard<'a>),
+    /// Another load of the key is in progress, the caller awaits its entry
+    Follower(PendingLoad),
+}
+
+/// Load of a key led by the caller, removed from the loads in progress when it finishes or when
+/// it is cancelled
+struct LoadGuard<'a> {
+    cache: &'a Cache,
+    key: &'a str,
+    sender: Option<oneshot::Sender<Option<CacheEntry>>>,
+}
+
+impl Cache {
+    pub fn new(settings: &CacheSettings) -> Self {
+        let ttl = Duration::from_secs(settings.ttl);
+        Self {
+            in_memory: moka::future::Cache::builder()
+                .max_capacity(settings.max_capacity)
+                .time_to_live(ttl)
+                .build(),
+            loads: Default::default(),
+            ttl,
+            not_found_ttl: Duration::from_secs(settings.not_found_ttl.into()),
+            redis_ttl: settings.redis_ttl.into(),
+            redis_not_found_ttl: settings.not_found_ttl.into(),
+        }
+    }
+
+    fn get<T: Clone + 'static>(&self, key: &str) -> Option<Cached<T>> {
+        let entry = self.in_memory.get(key)?;
+        if entry.expires_at <= Instant::now() {
+            return None;
+        }
+        entry.to_cached()
+    }
+
+    async fn insert<T: Clone + Send + Sync + 'static>(
+        &self,
+        key: &str,
+        cached: &Cached<T>,
+    ) -> CacheEntry {
+        let entry = match cached {
+            Cached::Found(value) => CacheEntry {
+                value: Some(Arc::new(value.clone())),
+                expires_at: Instant::now() + self.ttl,
+            },
+            Cached::NotFound => CacheEntry {
+                value: None,
+                expires_at: Instant::now() + self.not_found_ttl,
+            },
+        };
+        self.in_memory.insert(key.to_string(), entry.clone()).await;
+        entry
+    }
+
+    async fn invalidate(&self, key: &str) {
+        self.in_memory.invalidate(key).await;
+    }
+
+    fn start_load<'a>(&'a self, key: &'a str) -> Load<'a> {
+        let mut loads = self.loads.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(pending) = loads.get(key) {
+            return Load::Follower(pending.clone());
+        }
+        let (sender, receiver) = oneshot::channel();
+        loads.insert(key.to_string(), receiver.shared());
+        Load::Leader(LoadGuard {
+            cache: self,
+            key,
+            sender: Some(sender),
+        })
+    }
+
+    fn remove_load(&self, key: &str) {
+        let mut loads = self.loads.lock().unwrap_or_else(PoisonError::into_inner);
+        loads.remove(key);
+    }
+}
 
+impl CacheEntry {
+    fn to_cached<T: Clone + 'static>(&self) -> Option<Cached<T>> {
+        match &self.value {
+            Some(value) => value.downcast_ref::<T>().cloned().map(Cached::Found),
+            None => Some(Cached::NotFound),
+        }
+    }
+}
+
+impl LoadGuard<'_> {
+    /// Share the loaded entry with the loads of the key which awaited this one
+    fn finish(mut self, entry: Option<CacheEntry>) {
+        self.cache.remove_load(self.key);
+        if let Some(sender) = self.sender.take() {
+            sender.send(entry).ok();
+        }
+    }
+}
+
+impl Drop for LoadGuard<'_> {
+    fn drop(&mut self) {
+        // The load was cancelled, the loads which awaited it load the key themselves
+        if self.sender.is_some() {
+            self.cache.remove_load(self.key);
+        }
+    }
+}
+
+impl<T> Cached<T> {
+    fn into_result(self, key: &str) -> CustomResult<T, errors::StorageError> {
+        match self {
+            Self::Found(value) => Ok(value),
+            Self::NotFound => Err(errors::StorageError::DatabaseError(report!(
+                DatabaseError::NotFound
+            )))
+            .into_report()
+            .attach_printable_lazy(|| format!("{key} was cached as not found")),
+        }
+    }
+}
+
+/// Drop the keys redacted by any application instance from the in-memory cache, until the Redis
+/// connections are closed
+pub async fn subscribe_to_invalidations(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    cache: Arc<Cache>,
+) -> CustomResult<(), errors::RedisError> {
+    redis_conn.subscribe(CACHE_INVALIDATION_CHANNEL).await?;
+
+    let messages = redis_conn.on_message();
+    tokio::spawn(async move {
+        futures::pin_mut!(messages);
+        while let Some((channel, key)) = messages.next().await {
+            if let (CACHE_INVALIDATION_CHANNEL, Some(key)) = (channel.as_str(), key) {
+                cache.invalidate(&key).await;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Get the value of the key from the in-memory cache, from the Redis cache, or from `fun` when it
+/// is cached in neither. Keys not found by `fun` are cached as such for a short time, and
+/// concurrent calls for the same key call `fun` only once.
 pub async fn get_or_populate_cache<T, F, Fut>(
     store: &Store,
     key: &str,
     fun: F,
 ) -> CustomResult<T, errors::StorageError>
 where
-    T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
+    T: serde::Serialize
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    get_or_populate(&store.cache, &store.redis_conn, key, fun).await
+}
+
+async fn get_or_populate<T, F, Fut>(
+    cache: &Cache,
+    redis: &redis_interface::RedisConnectionPool,
+    key: &str,
+    fun: F,
+) -> CustomResult<T, errors::StorageError>
+where
+    T: serde::Serialize
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    if let Some(cached) = cache.get::<T>(key) {
+        return cached.into_result(key);
+    }
+
+    match cache.start_load(key) {
+        Load::Leader(guard) => {
+            let loaded = populate_cache(cache, redis, key, fun).await;
+            guard.finish(loaded.as_ref().ok().map(|(_, entry)| entry.clone()));
+            let (cached, _) = loaded?;
+            cached.into_result(key)
+        }
+        Load::Follower(pending) => {
+            // The awaited load failed or was cancelled when it shares no entry
+            match pending
+                .await
+                .ok()
+                .flatten()
+                .and_then(|entry| entry.to_cached())
+            {
+                Some(cached) => cached.into_result(key),
+                None => {
+                    let (cached, _) = populate_cache(cache, redis, key, fun).await?;
+                    cached.into_result(key)
+                }
+            }
+        }
+    }
+}
+
+async fn populate_cache<T, F, Fut>(
+    cache: &Cache,
+    redis: &redis_interface::RedisConnectionPool,
+    key: &str,
+    fun: F,
+) -> CustomResult<(Cached<T>, CacheEntry), errors::StorageError>
+where
+    T: serde::Serialize
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug
+        + Clone
+        + Send
+        + Sync
+        + 'static,
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
     let type_name = std::any::type_name::<T>();
-    let redis = &store.redis_conn;
-    let redis_val = redis.get_and_deserialize_key::<T>(key, type_name).await;
-    match redis_val {
-        Err(err) => match err.current_context() {
-            errors::RedisError::NotFound => {
-                let data = fun().await?;
+    let redis_val = redis
+        .get_key::<Vec<u8>>(key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable_lazy(|| format!("Error while fetching cache for {type_name}"))?;
+
+    let cached = if redis_val.is_empty() {
+        match fun().await {
+            Ok(data) => {
                 redis
-                    .serialize_and_set_key(key, &data)
+                    .serialize_and_set_key_with_expiry(key, &data, cache.redis_ttl)
                     .await
                     .change_context(errors::StorageError::KVError)?;
-                Ok(data)
+                Cached::Found(data)
             }
-            _ => Err(err
-                .change_context(errors::StorageError::KVError)
-                .attach_printable(format!("Error while fetching cache for {type_name}"))),
-        },
-        Ok(val) => Ok(val),
-    }
+            Err(error) if error.current_context().is_db_not_found() => {
+                redis
+                    .set_key_with_expiry(key, NOT_FOUND_MARKER, cache.redis_not_found_ttl)
+                    .await
+                    .change_context(errors::StorageError::KVError)?;
+                Cached::NotFound
+            }
+            Err(error) => return Err(error),
+        }
+    } else if redis_val == NOT_FOUND_MARKER {
+        Cached::NotFound
+    } else {
+        Cached::Found(
+            redis_val
+                .parse_struct(type_name)
+                .change_context(errors::StorageError::DeserializationFailed)?,
+        )
+    };
+
+    let entry = cache.insert(key, &cached).await;
+    Ok((cached, entry))
 }
 
-pub async fn redact_cache<T, F, Fut>(
+/// Run `fun` and redact the keys from the Redis cache and from the in-memory cache of every
+/// application instance. The keys are redacted both before and after running `fun`, so that values
+/// loaded from the database before `fun` updated it are dropped as well.
+pub async fn redact_cache<T, F, Fut, K>(
     store: &Store,
-    key: &str,
+    keys: &[K],
     fun: F,
 ) -> CustomResult<T, errors::StorageError>
 where
     T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+    K: AsRef<str> + Sync,
 {
+    redact_keys(store, keys).await?;
     let data = fun().await?;
-    store
-        .redis_conn
-        .delete_key(key)
-        .await
-        .change_context(errors::StorageError::KVError)?;
+    redact_keys(store, keys).await?;
     Ok(data)
 }
+
+async fn redact_keys<K>(store: &Store, keys: &[K]) -> CustomResult<(), errors::StorageError>
+where
+    K: AsRef<str> + Sync,
+{
+    for key in keys {
+        let key = key.as_ref();
+        store
+            .redis_conn
+            .delete_key(key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+        store.cache.invalidate(key).await;
+        store
+            .redis_conn
+            .publish(CACHE_INVALIDATION_CHANNEL, key)
+            .await
+            .change_context(errors::StorageError::KVError)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    #[actix_rt::test]
+    async fn test_redacted_key_is_evicted_from_other_instances() {
+        let conf = Settings::new().expect("invalid settings");
+        let key = format!("test_cache_invalidation_{}", uuid::Uuid::new_v4());
+
+        // Two application instances, each with its own in-memory cache
+        let redis_conn_a = crate::connection::redis_connection(&conf).await;
+        let cache_a = Arc::new(Cache::new(&conf.cache));
+        subscribe_to_invalidations(&redis_conn_a, cache_a.clone())
+            .await
+            .unwrap();
+        let redis_conn_b = crate::connection::redis_connection(&conf).await;
+        let cache_b = Arc::new(Cache::new(&conf.cache));
+        subscribe_to_invalidations(&redis_conn_b, cache_b.clone())
+            .await
+            .unwrap();
+
+        cache_b
+            .insert(&key, &Cached::Found("cached".to_string()))
+            .await;
+        assert!(cache_b.get::<String>(&key).is_some());
+
+        redis_conn_a
+            .publish(CACHE_INVALIDATION_CHANNEL, key.as_str())
+            .await
+            .unwrap();
+
+        let mut evicted = false;
+        for _ in 0..50 {
+            if cache_b.get::<String>(&key).is_none() {
+                evicted = true;
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(evicted, "the key was not evicted from the other instance");
+    }
+
+    #[actix_rt::test]
+    async fn test_concurrent_loads_of_a_key_are_coalesced() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let cache = Cache::new(&conf.cache);
+        let key = format!("test_cache_coalescing_{}", uuid::Uuid::new_v4());
+        let calls = AtomicUsize::new(0);
+
+        let loads = (0..10).map(|_| {
+            get_or_populate(&cache, &redis_conn, &key, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok("loaded".to_string())
+            })
+        });
+        let values = futures::future::join_all(loads).await;
+
+        assert!(values.into_iter().all(|value| value.unwrap() == "loaded"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(cache.loads.lock().unwrap().is_empty());
+        redis_conn.delete_key(&key).await.unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_not_found_entry_expires() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let mut settings = conf.cache.clone();
+        settings.not_found_ttl = 1;
+        let cache = Cache::new(&settings);
+        let key = format!("test_cache_not_found_{}", uuid::Uuid::new_v4());
+        let calls = AtomicUsize::new(0);
+        let load = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<String, _>(errors::StorageError::DatabaseError(report!(
+                DatabaseError::NotFound
+            )))
+            .into_report()
+        };
+
+        let first = get_or_populate(&cache, &redis_conn, &key, load).await;
+        assert!(first.unwrap_err().current_context().is_db_not_found());
+        let cached = get_or_populate(&cache, &redis_conn, &key, load).await;
+        assert!(cached.unwrap_err().current_context().is_db_not_found());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(cache.get::<String>(&key).is_none());
+        let reloaded = get_or_populate(&cache, &redis_conn, &key, load).await;
+        assert!(reloaded.unwrap_err().current_context().is_db_not_found());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        redis_conn.delete_key(&key).await.unwrap();
+    }
+}
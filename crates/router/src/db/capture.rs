@@ -0,0 +1,141 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Capture::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        new: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let capture = storage::Capture {
+            id: i32::try_from(captures.len()).unwrap_or(i32::MAX),
+            capture_id: new.capture_id,
+            payment_id: new.payment_id,
+            merchant_id: new.merchant_id,
+            attempt_id: new.attempt_id,
+            status: new.status,
+            amount: new.amount,
+            currency: new.currency,
+            connector: new.connector,
+            connector_capture_id: None,
+            error_message: None,
+            error_code: None,
+            final_capture: new.final_capture,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        captures.push(capture.clone());
+        Ok(capture)
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+
+        let item = captures
+            .iter_mut()
+            .find(|item| item.merchant_id == this.merchant_id && item.capture_id == this.capture_id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        *item = capture.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn find_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let captures = self.captures.lock().await;
+
+        Ok(captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.attempt_id == attempt_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -45,8 +45,13 @@ impl ConfigInterface for Store {
         &self,
         config: storage::ConfigNew,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        config.insert(&conn).await.map_err(Into::into).into_report()
+        // The key may have been cached as not found
+        let key = config.key.clone();
+        cache::redact_cache(self, &[key], || async {
+            let conn = pg_connection(&self.master_pool).await;
+            config.insert(&conn).await.map_err(Into::into).into_report()
+        })
+        .await
     }
 
     async fn find_config_by_key(
@@ -76,7 +81,7 @@ impl ConfigInterface for Store {
         key: &str,
         config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        cache::redact_cache(self, key, || async {
+        cache::redact_cache(self, &[key], || async {
             self.update_config_by_key(key, config_update).await
         })
         .await
@@ -91,11 +96,14 @@ impl ConfigInterface for Store {
     }
 
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Config::delete_by_key(&conn, key)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        cache::redact_cache(self, &[key], || async {
+            let conn = pg_connection(&self.master_pool).await;
+            storage::Config::delete_by_key(&conn, key)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        })
+        .await
     }
 }
 
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+
+use super::{MockDb, Store};
+use crate::{
+    core::errors::{self, CustomResult},
+    services::logger,
+};
+
+/// The number of payments processed by a connector within a window, and how many of them were
+/// successful
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConnectorPaymentCounts {
+    pub total: u64,
+    pub success: u64,
+}
+
+/// Payment counts are stored in a Redis hash per connector, with a pair of counters for each
+/// time bucket of the sliding window
+#[async_trait::async_trait]
+pub trait ConnectorSuccessRateInterface {
+    async fn increment_connector_payment_counts(
+        &self,
+        key: &str,
+        bucket: u64,
+        is_success: bool,
+        ttl_in_secs: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Sum the payment counts of the buckets starting from `oldest_bucket`. Counters of older
+    /// buckets are removed.
+    async fn get_connector_payment_counts(
+        &self,
+        key: &str,
+        oldest_bucket: u64,
+    ) -> CustomResult<ConnectorPaymentCounts, errors::StorageError>;
+}
+
+const TOTAL_COUNT_PREFIX: &str = "total_";
+const SUCCESS_COUNT_PREFIX: &str = "success_";
+
+/// Increments the total counter of the bucket, and its success counter if given, and extends the
+/// expiry of the hash in the same script, so that the hash cannot be left without an expiry
+const INCREMENT_PAYMENT_COUNTS_SCRIPT: &str = r#"
+redis.call("HINCRBY", KEYS[1], ARGV[1], 1)
+if ARGV[2] ~= "" then
+    redis.call("HINCRBY", KEYS[1], ARGV[2], 1)
+end
+redis.call("EXPIRE", KEYS[1], ARGV[3])
+return 1
+"#;
+
+#[async_trait::async_trait]
+impl ConnectorSuccessRateInterface for Store {
+    async fn increment_connector_payment_counts(
+        &self,
+        key: &str,
+        bucket: u64,
+        is_success: bool,
+        ttl_in_secs: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let success_field = if is_success {
+            format!("{SUCCESS_COUNT_PREFIX}{bucket}")
+        } else {
+            String::new()
+        };
+
+        self.redis_conn
+            .evaluate_script::<i64, _>(
+                INCREMENT_PAYMENT_COUNTS_SCRIPT,
+                key.to_string(),
+                vec![
+                    format!("{TOTAL_COUNT_PREFIX}{bucket}"),
+                    success_field,
+                    ttl_in_secs.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to increment connector payment counts")?;
+
+        Ok(())
+    }
+
+    async fn get_connector_payment_counts(
+        &self,
+        key: &str,
+        oldest_bucket: u64,
+    ) -> CustomResult<ConnectorPaymentCounts, errors::StorageError> {
+        let counters = self
+            .redis_conn
+            .get_hash_fields::<HashMap<String, u64>>(key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get connector payment counts")?;
+
+        let mut payment_counts = ConnectorPaymentCounts::default();
+        let mut expired_fields = Vec::new();
+        for (field, count) in counters {
+            let (total_count, bucket) = match (
+                field.strip_prefix(TOTAL_COUNT_PREFIX),
+                field.strip_prefix(SUCCESS_COUNT_PREFIX),
+            ) {
+                (Some(bucket), _) => (true, bucket),
+                (None, Some(bucket)) => (false, bucket),
+                (None, None) => continue,
+            };
+            match bucket.parse::<u64>() {
+                Ok(bucket) if bucket >= oldest_bucket => {
+                    if total_count {
+                        payment_counts.total += count;
+                    } else {
+                        payment_counts.success += count;
+                    }
+                }
+                _ => expired_fields.push(field),
+            }
+        }
+
+        if !expired_fields.is_empty() {
+            // Failing to remove expired counters does not affect the computed counts
+            if let Err(error) = self
+                .redis_conn
+                .delete_hash_fields(key, expired_fields)
+                .await
+            {
+                logger::error!(?error, "Failed to remove expired connector payment counts");
+            }
+        }
+
+        Ok(payment_counts)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorSuccessRateInterface for MockDb {
+    async fn increment_connector_payment_counts(
+        &self,
+        _key: &str,
+        _bucket: u64,
+        _is_success: bool,
+        _ttl_in_secs: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn get_connector_payment_counts(
+        &self,
+        _key: &str,
+        _oldest_bucket: u64,
+    ) -> CustomResult<ConnectorPaymentCounts, errors::StorageError> {
+        Ok(ConnectorPaymentCounts::default())
+    }
+}
@@ -1,13 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
-    core::{
-        customers::REDACTED,
-        errors::{self, CustomResult},
-    },
-    types::storage,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -16,12 +10,14 @@ pub trait CustomerInterface {
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError>;
 
     async fn find_customer_optional_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Customer>, errors::StorageError>;
 
     async fn update_customer_by_customer_id_merchant_id(
@@ -29,103 +25,457 @@ pub trait CustomerInterface {
         customer_id: String,
         merchant_id: String,
         customer: storage::CustomerUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl CustomerInterface for Store {
-    async fn find_customer_optional_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        let maybe_customer = storage::Customer::find_optional_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()?;
-        maybe_customer.map_or(Ok(None), |customer| {
-            // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
-            // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::date_time;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::CustomerInterface;
+    use crate::{
+        connection::pg_connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Customer>, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            let maybe_customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            self.redis_conn
+                                .get_hash_field_and_deserialize(&key, "cust", "Customer")
+                                .await
+                                .map(Some)
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
+
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    storage_types::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        customer,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let database_call = || async {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage_types::Customer::find_by_customer_id_merchant_id(
+                            &conn,
+                            &customer_id,
+                            &merchant_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                    };
+                    let this: storage_types::Customer =
+                        db_utils::try_redis_get_else_try_database_get(
+                            self.redis_conn
+                                .get_hash_field_and_deserialize(&key, "cust", "Customer"),
+                            database_call,
+                        )
+                        .await?;
+
+                    let updated_customer = customer.clone().apply_changeset(this.clone());
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Customer>::encode_to_string_of_json(
+                            &updated_customer,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.redis_conn
+                        .set_hash_fields(&key, ("cust", &redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::DBOperation::Update {
+                        updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
+                            orig: this,
+                            update_data: customer,
+                        }),
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::MerchantIdCustomerId {
+                            merchant_id: &updated_customer.merchant_id,
+                            customer_id: &updated_customer.customer_id,
+                        },
+                    )
+                    .await?;
+
+                    Ok(updated_customer)
+                }
+            }
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage_types::Customer::find_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            let customer: storage_types::Customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn
+                            .get_hash_field_and_deserialize(&key, "cust", "Customer"),
+                        database_call,
+                    )
+                    .await
+                }
+            }?;
             match customer.name {
                 Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-                _ => Ok(Some(customer)),
+                _ => Ok(customer),
             }
-        })
-    }
+        }
 
-    async fn update_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: String,
-        merchant_id: String,
-        customer: storage::CustomerUpdate,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Customer::update_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            customer,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-    }
+        async fn insert_customer(
+            &self,
+            customer_data: storage_types::CustomerNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    customer_data
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "{}_{}",
+                        customer_data.merchant_id, customer_data.customer_id
+                    );
+                    db_utils::check_database_absence(
+                        "customer",
+                        &customer_data.customer_id,
+                        || async {
+                            let conn = pg_connection(&self.master_pool).await;
+                            storage_types::Customer::find_by_customer_id_merchant_id(
+                                &conn,
+                                &customer_data.customer_id,
+                                &customer_data.merchant_id,
+                            )
+                            .await
+                            .map_err(Into::into)
+                            .into_report()
+                        },
+                    )
+                    .await?;
 
-    async fn find_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        let customer =
-            storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
-                .await
-                .map_err(Into::into)
-                .into_report()?;
-        match customer.name {
-            Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-            _ => Ok(customer),
+                    let created_customer = storage_types::Customer {
+                        id: 0i32,
+                        customer_id: customer_data.customer_id.clone(),
+                        merchant_id: customer_data.merchant_id.clone(),
+                        name: customer_data.name.clone(),
+                        email: customer_data.email.clone(),
+                        phone: customer_data.phone.clone(),
+                        phone_country_code: customer_data.phone_country_code.clone(),
+                        description: customer_data.description.clone(),
+                        created_at: date_time::now(),
+                        metadata: customer_data.metadata.clone(),
+                    };
+
+                    match self
+                        .redis_conn
+                        .serialize_and_set_hash_field_if_not_exist(&key, "cust", &created_customer)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "customer",
+                            key: Some(created_customer.customer_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::Customer(customer_data),
+                            };
+                            self.push_to_drainer_stream::<storage_types::Customer>(
+                                redis_entry,
+                                PartitionKey::MerchantIdCustomerId {
+                                    merchant_id: &created_customer.merchant_id,
+                                    customer_id: &created_customer.customer_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_customer)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    storage_types::Customer::delete_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let database_call = || async {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage_types::Customer::find_by_customer_id_merchant_id(
+                            &conn,
+                            customer_id,
+                            merchant_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                    };
+                    let _: storage_types::Customer = db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn
+                            .get_hash_field_and_deserialize(&key, "cust", "Customer"),
+                        database_call,
+                    )
+                    .await?;
+
+                    // The deletion is drained after the writes pushed before it, so that a
+                    // pending insert or update does not recreate the customer
+                    let redis_entry = kv::DBOperation::Delete {
+                        deletable: kv::Deletable::Customer(kv::CustomerDeleteMems {
+                            customer_id: customer_id.to_owned(),
+                            merchant_id: merchant_id.to_owned(),
+                        }),
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::MerchantIdCustomerId {
+                            merchant_id,
+                            customer_id,
+                        },
+                    )
+                    .await?;
+
+                    self.redis_conn
+                        .delete_hash_fields(&key, "cust")
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    Ok(true)
+                }
+            }
         }
     }
+}
 
-    async fn insert_customer(
-        &self,
-        customer_data: storage::CustomerNew,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        customer_data
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::CustomerInterface;
+    use crate::{
+        connection::pg_connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Customer>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let maybe_customer = storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
+
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Customer::update_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                customer,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn delete_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let customer = storage_types::Customer::find_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+            match customer.name {
+                Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
+                _ => Ok(customer),
+            }
+        }
+
+        async fn insert_customer(
+            &self,
+            customer_data: storage_types::CustomerNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            customer_data
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Customer::delete_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
     }
 }
 
@@ -136,6 +486,7 @@ impl CustomerInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
         let customers = self.customers.lock().await;
 
@@ -152,6 +503,7 @@ impl CustomerInterface for MockDb {
         _customer_id: String,
         _merchant_id: String,
         _customer: storage::CustomerUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -161,6 +513,7 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: &str,
         _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -170,6 +523,7 @@ impl CustomerInterface for MockDb {
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         let mut customers = self.customers.lock().await;
         let customer = storage::Customer {
@@ -193,6 +547,7 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: &str,
         _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -1,4 +1,5 @@
 use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -13,6 +14,17 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,15 +36,86 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
 impl EventInterface for MockDb {
     async fn insert_event(
         &self,
-        _event: storage::EventNew,
+        event: storage::EventNew,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let mut events = self.events.lock().await;
+
+        let event = storage::Event {
+            #[allow(clippy::as_conversions)]
+            id: events.len() as i32,
+            event_id: event.event_id,
+            event_type: event.event_type,
+            event_class: event.event_class,
+            is_webhook_notified: event.is_webhook_notified,
+            intent_reference_id: event.intent_reference_id,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type,
+            created_at: common_utils::date_time::now(),
+            delivery_status: Default::default(),
+            delivery_attempts: 0,
+            last_delivery_attempted_at: None,
+        };
+        events.push(event.clone());
+        Ok(event)
+    }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
     ) -> CustomResult<storage::Event, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let events = self.events.lock().await;
+
+        events
+            .iter()
+            .find(|event| event.event_id == event_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let mut events = self.events.lock().await;
+
+        let item = events
+            .iter_mut()
+            .find(|item| item.event_id == this.event_id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        *item = event.apply_changeset(item.clone());
+        Ok(item.clone())
     }
 }
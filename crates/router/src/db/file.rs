@@ -0,0 +1,150 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait FileMetadataInterface {
+    async fn insert_file_metadata(
+        &self,
+        file: storage::FileMetadataNew,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError>;
+
+    async fn find_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError>;
+
+    async fn delete_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn update_file_metadata(
+        &self,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FileMetadataInterface for Store {
+    async fn insert_file_metadata(
+        &self,
+        file: storage::FileMetadataNew,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        file.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::FileMetadata::find_by_merchant_id_file_id(&conn, merchant_id, file_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::FileMetadata::delete_by_merchant_id_file_id(&conn, merchant_id, file_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_file_metadata(
+        &self,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, file_metadata)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl FileMetadataInterface for MockDb {
+    async fn insert_file_metadata(
+        &self,
+        new: storage::FileMetadataNew,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let mut files = self.files.lock().await;
+
+        let file = storage::FileMetadata {
+            file_id: new.file_id,
+            merchant_id: new.merchant_id,
+            file_name: new.file_name,
+            file_size: new.file_size,
+            file_type: new.file_type,
+            provider_file_id: new.provider_file_id,
+            file_upload_provider: new.file_upload_provider,
+            available: new.available,
+            created_at: common_utils::date_time::now(),
+        };
+        files.push(file.clone());
+        Ok(file)
+    }
+
+    async fn find_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let files = self.files.lock().await;
+
+        files
+            .iter()
+            .find(|file| file.merchant_id == merchant_id && file.file_id == file_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn delete_file_metadata_by_merchant_id_file_id(
+        &self,
+        merchant_id: &str,
+        file_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut files = self.files.lock().await;
+        let initial_length = files.len();
+
+        files.retain(|file| !(file.merchant_id == merchant_id && file.file_id == file_id));
+        Ok(files.len() < initial_length)
+    }
+
+    async fn update_file_metadata(
+        &self,
+        this: storage::FileMetadata,
+        file_metadata: storage::FileMetadataUpdate,
+    ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
+        let mut files = self.files.lock().await;
+
+        let item = files
+            .iter_mut()
+            .find(|item| item.merchant_id == this.merchant_id && item.file_id == this.file_id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        *item = file_metadata.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,186 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncrementalAuthorizationInterface {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError>;
+
+    async fn find_incremental_authorization_by_merchant_id_connector_authorization_id(
+        &self,
+        merchant_id: &str,
+        connector_authorization_id: &str,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for Store {
+    async fn insert_incremental_authorization(
+        &self,
+        authorization: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        authorization
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, authorization)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IncrementalAuthorization::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_incremental_authorization_by_merchant_id_connector_authorization_id(
+        &self,
+        merchant_id: &str,
+        connector_authorization_id: &str,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IncrementalAuthorization::find_by_merchant_id_connector_authorization_id(
+            &conn,
+            merchant_id,
+            connector_authorization_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IncrementalAuthorizationInterface for MockDb {
+    async fn insert_incremental_authorization(
+        &self,
+        new: storage::IncrementalAuthorizationNew,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut authorizations = self.incremental_authorizations.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let authorization = storage::IncrementalAuthorization {
+            id: i32::try_from(authorizations.len()).unwrap_or(i32::MAX),
+            authorization_id: new.authorization_id,
+            payment_id: new.payment_id,
+            merchant_id: new.merchant_id,
+            attempt_id: new.attempt_id,
+            status: new.status,
+            amount: new.amount,
+            previously_authorized_amount: new.previously_authorized_amount,
+            currency: new.currency,
+            connector: new.connector,
+            connector_authorization_id: None,
+            reason: new.reason,
+            error_message: None,
+            error_code: None,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        authorizations.push(authorization.clone());
+        Ok(authorization)
+    }
+
+    async fn update_incremental_authorization(
+        &self,
+        this: storage::IncrementalAuthorization,
+        authorization: storage::IncrementalAuthorizationUpdate,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let mut authorizations = self.incremental_authorizations.lock().await;
+
+        let item = authorizations
+            .iter_mut()
+            .find(|item| {
+                item.merchant_id == this.merchant_id
+                    && item.authorization_id == this.authorization_id
+            })
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        *item = authorization.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn find_incremental_authorizations_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::IncrementalAuthorization>, errors::StorageError> {
+        let authorizations = self.incremental_authorizations.lock().await;
+
+        Ok(authorizations
+            .iter()
+            .filter(|authorization| {
+                authorization.merchant_id == merchant_id
+                    && authorization.payment_id == payment_id
+                    && authorization.attempt_id == attempt_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_incremental_authorization_by_merchant_id_connector_authorization_id(
+        &self,
+        merchant_id: &str,
+        connector_authorization_id: &str,
+    ) -> CustomResult<storage::IncrementalAuthorization, errors::StorageError> {
+        let authorizations = self.incremental_authorizations.lock().await;
+
+        authorizations
+            .iter()
+            .find(|authorization| {
+                authorization.merchant_id == merchant_id
+                    && authorization.connector_authorization_id.as_deref()
+                        == Some(connector_authorization_id)
+            })
+            .cloned()
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))
+            .into_report()
+    }
+}
@@ -1,10 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,6 +10,7 @@ pub trait MandateInterface {
         &self,
         merchant_id: &str,
         mandate_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
     async fn find_mandate_by_merchant_id_customer_id(
@@ -26,63 +24,355 @@ pub trait MandateInterface {
         merchant_id: &str,
         mandate_id: &str,
         mandate: storage::MandateUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl MandateInterface for Store {
-    async fn find_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::date_time;
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::{errors::RedisError, HsetnxReply};
 
-    async fn find_mandate_by_merchant_id_customer_id(
-        &self,
-        merchant_id: &str,
-        customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+    use super::MandateInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
 
-    async fn update_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Mandate::update_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id, mandate)
+    /// Prefix of the fields of the customer hash which index the mandates of the customer
+    const MANDATE_INDEX_FIELD_PREFIX: &str = "mandate_";
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage_types::Mandate::find_by_merchant_id_mandate_id(
+                    &conn,
+                    merchant_id,
+                    mandate_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{mandate_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn
+                            .get_hash_field_and_deserialize(&key, "mandate", "Mandate"),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        // Mandates inserted into the KV store are indexed in the hash of their customer, so that
+        // they are listed before they have been drained to the database. The KV store holds the
+        // latest state of the mandates it has.
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let mut mandates = storage_types::Mandate::find_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+            )
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+
+            let mandate_ids = self
+                .redis_conn
+                .hscan(
+                    &format!("{merchant_id}_{customer_id}"),
+                    &format!("{MANDATE_INDEX_FIELD_PREFIX}*"),
+                    None,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)?;
+
+            for mandate_id in mandate_ids {
+                let mandate = match self
+                    .redis_conn
+                    .get_hash_field_and_deserialize::<storage_types::Mandate>(
+                        &format!("{merchant_id}_{mandate_id}"),
+                        "mandate",
+                        "Mandate",
+                    )
+                    .await
+                {
+                    Ok(mandate) => mandate,
+                    Err(error) if matches!(error.current_context(), RedisError::NotFound) => {
+                        continue
+                    }
+                    Err(error) => Err(error).change_context(errors::StorageError::KVError)?,
+                };
+                mandates.retain(|stored| stored.mandate_id != mandate.mandate_id);
+                mandates.push(mandate);
+            }
+
+            Ok(mandates)
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    storage_types::Mandate::update_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        mandate,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{mandate_id}");
+                    let this = self
+                        .find_mandate_by_merchant_id_mandate_id(
+                            merchant_id,
+                            mandate_id,
+                            storage_scheme,
+                        )
+                        .await?;
+
+                    let updated_mandate = mandate.clone().apply_changeset(this.clone());
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Mandate>::encode_to_string_of_json(
+                            &updated_mandate,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.redis_conn
+                        .set_hash_fields(&key, ("mandate", &redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::DBOperation::Update {
+                        updatable: kv::Updateable::MandateUpdate(kv::MandateUpdateMems {
+                            orig: this,
+                            update_data: mandate,
+                        }),
+                    };
+                    self.push_to_drainer_stream::<storage_types::Mandate>(
+                        redis_entry,
+                        PartitionKey::MerchantIdMandateId {
+                            merchant_id,
+                            mandate_id,
+                        },
+                    )
+                    .await?;
+
+                    Ok(updated_mandate)
+                }
+            }
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage_types::MandateNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    mandate
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", mandate.merchant_id, mandate.mandate_id);
+                    db_utils::check_database_absence("mandate", &mandate.mandate_id, || async {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage_types::Mandate::find_by_merchant_id_mandate_id(
+                            &conn,
+                            &mandate.merchant_id,
+                            &mandate.mandate_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                    })
+                    .await?;
+
+                    let created_mandate = storage_types::Mandate {
+                        id: 0i32,
+                        mandate_id: mandate.mandate_id.clone(),
+                        customer_id: mandate.customer_id.clone(),
+                        merchant_id: mandate.merchant_id.clone(),
+                        payment_method_id: mandate.payment_method_id.clone(),
+                        mandate_status: mandate.mandate_status,
+                        mandate_type: mandate.mandate_type,
+                        customer_accepted_at: mandate.customer_accepted_at,
+                        customer_ip_address: mandate.customer_ip_address.clone(),
+                        customer_user_agent: mandate.customer_user_agent.clone(),
+                        network_transaction_id: mandate.network_transaction_id.clone(),
+                        previous_attempt_id: mandate.previous_attempt_id.clone(),
+                        created_at: mandate.created_at.unwrap_or_else(date_time::now),
+                        mandate_amount: mandate.mandate_amount,
+                        mandate_currency: mandate.mandate_currency,
+                        amount_captured: mandate.amount_captured,
+                        connector: mandate.connector.clone(),
+                        connector_mandate_id: mandate.connector_mandate_id.clone(),
+                    };
+
+                    match self
+                        .redis_conn
+                        .serialize_and_set_hash_field_if_not_exist(
+                            &key,
+                            "mandate",
+                            &created_mandate,
+                        )
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "mandate",
+                            key: Some(created_mandate.mandate_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            self.redis_conn
+                                .set_hash_fields(
+                                    &format!(
+                                        "{}_{}",
+                                        created_mandate.merchant_id, created_mandate.customer_id
+                                    ),
+                                    (
+                                        format!(
+                                            "{MANDATE_INDEX_FIELD_PREFIX}{}",
+                                            created_mandate.mandate_id
+                                        ),
+                                        created_mandate.mandate_id.as_str(),
+                                    ),
+                                )
+                                .await
+                                .change_context(errors::StorageError::KVError)?;
+
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::Mandate(mandate),
+                            };
+                            self.push_to_drainer_stream::<storage_types::Mandate>(
+                                redis_entry,
+                                PartitionKey::MerchantIdMandateId {
+                                    merchant_id: &created_mandate.merchant_id,
+                                    mandate_id: &created_mandate.mandate_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_mandate)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
     }
+}
 
-    async fn insert_mandate(
-        &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        mandate
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::MandateInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+        ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage_types::MandateUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Mandate::update_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                mandate,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage_types::MandateNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            mandate
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -92,6 +382,7 @@ impl MandateInterface for MockDb {
         &self,
         _merchant_id: &str,
         _mandate_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -111,6 +402,7 @@ impl MandateInterface for MockDb {
         _merchant_id: &str,
         _mandate_id: &str,
         _mandate: storage::MandateUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -119,6 +411,7 @@ impl MandateInterface for MockDb {
     async fn insert_mandate(
         &self,
         _mandate: storage::MandateNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
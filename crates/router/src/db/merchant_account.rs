@@ -54,12 +54,27 @@ impl MerchantAccountInterface for Store {
         &self,
         merchant_account: storage::MerchantAccountNew,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        merchant_account
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        #[cfg(feature = "accounts_cache")]
+        let cache_keys = [merchant_account.merchant_id.clone()];
+        let insert_func = || async {
+            let conn = pg_connection(&self.master_pool).await;
+            merchant_account
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            insert_func().await
+        }
+
+        // The merchant ID may have been cached as not found
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::redact_cache(self, &cache_keys, insert_func).await
+        }
     }
 
     async fn find_merchant_account_by_merchant_id(
@@ -90,7 +105,8 @@ impl MerchantAccountInterface for Store {
         this: storage::MerchantAccount,
         merchant_account: storage::MerchantAccountUpdate,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let _merchant_id = this.merchant_id.clone();
+        #[cfg(feature = "accounts_cache")]
+        let cache_keys = cache_keys(&this);
         let update_func = || async {
             let conn = pg_connection(&self.master_pool).await;
             this.update(&conn, merchant_account)
@@ -106,7 +122,7 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_id, update_func).await
+            super::cache::redact_cache(self, &cache_keys, update_func).await
         }
     }
 
@@ -134,7 +150,8 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, update_func).await
+            let cache_keys = find_cache_keys(self, merchant_id).await?;
+            super::cache::redact_cache(self, &cache_keys, update_func).await
         }
     }
 
@@ -142,11 +159,23 @@ impl MerchantAccountInterface for Store {
         &self,
         api_key: &str,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantAccount::find_by_api_key(&conn, api_key)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let fetch_func = || async {
+            let conn = pg_connection(&self.master_pool).await;
+            storage::MerchantAccount::find_by_api_key(&conn, api_key)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            fetch_func().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::get_or_populate_cache(self, &api_key_cache_key(api_key), fetch_func).await
+        }
     }
 
     async fn find_merchant_account_by_publishable_key(
@@ -179,11 +208,45 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, delete_func).await
+            let cache_keys = find_cache_keys(self, merchant_id).await?;
+            super::cache::redact_cache(self, &cache_keys, delete_func).await
         }
     }
 }
 
+/// The key under which the merchant account of an API key is cached. The API key is hashed so that
+/// it is not stored in Redis in plain text.
+#[cfg(feature = "accounts_cache")]
+fn api_key_cache_key(api_key: &str) -> String {
+    format!("api_key_{}", blake3::hash(api_key.as_bytes()).to_hex())
+}
+
+/// The keys under which the merchant account is cached
+#[cfg(feature = "accounts_cache")]
+fn cache_keys(merchant_account: &storage::MerchantAccount) -> Vec<String> {
+    let mut keys = vec![merchant_account.merchant_id.clone()];
+    keys.extend(
+        merchant_account
+            .api_key
+            .as_ref()
+            .map(|api_key| api_key_cache_key(api_key.peek())),
+    );
+    keys
+}
+
+#[cfg(feature = "accounts_cache")]
+async fn find_cache_keys(
+    store: &Store,
+    merchant_id: &str,
+) -> CustomResult<Vec<String>, errors::StorageError> {
+    let conn = pg_connection(&store.master_pool).await;
+    let merchant_account = storage::MerchantAccount::find_by_merchant_id(&conn, merchant_id)
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+    Ok(cache_keys(&merchant_account))
+}
+
 #[async_trait::async_trait]
 impl MerchantAccountInterface for MockDb {
     #[allow(clippy::panic)]
@@ -211,7 +211,7 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await
+            super::cache::redact_cache(self, &[_merchant_connector_id], update_call).await
         }
 
         #[cfg(not(feature = "accounts_cache"))]
@@ -1,3 +1,5 @@
+use storage_models::errors::DatabaseError;
+
 use super::MockDb;
 use crate::{
     core::errors::{self, CustomResult},
@@ -182,12 +184,22 @@ mod storage {
 impl PaymentAttemptInterface for MockDb {
     async fn find_payment_attempt_by_merchant_id_attempt_id(
         &self,
-        _merchant_id: &str,
-        _attempt_id: &str,
+        merchant_id: &str,
+        attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        let payment_attempt = payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.attempt_id == attempt_id
+            })
+            .cloned()
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        Ok(payment_attempt)
     }
 
     async fn find_payment_attempt_by_merchant_id_connector_txn_id(
@@ -244,6 +256,8 @@ impl PaymentAttemptInterface for MockDb {
             payment_token: None,
             error_code: payment_attempt.error_code,
             connector_metadata: None,
+            authentication_details: payment_attempt.authentication_details,
+            next_action_data: payment_attempt.next_action_data,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -383,6 +397,8 @@ mod storage {
                         payment_token: payment_attempt.payment_token.clone(),
                         error_code: payment_attempt.error_code.clone(),
                         connector_metadata: payment_attempt.connector_metadata.clone(),
+                        authentication_details: payment_attempt.authentication_details.clone(),
+                        next_action_data: payment_attempt.next_action_data.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -397,29 +413,23 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let conn = pg_connection(&self.master_pool).await;
-
                             //Reverse lookup for attempt_id
-                            ReverseLookupNew {
-                                lookup_id: format!(
-                                    "{}_{}",
-                                    &created_attempt.merchant_id,
-                                    // [#439]: Change this to `attempt_id`
-                                    &created_attempt.payment_id,
-                                ),
-                                pk_id: key,
-                                sk_id: field,
-                                source: "payment_attempt".to_string(),
-                            }
-                            .insert(&conn)
-                            .await
-                            .map_err(Into::<errors::StorageError>::into)
-                            .into_report()?;
-
-                            let redis_entry = kv::TypedSql {
-                                op: kv::DBOperation::Insert {
-                                    insertable: kv::Insertable::PaymentAttempt(payment_attempt),
+                            self.insert_reverse_lookup(
+                                ReverseLookupNew {
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        &created_attempt.merchant_id, &created_attempt.attempt_id,
+                                    ),
+                                    pk_id: key,
+                                    sk_id: field,
+                                    source: "payment_attempt".to_string(),
                                 },
+                                storage_scheme,
+                            )
+                            .await?;
+
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::PaymentAttempt(payment_attempt),
                             };
                             self.push_to_drainer_stream::<PaymentAttempt>(
                                 redis_entry,
@@ -468,37 +478,34 @@ mod storage {
                         .map(|_| updated_attempt)
                         .change_context(errors::StorageError::KVError)?;
 
-                    let conn = pg_connection(&self.master_pool).await;
                     // Reverse lookup for connector_transaction_id
                     if let (None, Some(connector_transaction_id)) = (
                         old_connector_transaction_id,
                         &updated_attempt.connector_transaction_id,
                     ) {
                         let field = format!("pa_{}", updated_attempt.attempt_id);
-                        ReverseLookupNew {
-                            lookup_id: format!(
-                                "{}_{}",
-                                &updated_attempt.merchant_id, connector_transaction_id
-                            ),
-                            pk_id: key.clone(),
-                            sk_id: field.clone(),
-                            source: "payment_attempt".to_string(),
-                        }
-                        .insert(&conn)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        self.insert_reverse_lookup(
+                            ReverseLookupNew {
+                                lookup_id: format!(
+                                    "{}_{}",
+                                    &updated_attempt.merchant_id, connector_transaction_id
+                                ),
+                                pk_id: key.clone(),
+                                sk_id: field.clone(),
+                                source: "payment_attempt".to_string(),
+                            },
+                            storage_scheme,
+                        )
+                        .await?;
                     }
 
-                    let redis_entry = kv::TypedSql {
-                        op: kv::DBOperation::Update {
-                            updatable: kv::Updateable::PaymentAttemptUpdate(
-                                kv::PaymentAttemptUpdateMems {
-                                    orig: this,
-                                    update_data: payment_attempt,
-                                },
-                            ),
-                        },
+                    let redis_entry = kv::DBOperation::Update {
+                        updatable: kv::Updateable::PaymentAttemptUpdate(
+                            kv::PaymentAttemptUpdateMems {
+                                orig: this,
+                                update_data: payment_attempt,
+                            },
+                        ),
                     };
                     self.push_to_drainer_stream::<PaymentAttempt>(
                         redis_entry,
@@ -529,23 +536,21 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    // [#439]: get the attempt_id from payment_intent
+                    // All the attempts of a payment are stored under the same key, the latest
+                    // attempt is the active attempt of the payment. The refunds stored under the
+                    // same key do not deserialize into payment attempts and are skipped.
                     let key = format!("{merchant_id}_{payment_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&key)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let redis_fut = async {
+                        self.redis_conn
+                            .hscan_and_deserialize::<PaymentAttempt>(&key, "pa_*", None)
+                            .await?
+                            .into_iter()
+                            .max_by_key(|payment_attempt| payment_attempt.created_at)
+                            .ok_or(redis_interface::errors::RedisError::NotFound)
+                            .into_report()
+                    };
 
-                    db_utils::try_redis_get_else_try_database_get(
-                        self.redis_conn.get_hash_field_and_deserialize(
-                            &lookup.pk_id,
-                            &lookup.sk_id,
-                            "PaymentAttempt",
-                        ),
-                        database_call,
-                    )
-                    .await
+                    db_utils::try_redis_get_else_try_database_get(redis_fut, database_call).await
                 }
             }
         }
@@ -575,10 +580,8 @@ mod storage {
                     // We assume that PaymentAttempt <=> PaymentIntent is a one-to-one relation for now
                     let lookup_id = format!("{merchant_id}_{connector_transaction_id}");
                     let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await?;
                     let key = &lookup.pk_id;
 
                     db_utils::try_redis_get_else_try_database_get(
@@ -607,7 +610,7 @@ mod storage {
             )
             .await
             .and_then(|attempt| match attempt.status {
-                enums::AttemptStatus::Charged => Ok(attempt),
+                enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => Ok(attempt),
                 _ => Err(errors::StorageError::ValueNotFound(format!(
                     "Successful payment attempt does not exist for {payment_id}_{merchant_id}"
                 )))
@@ -638,10 +641,8 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{connector_txn_id}");
                     let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await?;
 
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
@@ -676,10 +677,8 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{attempt_id}");
                     let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await?;
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
                         self.redis_conn.get_hash_field_and_deserialize(
@@ -92,6 +92,8 @@ mod storage {
                         setup_future_usage: new.setup_future_usage,
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
+                        attempt_count: new.attempt_count,
+                        request_incremental_authorization: new.request_incremental_authorization,
                     };
 
                     match self
@@ -105,10 +107,8 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let redis_entry = kv::TypedSql {
-                                op: kv::DBOperation::Insert {
-                                    insertable: kv::Insertable::PaymentIntent(new),
-                                },
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::PaymentIntent(new),
                             };
                             self.push_to_drainer_stream::<PaymentIntent>(
                                 redis_entry,
@@ -158,15 +158,13 @@ mod storage {
                         .map(|_| updated_intent)
                         .change_context(errors::StorageError::KVError)?;
 
-                    let redis_entry = kv::TypedSql {
-                        op: kv::DBOperation::Update {
-                            updatable: kv::Updateable::PaymentIntentUpdate(
-                                kv::PaymentIntentUpdateMems {
-                                    orig: this,
-                                    update_data: payment_intent,
-                                },
-                            ),
-                        },
+                    let redis_entry = kv::DBOperation::Update {
+                        updatable: kv::Updateable::PaymentIntentUpdate(
+                            kv::PaymentIntentUpdateMems {
+                                orig: this,
+                                update_data: payment_intent,
+                            },
+                        ),
                     };
 
                     self.push_to_drainer_stream::<PaymentIntent>(
@@ -344,6 +342,8 @@ impl PaymentIntentInterface for MockDb {
             setup_future_usage: new.setup_future_usage,
             off_session: new.off_session,
             client_secret: new.client_secret,
+            attempt_count: new.attempt_count,
+            request_incremental_authorization: new.request_incremental_authorization,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
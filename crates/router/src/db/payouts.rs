@@ -0,0 +1,169 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutInterface {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn list_payouts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Payout>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for Store {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payout.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, payout)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Payout::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_payouts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Payout>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Payout::find_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for MockDb {
+    async fn insert_payout(
+        &self,
+        new: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut payouts = self.payouts.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let payout = storage::Payout {
+            #[allow(clippy::as_conversions)]
+            id: payouts.len() as i32,
+            payout_id: new.payout_id,
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            connector: new.connector,
+            connector_payout_id: new.connector_payout_id,
+            payout_type: new.payout_type,
+            amount: new.amount,
+            currency: new.currency,
+            status: new.status,
+            description: new.description,
+            metadata: new.metadata,
+            error_code: None,
+            error_message: None,
+            created_at: new.created_at.unwrap_or(current_time),
+            modified_at: new.modified_at.unwrap_or(current_time),
+        };
+        payouts.push(payout.clone());
+        Ok(payout)
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut payouts = self.payouts.lock().await;
+
+        let item = payouts
+            .iter_mut()
+            .find(|item| item.merchant_id == this.merchant_id && item.payout_id == this.payout_id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))?;
+
+        *item = payout.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let payouts = self.payouts.lock().await;
+
+        payouts
+            .iter()
+            .find(|payout| payout.merchant_id == merchant_id && payout.payout_id == payout_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn list_payouts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Payout>, errors::StorageError> {
+        let payouts = self.payouts.lock().await;
+        let offset = offset.and_then(|offset| usize::try_from(offset).ok());
+        let limit = limit.and_then(|limit| usize::try_from(limit).ok());
+
+        Ok(payouts
+            .iter()
+            .rev()
+            .filter(|payout| payout.merchant_id == merchant_id)
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+}
@@ -1,5 +1,4 @@
-use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
-use router_env::logger;
+use redis_interface::{errors::RedisError, RedisEntryId};
 
 use super::{MockDb, Store};
 use crate::{
@@ -23,10 +22,6 @@ pub trait QueueInterface {
         id: &RedisEntryId,
     ) -> CustomResult<(), RedisError>;
 
-    async fn acquire_pt_lock(&self, tag: &str, lock_key: &str, lock_val: &str, ttl: i64) -> bool;
-
-    async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> bool;
-
     async fn stream_append_entry(
         &self,
         stream: &str,
@@ -66,42 +61,6 @@ impl QueueInterface for Store {
             .await
     }
 
-    async fn acquire_pt_lock(&self, tag: &str, lock_key: &str, lock_val: &str, ttl: i64) -> bool {
-        let conn = self.redis_conn.clone();
-        let is_lock_acquired = conn.set_key_if_not_exist(lock_key, lock_val).await;
-        match is_lock_acquired {
-            Ok(SetnxReply::KeySet) => match conn.set_expiry(lock_key, ttl).await {
-                Ok(()) => true,
-
-                #[allow(unused_must_use)]
-                Err(error) => {
-                    logger::error!(error=?error.current_context());
-                    conn.delete_key(lock_key).await;
-                    false
-                }
-            },
-            Ok(SetnxReply::KeyNotSet) => {
-                logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
-                false
-            }
-            Err(error) => {
-                logger::error!(error=%error.current_context(), %tag, "Error while locking");
-                false
-            }
-        }
-    }
-
-    async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> bool {
-        let is_lock_released = self.redis_conn.delete_key(lock_key).await;
-        match is_lock_released {
-            Ok(()) => true,
-            Err(error) => {
-                logger::error!(error=%error.current_context(), %tag, "Error while releasing lock");
-                false
-            }
-        }
-    }
-
     async fn stream_append_entry(
         &self,
         stream: &str,
@@ -142,22 +101,6 @@ impl QueueInterface for MockDb {
         Err(RedisError::ConsumerGroupCreateFailed)?
     }
 
-    async fn acquire_pt_lock(
-        &self,
-        _tag: &str,
-        _lock_key: &str,
-        _lock_val: &str,
-        _ttl: i64,
-    ) -> bool {
-        // [#172]: Implement function for `MockDb`
-        false
-    }
-
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> bool {
-        // [#172]: Implement function for `MockDb`
-        false
-    }
-
     async fn stream_append_entry(
         &self,
         _stream: &str,
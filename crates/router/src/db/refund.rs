@@ -235,10 +235,8 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{internal_reference_id}");
                     let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await?;
 
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
@@ -310,8 +308,6 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let conn = pg_connection(&self.master_pool).await;
-
                             let reverse_lookups = vec![
                                 storage_types::ReverseLookupNew {
                                     sk_id: field.clone(),
@@ -334,14 +330,13 @@ mod storage {
                                     source: "refund".to_string(),
                                 },
                             ];
-                            storage_types::ReverseLookupNew::batch_insert(reverse_lookups, &conn)
-                                .await
-                                .change_context(errors::StorageError::KVError)?;
+                            for reverse_lookup in reverse_lookups {
+                                self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                                    .await?;
+                            }
 
-                            let redis_entry = kv::TypedSql {
-                                op: kv::DBOperation::Insert {
-                                    insertable: kv::Insertable::Refund(new),
-                                },
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::Refund(new),
                             };
                             self.push_to_drainer_stream::<storage_types::Refund>(
                                 redis_entry,
@@ -380,7 +375,10 @@ mod storage {
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{connector_transaction_id}");
-                    let lookup = match self.get_lookup_by_lookup_id(&lookup_id).await {
+                    let lookup = match self
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await
+                    {
                         Ok(l) => l,
                         Err(err) => {
                             logger::error!(?err);
@@ -419,11 +417,7 @@ mod storage {
                     let updated_refund = refund.clone().apply_changeset(this.clone());
                     // Check for database presence as well Maybe use a read replica here ?
 
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&key)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let lookup = self.get_lookup_by_lookup_id(&key, storage_scheme).await?;
 
                     let field = &lookup.sk_id;
 
@@ -438,13 +432,11 @@ mod storage {
                         .await
                         .change_context(errors::StorageError::KVError)?;
 
-                    let redis_entry = kv::TypedSql {
-                        op: kv::DBOperation::Update {
-                            updatable: kv::Updateable::RefundUpdate(kv::RefundUpdateMems {
-                                orig: this,
-                                update_data: refund,
-                            }),
-                        },
+                    let redis_entry = kv::DBOperation::Update {
+                        updatable: kv::Updateable::RefundUpdate(kv::RefundUpdateMems {
+                            orig: this,
+                            update_data: refund,
+                        }),
                     };
                     self.push_to_drainer_stream::<storage_types::Refund>(
                         redis_entry,
@@ -477,10 +469,8 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{refund_id}");
                     let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                        .get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                        .await?;
 
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
@@ -527,16 +517,11 @@ mod storage {
                 }
                 enums::MerchantStorageScheme::RedisKv => {
                     let key = format!("{merchant_id}_{payment_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&key)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
-
-                    let pattern = db_utils::generate_hscan_pattern_for_refund(&lookup.sk_id);
+                    // Refunds of all the attempts of the payment
+                    let pattern = "pa_*_ref_*";
 
                     self.redis_conn
-                        .hscan_and_deserialize(&key, &pattern, None)
+                        .hscan_and_deserialize(&key, pattern, None)
                         .await
                         .change_context(errors::StorageError::KVError)
                 }
@@ -1,36 +1,186 @@
-use storage_models::{errors, StorageResult};
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
-    types::storage::reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    core::errors::{self, CustomResult},
+    types::storage::{
+        enums,
+        reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    },
 };
 
 #[async_trait::async_trait]
 pub trait ReverseLookupInterface {
-    async fn insert_reverse_lookup(&self, _new: ReverseLookupNew) -> StorageResult<ReverseLookup>;
-    async fn get_lookup_by_lookup_id(&self, _id: &str) -> StorageResult<ReverseLookup>;
+    async fn insert_reverse_lookup(
+        &self,
+        _new: ReverseLookupNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError>;
+    async fn get_lookup_by_lookup_id(
+        &self,
+        _id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ReverseLookupInterface for Store {
-    async fn insert_reverse_lookup(&self, new: ReverseLookupNew) -> StorageResult<ReverseLookup> {
-        let conn = pg_connection(&self.master_pool).await;
-        new.insert(&conn).await
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums, kv,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+        utils::{db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_rev_lookup = ReverseLookup {
+                        lookup_id: new.lookup_id.clone(),
+                        sk_id: new.sk_id.clone(),
+                        pk_id: new.pk_id.clone(),
+                        source: new.source.clone(),
+                    };
+                    let key = format!("reverse_lookup_{}", &created_rev_lookup.lookup_id);
+
+                    match self
+                        .redis_conn
+                        .serialize_and_set_hash_field_if_not_exist(
+                            &key,
+                            "reverse_lookup",
+                            &created_rev_lookup,
+                        )
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "reverse_lookup",
+                            key: Some(created_rev_lookup.lookup_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::DBOperation::Insert {
+                                insertable: kv::Insertable::ReverseLookup(new),
+                            };
+                            self.push_to_drainer_stream::<ReverseLookup>(
+                                redis_entry,
+                                PartitionKey::LookupId {
+                                    lookup_id: &created_rev_lookup.lookup_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_rev_lookup)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("reverse_lookup_{id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn.get_hash_field_and_deserialize(
+                            &key,
+                            "reverse_lookup",
+                            "ReverseLookup",
+                        ),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
     }
+}
+
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
 
-    async fn get_lookup_by_lookup_id(&self, id: &str) -> StorageResult<ReverseLookup> {
-        let conn = pg_connection(&self.master_pool).await;
-        ReverseLookup::find_by_lookup_id(id, &conn).await
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            new.insert(&conn).await.map_err(Into::into).into_report()
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            ReverseLookup::find_by_lookup_id(id, &conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl ReverseLookupInterface for MockDb {
-    async fn insert_reverse_lookup(&self, _new: ReverseLookupNew) -> StorageResult<ReverseLookup> {
-        Err(errors::DatabaseError::NotFound.into())
+    async fn insert_reverse_lookup(
+        &self,
+        _new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
     }
-    async fn get_lookup_by_lookup_id(&self, _id: &str) -> StorageResult<ReverseLookup> {
-        Err(errors::DatabaseError::NotFound.into())
+
+    async fn get_lookup_by_lookup_id(
+        &self,
+        _id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
     }
 }
@@ -50,6 +50,7 @@ pub mod headers {
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const DATE: &str = "Date";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
 }
 
 pub mod pii {
@@ -82,6 +83,8 @@ pub fn mk_app(
             .service(routes::Configs::server(state.clone()))
             .service(routes::Refunds::server(state.clone()))
             .service(routes::Payouts::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
+            .service(routes::Files::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()));
     }
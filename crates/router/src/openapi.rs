@@ -54,6 +54,9 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to customers"),
+        (name = "Disputes", description = "Manage disputes raised against payments"),
+        (name = "Files", description = "Upload files used as dispute evidence and KYC documents"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
@@ -65,6 +68,21 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
         crate::routes::refunds::refunds_create,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_confirm,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::payouts::payouts_reverse,
+        crate::routes::payouts::payouts_list,
+        crate::routes::payouts::payouts_accounts,
+        crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::retrieve_disputes_list,
+        crate::routes::disputes::accept_dispute,
+        crate::routes::disputes::submit_dispute_evidence,
+        crate::routes::files::files_create,
+        crate::routes::files::files_retrieve,
+        crate::routes::files::files_delete,
         crate::routes::admin::merchant_account_create,
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
@@ -82,6 +100,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_update,
         crate::routes::payments::payments_confirm,
         crate::routes::payments::payments_capture,
+        crate::routes::payments::payments_incremental_authorization,
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
@@ -131,6 +150,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureStatus,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::WalletIssuer,
@@ -139,6 +159,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::SupportedWallets,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+        api_models::enums::FilePurpose,
         api_models::admin::PaymentConnectorCreate,
         api_models::admin::PaymentMethods,
         api_models::payments::AddressDetails,
@@ -151,6 +176,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::AffirmIssuer,
         api_models::payments::AfterpayClearpayIssuer,
         api_models::payments::NextAction,
+        api_models::payments::QrCodeInformation,
+        api_models::payments::SdkClientInformation,
+        api_models::payments::AuthenticationDetails,
         api_models::payments::PayLaterData,
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
@@ -169,6 +197,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::CaptureResponse,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::SessionToken,
@@ -183,6 +213,23 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentListResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutUpdateRequest,
+        api_models::payouts::PayoutConfirmRequest,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::CardPayout,
+        api_models::payouts::BankPayout,
+        api_models::payouts::ConnectedAccountPayout,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutListConstraints,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutAccountsResponse,
+        api_models::payouts::PayoutAccount,
+        api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeListConstraints,
+        api_models::disputes::DisputeListResponse,
+        api_models::disputes::SubmitEvidenceRequest,
+        api_models::files::CreateFileResponse,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -3,7 +3,9 @@ pub mod api_keys;
 pub mod app;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod ephemeral_key;
+pub mod files;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
@@ -14,8 +16,9 @@ pub mod refunds;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Configs, Customers, Disputes, EphemeralKey, Files, Health, Mandates,
+    MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds,
+    Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
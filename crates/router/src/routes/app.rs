@@ -4,13 +4,16 @@ use super::health::*;
 #[cfg(feature = "olap")]
 use super::{admin::*, api_keys::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
+use super::{
+    configs::*, customers::*, disputes::*, files::*, mandates::*, payments::*, payouts::*,
+    refunds::*,
+};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
-    services::Store,
+    services::{file_storage, Store},
 };
 
 #[derive(Clone)]
@@ -18,6 +21,7 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    pub file_storage_client: Box<dyn file_storage::FileStorageInterface>,
 }
 
 pub trait AppStateInfo {
@@ -47,11 +51,14 @@ impl AppState {
             }
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
+        let file_storage_client =
+            file_storage::get_file_storage_client(&conf.file_upload_config).await;
 
         Self {
             flow_name: String::from("default"),
             store,
             conf,
+            file_storage_client,
         }
     }
 
@@ -104,6 +111,10 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
                 .service(
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -185,22 +196,75 @@ impl Payouts {
 
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
+            route = route
+                .service(web::resource("/list").route(web::get().to(payouts_list)))
+                .service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
         }
         #[cfg(feature = "oltp")]
         {
             route = route
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(
+                    web::resource("/{payout_id}")
+                        .route(web::get().to(payouts_retrieve))
+                        .route(web::post().to(payouts_update)),
+                )
+                .service(
+                    web::resource("/{payout_id}/confirm").route(web::post().to(payouts_confirm)),
+                )
+                .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
+                .service(
+                    web::resource("/{payout_id}/reverse").route(web::post().to(payouts_reverse)),
+                );
+        }
+        route
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/disputes").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route =
+                route.service(web::resource("/list").route(web::get().to(retrieve_disputes_list)));
+        }
+        #[cfg(feature = "oltp")]
+        {
+            route = route
+                .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
+                .service(
+                    web::resource("/{dispute_id}/accept").route(web::post().to(accept_dispute)),
+                )
+                .service(
+                    web::resource("/{dispute_id}/evidence")
+                        .route(web::post().to(submit_dispute_evidence)),
+                );
         }
         route
     }
 }
 
+pub struct Files;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Files {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/files")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(files_create)))
+            .service(
+                web::resource("/{file_id}")
+                    .route(web::get().to(files_retrieve))
+                    .route(web::delete().to(files_delete)),
+            )
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
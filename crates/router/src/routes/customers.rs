@@ -29,7 +29,7 @@ pub async fn customers_create(
     req: HttpRequest,
     json_payload: web::Json<customers::CustomerRequest>,
 ) -> HttpResponse {
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
@@ -106,7 +106,7 @@ pub async fn customers_update(
 ) -> HttpResponse {
     let customer_id = path.into_inner();
     json_payload.customer_id = customer_id;
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
@@ -0,0 +1,160 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::disputes::*,
+    services::{api, authentication as auth},
+    types::api::disputes,
+};
+
+// Disputes - Retrieve
+
+///
+/// To retrieve the details of a dispute raised against a payment
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was retrieved successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve a Dispute"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+// #[get("/{dispute_id}")]
+pub async fn retrieve_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        dispute_id,
+        disputes_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Disputes - List
+
+///
+/// To list the disputes raised against the payments of the merchant
+#[utoipa::path(
+    get,
+    path = "/disputes/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+        ("offset" = Option<i64>, Query, description = "The number of objects to skip"),
+        ("dispute_status" = Option<DisputeStatus>, Query, description = "The status of the dispute"),
+        ("dispute_stage" = Option<DisputeStage>, Query, description = "The stage of the dispute"),
+        ("connector" = Option<String>, Query, description = "The connector through which the dispute was raised")
+    ),
+    responses(
+        (status = 200, description = "The dispute list was retrieved successfully", body = DisputeListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "List Disputes"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+#[cfg(feature = "olap")]
+// #[get("/list")]
+pub async fn retrieve_disputes_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<disputes::DisputeListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, req| disputes_list_core(&*state.store, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Disputes - Accept
+
+///
+/// To accept a dispute, conceding the disputed amount to the customer
+#[utoipa::path(
+    post,
+    path = "/disputes/{dispute_id}/accept",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was accepted", body = DisputeResponse),
+        (status = 400, description = "Dispute cannot be accepted in its current state")
+    ),
+    tag = "Disputes",
+    operation_id = "Accept a Dispute"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesAccept))]
+// #[post("/{dispute_id}/accept")]
+pub async fn accept_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        dispute_id,
+        disputes_accept_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Disputes - Submit Evidence
+
+///
+/// To challenge a dispute by submitting evidence to the connector
+#[utoipa::path(
+    post,
+    path = "/disputes/{dispute_id}/evidence",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body=SubmitEvidenceRequest,
+    responses(
+        (status = 200, description = "The evidence was submitted", body = DisputeResponse),
+        (status = 400, description = "Dispute cannot be challenged in its current state")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+// #[post("/{dispute_id}/evidence")]
+pub async fn submit_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<disputes::SubmitEvidenceRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let dispute_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            disputes_submit_evidence_core(state, merchant_account, &dispute_id, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
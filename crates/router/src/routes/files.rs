@@ -0,0 +1,126 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::files::*,
+    services::{api, authentication as auth},
+};
+
+// Files - Create
+
+///
+/// To upload a file, which can be used as evidence to challenge a dispute or as a KYC document
+#[utoipa::path(
+    post,
+    path = "/files",
+    request_body(
+        content_type = "multipart/form-data",
+        description = "Multipart form with the `file` to be uploaded, its `purpose` and an optional `dispute_id` to submit the file to the connector of the dispute"
+    ),
+    responses(
+        (status = 200, description = "File uploaded", body = CreateFileResponse),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Create a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFile))]
+// #[post("")]
+pub async fn files_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let create_file_request = match helpers::get_create_file_request(
+        payload,
+        state.conf.file_upload_config.max_file_size,
+    )
+    .await
+    {
+        Ok(create_file_request) => create_file_request,
+        Err(error) => return api::log_and_return_error_response(error),
+    };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        create_file_request,
+        files_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Files - Retrieve
+
+///
+/// To retrieve the contents of an uploaded file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File retrieved"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Retrieve a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrieveFile))]
+// #[get("/{file_id}")]
+pub async fn files_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let file_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        file_id,
+        files_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Files - Delete
+
+///
+/// To delete an uploaded file
+#[utoipa::path(
+    delete,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File deleted"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Delete a File"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFile))]
+// #[delete("/{file_id}")]
+pub async fn files_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let file_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        file_id,
+        files_delete_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
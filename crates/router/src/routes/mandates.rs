@@ -72,13 +72,11 @@ pub async fn revoke_mandate(
     let mandate_id = mandates::MandateId {
         mandate_id: path.into_inner(),
     };
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
+        mandate::revoke_mandate,
         &auth::ApiKeyAuth,
     )
     .await
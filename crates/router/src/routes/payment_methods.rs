@@ -29,7 +29,7 @@ pub async fn create_payment_method_api(
     req: HttpRequest,
     json_payload: web::Json<payment_methods::CreatePaymentMethod>,
 ) -> HttpResponse {
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
@@ -206,7 +206,7 @@ pub async fn payment_method_update_api(
 ) -> HttpResponse {
     let payment_method_id = path.into_inner();
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
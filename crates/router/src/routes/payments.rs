@@ -37,7 +37,7 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         payload,
@@ -202,7 +202,7 @@ pub async fn payments_update(
         Err(err) => return api::log_and_return_error_response(report!(err)),
     };
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         payload,
@@ -262,7 +262,7 @@ pub async fn payments_confirm(
             Err(e) => return api::log_and_return_error_response(e),
         };
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         payload,
@@ -311,7 +311,7 @@ pub async fn payments_capture(
         ..json_payload.into_inner()
     };
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         capture_payload,
@@ -330,6 +330,60 @@ pub async fn payments_capture(
     .await
 }
 
+// Payments - Incremental authorization
+
+///
+/// To increase the amount authorized for a payment which was not captured yet
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/incremental_authorization",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=PaymentsIncrementalAuthorizationRequest,
+    responses(
+        (status = 200, description = "Authorized amount of the payment increased", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Increment the authorized amount of a Payment"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization))]
+// #[post("/{payment_id}/incremental_authorization")]
+pub async fn payments_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsIncrementalAuthorizationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+
+    api::server_wrap_with_idempotency(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentIncrementalAuthorization,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 // Payments - Session token
 
 ///
@@ -458,7 +512,7 @@ pub async fn payments_cancel(
     let payment_id = path.into_inner();
     payload.payment_id = payment_id;
 
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         payload,
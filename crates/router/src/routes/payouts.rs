@@ -1,45 +1,291 @@
-use actix_web::{
-    body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
-};
+use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts::*,
+    services::{api, authentication as auth},
+    types::api::payouts,
+};
+
+// Payouts - Create
+
+///
+/// To create a payout to a customer. The payout is confirmed right away if `confirm` is set to true
+#[utoipa::path(
+    post,
+    path = "/payouts/create",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
 // #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    http_response("create")
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Retrieve
+
+///
+/// To retrieve the properties of a Payout. This may be used to get the status of a previously initiated payout
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    http_response("retrieve")
+// #[get("/{payout_id}")]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Update
+
+///
+/// To update the properties of a Payout which is yet to be confirmed
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout updated", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Update a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-// #[post("/update")]
-pub async fn payouts_update() -> impl Responder {
-    http_response("update")
+// #[post("/{payout_id}")]
+pub async fn payouts_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutUpdateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payouts_update_core(state, merchant_account, &payout_id, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
-// #[post("/reverse")]
-pub async fn payouts_reverse() -> impl Responder {
-    http_response("reverse")
+// Payouts - Confirm
+
+///
+/// To confirm a Payout, which creates and executes the payout at the connector
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/confirm",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutConfirmRequest,
+    responses(
+        (status = 200, description = "Payout confirmed", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Confirm a Payout"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsConfirm))]
+// #[post("/{payout_id}/confirm")]
+pub async fn payouts_confirm(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutConfirmRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payouts_confirm_core(state, merchant_account, &payout_id, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+// Payouts - Cancel
+
+///
+/// To cancel a Payout which has not been executed at the connector yet
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be cancelled in its current state")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout"
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    http_response("cancel")
+// #[post("/{payout_id}/cancel")]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_cancel_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-#[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
-// #[get("/accounts")]
-pub async fn payouts_accounts() -> impl Responder {
-    http_response("accounts")
+// Payouts - Reverse
+
+///
+/// To reverse a successful Payout, returning the funds to the merchant
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reverse",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout reversed", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be reversed in its current state")
+    ),
+    tag = "Payouts",
+    operation_id = "Reverse a Payout"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
+// #[post("/{payout_id}/reverse")]
+pub async fn payouts_reverse(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_reverse_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
-    HttpResponse::Ok().body(response)
+// Payouts - List
+
+///
+/// To list the payouts created by the merchant
+#[utoipa::path(
+    get,
+    path = "/payouts/list",
+    params(
+        ("limit" = i64, Query, description = "Limit on the number of objects to return"),
+        ("offset" = i64, Query, description = "The number of objects to skip")
+    ),
+    responses(
+        (status = 200, description = "List of payouts", body = PayoutListResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payouts"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+#[cfg(feature = "olap")]
+// #[get("/list")]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<payouts::PayoutListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, req| payouts_list_core(&*state.store, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+// Payouts - Accounts
+
+///
+/// To list the connector accounts of the merchant which payouts can be made with
+#[utoipa::path(
+    get,
+    path = "/payouts/accounts",
+    responses(
+        (status = 200, description = "List of payout accounts", body = PayoutAccountsResponse)
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payout Accounts"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
+#[cfg(feature = "olap")]
+// #[get("/accounts")]
+pub async fn payouts_accounts(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, merchant_account, _| payouts_accounts_core(&*state.store, merchant_account),
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
@@ -30,7 +30,7 @@ pub async fn refunds_create(
     req: HttpRequest,
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
@@ -105,7 +105,7 @@ pub async fn refunds_update(
     path: web::Path<String>,
 ) -> HttpResponse {
     let refund_id = path.into_inner();
-    api::server_wrap(
+    api::server_wrap_with_idempotency(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
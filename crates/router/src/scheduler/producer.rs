@@ -12,6 +12,7 @@ use crate::{
     logger::{debug, error, info, warn},
     routes::AppState,
     scheduler::{utils::*, SchedulerFlow, SchedulerOptions},
+    services::locking,
     types::storage::{self, enums::ProcessTrackerStatus},
 };
 
@@ -62,21 +63,39 @@ pub async fn run_producer_flow(
     op: &SchedulerOptions,
     settings: &SchedulerSettings,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    lock_acquire_release::<_, _, error_stack::Report<errors::ProcessTrackerError>>(
-        state,
-        settings,
-        move || async {
-            let tasks = fetch_producer_tasks(&*state.store, op, settings).await?;
-            debug!("Producer count of tasks {}", tasks.len());
+    let lock = locking::try_acquire(
+        state.store.get_redis_conn(),
+        &settings.producer.lock_key,
+        settings.producer.lock_ttl,
+    )
+    .await
+    .map_err(errors::ProcessTrackerError::ERedisError)?;
+    let lock = match lock {
+        Some(lock) => lock,
+        None => {
+            info!("Producer lock not acquired, previous fetch still in progress");
+            return Ok(());
+        }
+    };
+
+    let result = produce_tasks(state, op, settings).await;
+    if let Err(error) = lock.release().await {
+        error!(?error, "Failed to release producer lock");
+    }
+    result
+}
 
-            // [#268]: Allow task based segregation of tasks
+async fn produce_tasks(
+    state: &AppState,
+    op: &SchedulerOptions,
+    settings: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tasks = fetch_producer_tasks(&*state.store, op, settings).await?;
+    debug!("Producer count of tasks {}", tasks.len());
 
-            divide_and_append_tasks(state, SchedulerFlow::Producer, tasks, settings).await?;
+    // [#268]: Allow task based segregation of tasks
 
-            Ok(())
-        },
-    )
-    .await?;
+    divide_and_append_tasks(state, SchedulerFlow::Producer, tasks, settings).await?;
 
     Ok(())
 }
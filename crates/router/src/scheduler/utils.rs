@@ -331,34 +331,6 @@ fn get_delay<'a>(
     }
 }
 
-pub(crate) async fn lock_acquire_release<F, Fut, E>(
-    state: &AppState,
-    settings: &SchedulerSettings,
-    callback: F,
-) -> Result<(), E>
-where
-    F: Fn() -> Fut,
-    Fut: futures::Future<Output = Result<(), E>>,
-{
-    let tag = "PRODUCER_LOCK";
-    let lock_key = &settings.producer.lock_key;
-    let lock_val = "LOCKED";
-    let ttl = settings.producer.lock_ttl;
-
-    let result = if state
-        .store
-        .acquire_pt_lock(tag, lock_key, lock_val, ttl)
-        .await
-    {
-        let result = callback().await;
-        state.store.release_pt_lock(tag, lock_key).await;
-        result
-    } else {
-        Ok(())
-    };
-    result
-}
-
 pub(crate) async fn signal_handler(
     mut sig: signal_hook_tokio::Signals,
     sender: oneshot::Sender<()>,
@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod outgoing_webhook_retry;
+pub mod payment_auth_expiry;
+pub mod payment_auto_capture;
 pub mod payment_sync;
 pub mod refund_router;
 
@@ -44,7 +47,10 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    PaymentsAutoCaptureWorkflow,
+    PaymentsAuthExpiryWorkflow
 }
 
 #[async_trait]
@@ -0,0 +1,24 @@
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks as webhooks_core, errors, routes::AppState, scheduler::consumer, types::storage,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        webhooks_core::start_outgoing_webhook_retry_workflow(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,24 @@
+use super::{PaymentsAutoCaptureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::capture_policy, errors, routes::AppState, scheduler::consumer, types::storage,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsAutoCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        capture_policy::start_auto_capture_workflow(state, process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -1,7 +1,10 @@
 pub mod api;
 pub mod authentication;
 pub mod encryption;
+pub mod file_storage;
+pub mod locking;
 pub mod logger;
+pub mod rate_limit;
 
 use std::sync::Arc;
 
@@ -14,6 +17,7 @@ pub struct Store {
     #[cfg(feature = "olap")]
     pub replica_pool: PgPool,
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
+    pub(crate) cache: Arc<crate::db::cache::Cache>,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
 }
@@ -26,12 +30,20 @@ pub(crate) struct StoreConfig {
 }
 
 impl Store {
+    #[allow(clippy::expect_used)]
     pub async fn new(config: &crate::configs::settings::Settings, test_transaction: bool) -> Self {
+        let redis_conn = crate::connection::redis_connection(config).await;
+        let cache = Arc::new(crate::db::cache::Cache::new(&config.cache));
+        crate::db::cache::subscribe_to_invalidations(&redis_conn, cache.clone())
+            .await
+            .expect("Failed to subscribe to cache invalidations");
+
         Self {
             master_pool: diesel_make_pg_pool(&config.master_database, test_transaction).await,
             #[cfg(feature = "olap")]
             replica_pool: diesel_make_pg_pool(&config.replica_database, test_transaction).await,
-            redis_conn: Arc::new(crate::connection::redis_connection(config).await),
+            redis_conn: Arc::new(redis_conn),
+            cache,
             #[cfg(feature = "kv_store")]
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
@@ -49,25 +61,79 @@ impl Store {
     #[cfg(feature = "kv_store")]
     pub(crate) async fn push_to_drainer_stream<T>(
         &self,
-        redis_entry: storage_models::kv::TypedSql,
+        db_op: storage_models::kv::DBOperation,
         partition_key: crate::utils::storage_partitioning::PartitionKey<'_>,
     ) -> crate::core::errors::CustomResult<(), crate::core::errors::StorageError>
     where
         T: crate::utils::storage_partitioning::KvStorePartition,
     {
         use error_stack::ResultExt;
+        use storage_models::kv;
 
         let shard_key = T::shard_key(partition_key, self.config.drainer_num_partitions);
         let stream_name = self.get_drainer_stream_name(&shard_key);
-        self.redis_conn
-            .stream_append_entry(
-                &stream_name,
-                &redis_interface::RedisEntryId::AutoGeneratedID,
-                redis_entry
-                    .to_field_value_pairs()
-                    .change_context(crate::core::errors::StorageError::KVError)?,
-            )
-            .await
-            .change_context(crate::core::errors::StorageError::KVError)
+        let primary_key = db_op.primary_key();
+        let redis_entry = kv::TypedSql {
+            op: db_op,
+            version: None,
+        };
+        let field_value_pairs = redis_entry
+            .to_field_value_pairs()
+            .change_context(crate::core::errors::StorageError::KVError)?;
+
+        match primary_key {
+            // The drainer uses the version to skip operations which were already applied and to
+            // apply the operations of a row in order. The version is assigned and the entry is
+            // appended by a single script, so that no version is left without an entry and the
+            // entries of a row are appended in the order of their versions.
+            Some(primary_key) => {
+                let version_key = kv::version_key(&stream_name, &primary_key);
+                let mut args = vec![
+                    kv::ASSIGNED_VERSION_FIELD.to_string(),
+                    kv::VERSION_TTL.to_string(),
+                    kv::VERSION_ENTRY_FIELD.to_string(),
+                ];
+                args.extend(
+                    field_value_pairs
+                        .into_iter()
+                        .flat_map(|(field, value)| [field.to_string(), value]),
+                );
+                self.redis_conn
+                    .evaluate_script::<i64, _>(
+                        APPEND_VERSIONED_ENTRY_SCRIPT,
+                        vec![version_key, stream_name],
+                        args,
+                    )
+                    .await
+                    .map(|_| ())
+                    .change_context(crate::core::errors::StorageError::KVError)
+            }
+            None => self
+                .redis_conn
+                .stream_append_entry(
+                    &stream_name,
+                    &redis_interface::RedisEntryId::AutoGeneratedID,
+                    field_value_pairs,
+                )
+                .await
+                .change_context(crate::core::errors::StorageError::KVError),
+        }
     }
 }
+
+/// Assign the next version of the row in the version hash `KEYS[1]` and append the entry to the
+/// stream `KEYS[2]` along with the version. `ARGV` holds the field of the last assigned version,
+/// the expiry of the version hash, the field of the entry holding the version and the remaining
+/// fields and values of the entry. The version is stored only once the entry is appended.
+#[cfg(feature = "kv_store")]
+const APPEND_VERSIONED_ENTRY_SCRIPT: &str = r#"
+    local version = tonumber(redis.call("HGET", KEYS[1], ARGV[1]) or "0") + 1
+    local entry = { ARGV[3], tostring(version) }
+    for index = 4, #ARGV do
+        entry[#entry + 1] = ARGV[index]
+    end
+    redis.call("XADD", KEYS[2], "*", unpack(entry))
+    redis.call("HSET", KEYS[1], ARGV[1], version)
+    redis.call("EXPIRE", KEYS[1], ARGV[2])
+    return version
+"#;
@@ -1,4 +1,5 @@
 mod client;
+mod idempotency;
 pub(crate) mod request;
 
 use std::{
@@ -15,8 +16,8 @@ use masking::ExposeOptionInterface;
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
-use self::request::{ContentType, HeaderExt, RequestBuilderExt};
-pub use self::request::{Method, Request, RequestBuilder};
+pub use self::request::{ContentType, Method, Request, RequestBuilder};
+use self::request::{HeaderExt, RequestBuilderExt};
 use crate::{
     configs::settings::Connectors,
     core::{
@@ -83,6 +84,13 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
         Ok(None)
     }
 
+    fn get_request_form_data(
+        &self,
+        _req: &types::RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn build_request(
         &self,
         _req: &types::RouterData<T, Req, Resp>,
@@ -241,6 +249,9 @@ async fn send_request(
                     logger::debug!(?url_encoded_payload);
                     client.body(url_encoded_payload)
                 }
+                Some(ContentType::FormData) => {
+                    client.multipart(request.form_data.unwrap_or_default())
+                }
                 // If payload needs processing the body cannot have default
                 None => client.body(request.payload.expose_option().unwrap_or_default()),
             }
@@ -340,6 +351,7 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    FileData((Vec<u8>, mime::Mime)),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -458,6 +470,9 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::FileData((file_data, content_type))) => {
+            http_response_file_data(file_data, content_type)
+        }
 
         Err(error) => log_and_return_error_response(error),
     };
@@ -474,6 +489,57 @@ where
     res
 }
 
+/// Same as [`server_wrap`], except that requests carrying an `Idempotency-Key` header are run only
+/// once per key and merchant, and retries of the request get the response of the first request
+pub async fn server_wrap_with_idempotency<'a, 'b, T, Q, F, Fut>(
+    state: &'b AppState,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<storage::MerchantAccount, AppState>,
+) -> HttpResponse
+where
+    F: Fn(&'b AppState, storage::MerchantAccount, T) -> Fut,
+    Fut: Future<Output = RouterResponse<Q>>,
+    Q: Serialize + Debug + 'a,
+    T: Serialize + Debug,
+{
+    let idempotency_key = match idempotency::get_idempotency_key(request) {
+        Ok(Some(idempotency_key)) => idempotency_key,
+        Ok(None) => return server_wrap(state, request, payload, func, api_auth).await,
+        Err(error) => return log_and_return_error_response(error),
+    };
+
+    let idempotency_key = idempotency_key.as_str();
+    let func = &func;
+    let replayed_error = once_cell::sync::OnceCell::new();
+    let replayed_error_slot = &replayed_error;
+    let response = server_wrap(
+        state,
+        request,
+        payload,
+        move |state, merchant_account, payload| {
+            idempotency::call_once(
+                state,
+                request,
+                idempotency_key,
+                merchant_account,
+                payload,
+                replayed_error_slot,
+                func,
+            )
+        },
+        api_auth,
+    )
+    .await;
+
+    // The error response stored for the first request is sent to its retries as is
+    match replayed_error.into_inner() {
+        Some(replayed_error) => replayed_error.into_response(),
+        None => response,
+    }
+}
+
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
 where
     T: actix_web::ResponseError + error_stack::Context,
@@ -507,6 +573,16 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
         .body(res)
 }
 
+pub fn http_response_file_data<T: body::MessageBody + 'static>(
+    res: T,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .append_header(("Via", "Juspay_router"))
+        .body(res)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
@@ -0,0 +1,595 @@
+//! Runs requests carrying an `Idempotency-Key` header only once per key and merchant, replaying the
+//! stored response to retries of the request.
+
+use std::{future::Future, time::Duration};
+
+use actix_web::{http::header, HttpRequest, HttpResponse, ResponseError};
+use error_stack::{report, IntoReport, ResultExt};
+use once_cell::sync::OnceCell;
+use redis_interface::{RedisConnectionPool, SetnxReply};
+use serde::{Deserialize, Serialize};
+
+use super::{ApplicationResponse, RedirectForm};
+use crate::{
+    configs::settings::IdempotencySettings,
+    core::errors::{self, RouterResponse, RouterResult},
+    logger,
+    routes::AppState,
+    types::{api, storage},
+};
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// The response of a request run for the first time, or the stored response replayed to a retry
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub(super) enum IdempotentResponse<Q> {
+    Fresh(Q),
+    Replayed(serde_json::Value),
+}
+
+#[derive(Serialize, Deserialize)]
+struct IdempotencyRecord {
+    /// Hash of the endpoint and payload of the request the key was first used with
+    fingerprint: String,
+    /// `None` while the request is in progress
+    response: Option<StoredResponse>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum StoredResponse {
+    Json(String),
+    StatusOk,
+    TextPlain(String),
+    JsonForRedirection(api::RedirectionResponse),
+    Form(RedirectForm),
+    Error(StoredError),
+}
+
+/// The error response of a request which failed after it may have had side effects. It is replayed
+/// to retries of the request as is, since the error it was built from is not kept.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct StoredError {
+    status_code: u16,
+    body: String,
+}
+
+impl StoredError {
+    fn new(error: &errors::ApiErrorResponse) -> Self {
+        Self {
+            status_code: error.status_code().as_u16(),
+            body: error.to_string(),
+        }
+    }
+
+    pub(super) fn into_response(self) -> HttpResponse {
+        let status_code = actix_web::http::StatusCode::from_u16(self.status_code)
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
+        actix_web::HttpResponseBuilder::new(status_code)
+            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
+            .insert_header((header::VIA, "Juspay_Router"))
+            .body(self.body)
+    }
+}
+
+pub(super) fn get_idempotency_key(request: &HttpRequest) -> RouterResult<Option<String>> {
+    let idempotency_key = match request.headers().get(crate::headers::IDEMPOTENCY_KEY) {
+        Some(idempotency_key) => idempotency_key,
+        None => return Ok(None),
+    };
+
+    let idempotency_key = idempotency_key.to_str().into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Idempotency key must only contain visible ASCII characters".to_string(),
+        },
+    )?;
+    if idempotency_key.is_empty() || idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Idempotency key must be between 1 and {MAX_IDEMPOTENCY_KEY_LENGTH} characters long"
+            ),
+        })
+        .into_report()?;
+    }
+
+    Ok(Some(idempotency_key.to_string()))
+}
+
+/// Run `func` unless the idempotency key was already used by the merchant. Retries of the request
+/// get the stored response, while requests reusing the key for another endpoint or payload, or
+/// while the first request is still in progress, are rejected. A stored error response is set in
+/// `replayed_error` instead of being returned, to be sent as the response of the retry.
+pub(super) async fn call_once<'b, T, Q, F, Fut>(
+    state: &'b AppState,
+    request: &HttpRequest,
+    idempotency_key: &str,
+    merchant_account: storage::MerchantAccount,
+    payload: T,
+    replayed_error: &OnceCell<StoredError>,
+    func: &F,
+) -> RouterResponse<IdempotentResponse<Q>>
+where
+    F: Fn(&'b AppState, storage::MerchantAccount, T) -> Fut,
+    Fut: Future<Output = RouterResponse<Q>>,
+    Q: Serialize,
+    T: Serialize,
+{
+    let key = format!(
+        "idempotency_{}_{idempotency_key}",
+        merchant_account.merchant_id
+    );
+    let fingerprint = fingerprint(request, &payload)?;
+
+    run_once(
+        &state.store.get_redis_conn(),
+        &state.conf.idempotency,
+        &key,
+        fingerprint,
+        replayed_error,
+        func(state, merchant_account, payload),
+    )
+    .await
+}
+
+async fn run_once<Q, Fut>(
+    redis_conn: &RedisConnectionPool,
+    settings: &IdempotencySettings,
+    key: &str,
+    fingerprint: String,
+    replayed_error: &OnceCell<StoredError>,
+    request: Fut,
+) -> RouterResponse<IdempotentResponse<Q>>
+where
+    Fut: Future<Output = RouterResponse<Q>>,
+    Q: Serialize,
+{
+    let in_progress = serialize_record(&IdempotencyRecord {
+        fingerprint: fingerprint.clone(),
+        response: None,
+    })?;
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(key, in_progress.as_str(), settings.in_progress_ttl)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store idempotency record")?;
+    if reply == SetnxReply::KeyNotSet {
+        return replay(redis_conn, key, &fingerprint, replayed_error).await;
+    }
+
+    let response = match extend_while_running(
+        redis_conn,
+        key,
+        &in_progress,
+        settings.in_progress_ttl,
+        request,
+    )
+    .await
+    {
+        Ok(response) => response,
+        Err(error) if is_raised_before_side_effects(error.current_context()) => {
+            // The request can be retried with the same key once it is corrected
+            delete_record(redis_conn, key).await?;
+            return Err(error);
+        }
+        Err(error) => {
+            let stored_response = StoredResponse::Error(StoredError::new(error.current_context()));
+            store_record(redis_conn, settings, key, fingerprint, stored_response).await?;
+            return Err(error);
+        }
+    };
+
+    let (stored_response, response) = store_response(response)?;
+    match stored_response {
+        Some(stored_response) => {
+            store_record(redis_conn, settings, key, fingerprint, stored_response).await?
+        }
+        None => delete_record(redis_conn, key).await?,
+    }
+
+    Ok(response)
+}
+
+/// Errors raised while validating or authorizing the request, or while it waits for another
+/// request, before it has any side effects
+fn is_raised_before_side_effects(error: &errors::ApiErrorResponse) -> bool {
+    matches!(
+        error,
+        errors::ApiErrorResponse::Unauthorized
+            | errors::ApiErrorResponse::InvalidEphemeralKey
+            | errors::ApiErrorResponse::InvalidJwtToken
+            | errors::ApiErrorResponse::GenericUnauthorized { .. }
+            | errors::ApiErrorResponse::InvalidRequestUrl
+            | errors::ApiErrorResponse::InvalidHttpMethod
+            | errors::ApiErrorResponse::MissingRequiredField { .. }
+            | errors::ApiErrorResponse::InvalidDataFormat { .. }
+            | errors::ApiErrorResponse::InvalidRequestData { .. }
+            | errors::ApiErrorResponse::InvalidDataValue { .. }
+            | errors::ApiErrorResponse::ClientSecretNotGiven
+            | errors::ApiErrorResponse::ClientSecretInvalid
+            | errors::ApiErrorResponse::ResourceBusy
+            | errors::ApiErrorResponse::TooManyRequests
+    )
+}
+
+/// Run the request, extending the expiry of its in-progress record until the request completes, so
+/// that the record does not expire while a slow request is still running
+async fn extend_while_running<Fut: Future>(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    in_progress: &str,
+    in_progress_ttl: i64,
+    request: Fut,
+) -> Fut::Output {
+    let extension_period = Duration::from_secs(
+        u64::try_from(in_progress_ttl / 2)
+            .unwrap_or_default()
+            .max(1),
+    );
+    let mut extension_interval = tokio::time::interval_at(
+        tokio::time::Instant::now() + extension_period,
+        extension_period,
+    );
+
+    futures::pin_mut!(request);
+    loop {
+        tokio::select! {
+            output = &mut request => break output,
+            _ = extension_interval.tick() => {
+                if let Err(error) = redis_conn
+                    .set_expiry_if_value_matches(key, in_progress, in_progress_ttl)
+                    .await
+                {
+                    logger::error!(?error, "Failed to extend idempotency record");
+                }
+            }
+        }
+    }
+}
+
+async fn store_record(
+    redis_conn: &RedisConnectionPool,
+    settings: &IdempotencySettings,
+    key: &str,
+    fingerprint: String,
+    stored_response: StoredResponse,
+) -> RouterResult<()> {
+    let record = serialize_record(&IdempotencyRecord {
+        fingerprint,
+        response: Some(stored_response),
+    })?;
+    redis_conn
+        .set_key_with_expiry(key, record, settings.ttl)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store idempotent response")
+}
+
+async fn delete_record(redis_conn: &RedisConnectionPool, key: &str) -> RouterResult<()> {
+    redis_conn
+        .delete_key(key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete idempotency record")
+}
+
+async fn replay<Q>(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    fingerprint: &str,
+    replayed_error: &OnceCell<StoredError>,
+) -> RouterResponse<IdempotentResponse<Q>> {
+    let record = redis_conn
+        .get_and_deserialize_key::<IdempotencyRecord>(key, "IdempotencyRecord")
+        .await;
+    let record = match record {
+        Ok(record) => record,
+        // The first request failed meanwhile, and its record was deleted
+        Err(error) if matches!(error.current_context(), errors::RedisError::NotFound) => {
+            return Err(report!(errors::ApiErrorResponse::ResourceBusy))
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch idempotency record")
+        }
+    };
+
+    if record.fingerprint != fingerprint {
+        return Err(report!(errors::ApiErrorResponse::IdempotencyKeyConflict));
+    }
+
+    match record.response {
+        Some(StoredResponse::Json(response)) => {
+            let response = serde_json::from_str(&response)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize idempotent response")?;
+            Ok(ApplicationResponse::Json(IdempotentResponse::Replayed(
+                response,
+            )))
+        }
+        Some(StoredResponse::StatusOk) => Ok(ApplicationResponse::StatusOk),
+        Some(StoredResponse::TextPlain(text)) => Ok(ApplicationResponse::TextPlain(text)),
+        Some(StoredResponse::JsonForRedirection(response)) => {
+            Ok(ApplicationResponse::JsonForRedirection(response))
+        }
+        Some(StoredResponse::Form(form)) => Ok(ApplicationResponse::Form(form)),
+        Some(StoredResponse::Error(stored_error)) => {
+            // The slot is set once per request, as the stored response is replayed only once
+            replayed_error.set(stored_error).ok();
+            Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Replaying the stored error response")
+        }
+        None => Err(report!(errors::ApiErrorResponse::ResourceBusy))
+            .attach_printable("Request with the same idempotency key is in progress"),
+    }
+}
+
+/// The response to be stored, unless it cannot be replayed, and the response of the request
+fn store_response<Q: Serialize>(
+    response: ApplicationResponse<Q>,
+) -> RouterResult<(
+    Option<StoredResponse>,
+    ApplicationResponse<IdempotentResponse<Q>>,
+)> {
+    Ok(match response {
+        ApplicationResponse::Json(response) => {
+            let stored_response = serde_json::to_string(&response)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize idempotent response")?;
+            (
+                Some(StoredResponse::Json(stored_response)),
+                ApplicationResponse::Json(IdempotentResponse::Fresh(response)),
+            )
+        }
+        ApplicationResponse::StatusOk => (
+            Some(StoredResponse::StatusOk),
+            ApplicationResponse::StatusOk,
+        ),
+        ApplicationResponse::TextPlain(text) => (
+            Some(StoredResponse::TextPlain(text.clone())),
+            ApplicationResponse::TextPlain(text),
+        ),
+        ApplicationResponse::JsonForRedirection(response) => (
+            Some(StoredResponse::JsonForRedirection(response.clone())),
+            ApplicationResponse::JsonForRedirection(response),
+        ),
+        ApplicationResponse::Form(form) => (
+            Some(StoredResponse::Form(form.clone())),
+            ApplicationResponse::Form(form),
+        ),
+        ApplicationResponse::FileData(file_data) => {
+            (None, ApplicationResponse::FileData(file_data))
+        }
+    })
+}
+
+fn fingerprint<T: Serialize>(request: &HttpRequest, payload: &T) -> RouterResult<String> {
+    let payload = serde_json::to_vec(payload)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize request payload")?;
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(request.method().as_str().as_bytes());
+    hasher.update(request.path().as_bytes());
+    hasher.update(&payload);
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+fn serialize_record(record: &IdempotencyRecord) -> RouterResult<String> {
+    serde_json::to_string(record)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize idempotency record")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    fn json_response(id: u32) -> RouterResponse<serde_json::Value> {
+        Ok(ApplicationResponse::Json(serde_json::json!({ "id": id })))
+    }
+
+    fn get_json(response: ApplicationResponse<IdempotentResponse<serde_json::Value>>) -> String {
+        match response {
+            ApplicationResponse::Json(response) => serde_json::to_string(&response).unwrap(),
+            _ => panic!("expected a JSON response"),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_retry_gets_the_stored_response() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let key = format!("test_idempotency_{}", uuid::Uuid::new_v4());
+        let replayed_error = OnceCell::new();
+
+        let first = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(1) },
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            first,
+            ApplicationResponse::Json(IdempotentResponse::Fresh(_))
+        ));
+
+        // The retry is not run, and gets the response of the first request
+        let retry = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(2) },
+        )
+        .await
+        .unwrap();
+        assert_eq!(get_json(retry), get_json(first));
+    }
+
+    #[actix_rt::test]
+    async fn test_key_reused_with_another_payload_is_rejected() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let key = format!("test_idempotency_{}", uuid::Uuid::new_v4());
+        let replayed_error = OnceCell::new();
+
+        run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(1) },
+        )
+        .await
+        .unwrap();
+
+        let error = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "other_fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(2) },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::IdempotencyKeyConflict
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_retry_while_in_progress_is_rejected() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let key = format!("test_idempotency_{}", uuid::Uuid::new_v4());
+        let replayed_error = OnceCell::new();
+
+        let first = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async {
+                // The retry arrives while the first request is running
+                let retry = run_once(
+                    &redis_conn,
+                    &conf.idempotency,
+                    &key,
+                    "fingerprint".to_string(),
+                    &replayed_error,
+                    async { json_response(2) },
+                )
+                .await
+                .unwrap_err();
+                assert!(matches!(
+                    retry.current_context(),
+                    errors::ApiErrorResponse::ResourceBusy
+                ));
+
+                json_response(1)
+            },
+        )
+        .await;
+        assert!(first.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn test_error_after_side_effects_is_replayed() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let key = format!("test_idempotency_{}", uuid::Uuid::new_v4());
+
+        let first_error = OnceCell::new();
+        let error = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &first_error,
+            async {
+                Err::<ApplicationResponse<serde_json::Value>, _>(report!(
+                    errors::ApiErrorResponse::PaymentCaptureFailed { data: None }
+                ))
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(first_error.get().is_none());
+
+        let replayed_error = OnceCell::new();
+        run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(1) },
+        )
+        .await
+        .unwrap_err();
+        let replayed_error = replayed_error.into_inner().unwrap();
+        assert_eq!(
+            replayed_error.status_code,
+            error.current_context().status_code().as_u16()
+        );
+        assert_eq!(replayed_error.body, error.current_context().to_string());
+    }
+
+    #[actix_rt::test]
+    async fn test_validation_error_allows_retry() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let key = format!("test_idempotency_{}", uuid::Uuid::new_v4());
+        let replayed_error = OnceCell::new();
+
+        run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async {
+                Err::<ApplicationResponse<serde_json::Value>, _>(report!(
+                    errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "amount"
+                    }
+                ))
+            },
+        )
+        .await
+        .unwrap_err();
+
+        let retry = run_once(
+            &redis_conn,
+            &conf.idempotency,
+            &key,
+            "fingerprint".to_string(),
+            &replayed_error,
+            async { json_response(1) },
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            retry,
+            ApplicationResponse::Json(IdempotentResponse::Fresh(_))
+        ));
+    }
+}
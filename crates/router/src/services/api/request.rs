@@ -28,6 +28,7 @@ pub enum Method {
 pub enum ContentType {
     Json,
     FormUrlEncoded,
+    FormData,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +40,8 @@ pub struct Request {
     pub content_type: Option<ContentType>,
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
+    #[serde(skip)]
+    pub form_data: Option<reqwest::multipart::Form>,
 }
 
 impl Request {
@@ -51,6 +54,7 @@ impl Request {
             content_type: None,
             certificate: None,
             certificate_key: None,
+            form_data: None,
         }
     }
 
@@ -67,6 +71,10 @@ impl Request {
         self.content_type = Some(content_type);
     }
 
+    pub fn set_form_data(&mut self, form_data: reqwest::multipart::Form) {
+        self.form_data = Some(form_data);
+    }
+
     pub fn add_certificate(&mut self, certificate: Option<String>) {
         self.certificate = certificate;
     }
@@ -84,6 +92,7 @@ pub struct RequestBuilder {
     pub content_type: Option<ContentType>,
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
+    pub form_data: Option<reqwest::multipart::Form>,
 }
 
 impl RequestBuilder {
@@ -96,6 +105,7 @@ impl RequestBuilder {
             content_type: None,
             certificate: None,
             certificate_key: None,
+            form_data: None,
         }
     }
 
@@ -125,6 +135,11 @@ impl RequestBuilder {
         self
     }
 
+    pub fn form_data(mut self, form_data: Option<reqwest::multipart::Form>) -> Self {
+        self.form_data = form_data;
+        self
+    }
+
     pub fn content_type(mut self, content_type: ContentType) -> Self {
         self.content_type = Some(content_type);
         self
@@ -149,6 +164,7 @@ impl RequestBuilder {
             content_type: self.content_type,
             certificate: self.certificate,
             certificate_key: self.certificate_key,
+            form_data: self.form_data,
         }
     }
 }
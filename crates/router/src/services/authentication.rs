@@ -8,7 +8,7 @@ use crate::{
     core::errors::{self, RouterResult, StorageErrorExt},
     db::StorageInterface,
     routes::{app::AppStateInfo, AppState},
-    services::api,
+    services::{api, rate_limit},
     types::storage,
     utils::OptionExt,
 };
@@ -37,12 +37,14 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ApiKeyAuth {
     ) -> RouterResult<storage::MerchantAccount> {
         let api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
+        let merchant_account = state
             .store
             .find_merchant_account_by_api_key(api_key)
             .await
             .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Merchant not authenticated")
+            .attach_printable("Merchant not authenticated")?;
+        rate_limit::check_rate_limit(state, &merchant_account.merchant_id, api_key).await?;
+        Ok(merchant_account)
     }
 }
 
@@ -96,12 +98,14 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
     ) -> RouterResult<storage::MerchantAccount> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
+        let merchant_account = state
             .store
             .find_merchant_account_by_publishable_key(publishable_key)
             .await
             .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Merchant not authenticated")
+            .attach_printable("Merchant not authenticated")?;
+        rate_limit::check_rate_limit(state, &merchant_account.merchant_id, publishable_key).await?;
+        Ok(merchant_account)
     }
 }
 
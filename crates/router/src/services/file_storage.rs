@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::{FileStorageBackend, FileUploadConfig},
+    core::errors::{self, CustomResult},
+};
+
+/// Storage for the contents of files uploaded by merchants. The metadata of uploaded files is
+/// stored in the database, and the file contents are stored using one of these backends.
+#[async_trait::async_trait]
+pub trait FileStorageInterface: dyn_clone::DynClone + Send + Sync {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError>;
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError>;
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError>;
+}
+
+dyn_clone::clone_trait_object!(FileStorageInterface);
+
+pub async fn get_file_storage_client(config: &FileUploadConfig) -> Box<dyn FileStorageInterface> {
+    match config.storage_backend {
+        FileStorageBackend::FileSystem => Box::new(FileSystem::new(&config.file_system_path)),
+        #[cfg(feature = "s3")]
+        FileStorageBackend::AwsS3 => Box::new(s3::AwsS3::new(config).await),
+    }
+}
+
+/// Stores files in a directory on the local file system.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    base_path: PathBuf,
+}
+
+impl FileSystem {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: PathBuf::from(base_path),
+        }
+    }
+
+    fn get_file_path(&self, file_key: &str) -> PathBuf {
+        self.base_path.join(file_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for FileSystem {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        if let Some(parent_directory) = file_path.parent() {
+            tokio::fs::create_dir_all(parent_directory)
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::UploadFailed)
+                .attach_printable("Failed to create directory for file")?;
+        }
+        tokio::fs::write(&file_path, file)
+            .await
+            .into_report()
+            .change_context(errors::FileStorageError::UploadFailed)
+            .attach_printable_lazy(|| format!("Failed to write file {}", file_path.display()))
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        tokio::fs::read(&file_path).await.map_err(|error| {
+            let error_context = match error.kind() {
+                std::io::ErrorKind::NotFound => errors::FileStorageError::FileNotFound,
+                _ => errors::FileStorageError::RetrieveFailed,
+            };
+            error_stack::report!(error)
+                .change_context(error_context)
+                .attach_printable(format!("Failed to read file {}", file_path.display()))
+        })
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        tokio::fs::remove_file(&file_path).await.map_err(|error| {
+            let error_context = match error.kind() {
+                std::io::ErrorKind::NotFound => errors::FileStorageError::FileNotFound,
+                _ => errors::FileStorageError::DeleteFailed,
+            };
+            error_stack::report!(error)
+                .change_context(error_context)
+                .attach_printable(format!("Failed to delete file {}", file_path.display()))
+        })
+    }
+}
+
+#[cfg(feature = "s3")]
+mod s3 {
+    use aws_config::meta::region::RegionProviderChain;
+    use aws_sdk_s3::{types::ByteStream, Client, Region};
+
+    use super::*;
+
+    /// Stores files in an S3 (or S3-compatible) bucket.
+    #[derive(Debug, Clone)]
+    pub struct AwsS3 {
+        client: Client,
+        bucket_name: String,
+    }
+
+    impl AwsS3 {
+        pub async fn new(config: &FileUploadConfig) -> Self {
+            let region_provider =
+                RegionProviderChain::first_try(Region::new(config.region.clone()));
+            let shared_config = aws_config::from_env().region(region_provider).load().await;
+            Self {
+                client: Client::new(&shared_config),
+                bucket_name: config.bucket_name.clone(),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl FileStorageInterface for AwsS3 {
+        async fn upload_file(
+            &self,
+            file_key: &str,
+            file: Vec<u8>,
+        ) -> CustomResult<(), errors::FileStorageError> {
+            self.client
+                .put_object()
+                .bucket(&self.bucket_name)
+                .key(file_key)
+                .body(ByteStream::from(file))
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::UploadFailed)
+                .attach_printable("Error uploading file to S3")?;
+            Ok(())
+        }
+
+        async fn retrieve_file(
+            &self,
+            file_key: &str,
+        ) -> CustomResult<Vec<u8>, errors::FileStorageError> {
+            let object = self
+                .client
+                .get_object()
+                .bucket(&self.bucket_name)
+                .key(file_key)
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::RetrieveFailed)
+                .attach_printable("Error retrieving file from S3")?;
+            let file = object
+                .body
+                .collect()
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::RetrieveFailed)
+                .attach_printable("Error reading file contents from S3 response")?;
+            Ok(file.into_bytes().to_vec())
+        }
+
+        async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::FileStorageError> {
+            self.client
+                .delete_object()
+                .bucket(&self.bucket_name)
+                .key(file_key)
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::FileStorageError::DeleteFailed)
+                .attach_printable("Error deleting file from S3")?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Locks held in Redis, which serialize operations on the same resource across application
+//! instances.
+//!
+//! A lock expires after its TTL, so that it is not held forever by an instance which stopped
+//! while holding it, and is extended in the background for as long as it is held. Every
+//! acquisition of a lock is assigned a fencing token greater than the tokens of the previous
+//! acquisitions. Holders call [`Lock::fence_write`] before writing to the locked resource, which
+//! rejects the writes of a holder whose lock expired and was acquired by another holder.
+
+use std::{sync::Arc, time::Duration};
+
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, RedisConnectionPool, SetnxReply};
+use router_env::logger;
+
+use crate::{configs::settings::LockSettings, consts, core::errors::CustomResult, utils};
+
+/// Time for which the fencing token of a lock is kept after the lock was last acquired, in seconds
+const FENCING_TOKEN_TTL: i64 = 86400;
+
+/// Field of the fencing hash holding the token of the latest acquisition of the lock
+const FENCING_TOKEN_FIELD: &str = "fencing_token";
+
+/// Field of the fencing hash holding the greatest token which wrote to the locked resource
+const WRITTEN_TOKEN_FIELD: &str = "written_token";
+
+/// Accepts the write of the token unless the lock was acquired again since, or the resource was
+/// written with a greater token, and records the token as written
+const FENCE_WRITE_SCRIPT: &str = r#"
+local token = tonumber(ARGV[3])
+local latest_token = tonumber(redis.call("HGET", KEYS[1], ARGV[1]) or "0")
+local written_token = tonumber(redis.call("HGET", KEYS[1], ARGV[2]) or "0")
+if token < latest_token or token < written_token then
+    return 0
+end
+redis.call("HSET", KEYS[1], ARGV[2], ARGV[3])
+return 1
+"#;
+
+/// A lock held by this application instance, released when dropped
+pub struct Lock {
+    key: String,
+    /// Identifies this holder of the lock, so that the lock is not extended or released once it
+    /// expired and was acquired by another holder
+    holder: String,
+    fencing_token: i64,
+    redis_conn: Arc<RedisConnectionPool>,
+    extension: tokio::task::JoinHandle<()>,
+    released: bool,
+}
+
+impl Lock {
+    /// The token of this acquisition of the lock, greater than the tokens of its previous
+    /// acquisitions
+    pub fn fencing_token(&self) -> i64 {
+        self.fencing_token
+    }
+
+    /// Check that the locked resource may be written by this holder, returning `false` if the
+    /// lock expired and was acquired by another holder meanwhile
+    pub async fn fence_write(&self) -> CustomResult<bool, RedisError> {
+        self.redis_conn
+            .evaluate_script::<i64, _>(
+                FENCE_WRITE_SCRIPT,
+                get_fencing_key(&self.key),
+                vec![
+                    FENCING_TOKEN_FIELD.to_string(),
+                    WRITTEN_TOKEN_FIELD.to_string(),
+                    self.fencing_token.to_string(),
+                ],
+            )
+            .await
+            .map(|accepted| accepted == 1)
+    }
+
+    /// Release the lock, unless it expired and was acquired by another holder meanwhile
+    pub async fn release(mut self) -> CustomResult<(), RedisError> {
+        self.released = true;
+        self.extension.abort();
+        release_lock(&self.redis_conn, &self.key, &self.holder).await
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        self.extension.abort();
+        if self.released {
+            return;
+        }
+
+        let redis_conn = self.redis_conn.clone();
+        let key = std::mem::take(&mut self.key);
+        let holder = std::mem::take(&mut self.holder);
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(error) = release_lock(&redis_conn, &key, &holder).await {
+                        logger::error!(?error, %key, "Failed to release lock");
+                    }
+                });
+            }
+            Err(_) => {
+                logger::warn!(%key, "Lock dropped outside of a runtime, it is released once it expires");
+            }
+        }
+    }
+}
+
+/// Acquire the lock if it is not held, extending it every third of its TTL while it is held
+pub async fn try_acquire(
+    redis_conn: Arc<RedisConnectionPool>,
+    key: &str,
+    ttl: i64,
+) -> CustomResult<Option<Lock>, RedisError> {
+    let holder = utils::generate_id(consts::ID_LENGTH, "lock");
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(key, holder.as_str(), ttl)
+        .await?
+    {
+        SetnxReply::KeySet => {}
+        SetnxReply::KeyNotSet => return Ok(None),
+    }
+
+    let mut lock = Lock {
+        key: key.to_string(),
+        extension: extend_periodically(redis_conn.clone(), key.to_string(), holder.clone(), ttl),
+        holder,
+        fencing_token: 0,
+        redis_conn,
+        released: false,
+    };
+    // The lock is released on drop if the fencing token could not be assigned
+    lock.fencing_token = next_fencing_token(&lock.redis_conn, key)
+        .await
+        .attach_printable_lazy(|| format!("Failed to assign fencing token of lock {key}"))?;
+    logger::debug!(%key, fencing_token = lock.fencing_token, "Acquired lock");
+
+    Ok(Some(lock))
+}
+
+/// Acquire the lock, waiting for its current holder to release it for up to the configured number
+/// of attempts. `None` is returned if the lock is still held after the last attempt.
+pub async fn acquire(
+    redis_conn: Arc<RedisConnectionPool>,
+    key: &str,
+    settings: &LockSettings,
+) -> CustomResult<Option<Lock>, RedisError> {
+    for attempt in 1..=settings.max_attempts {
+        if let Some(lock) = try_acquire(redis_conn.clone(), key, settings.ttl).await? {
+            return Ok(Some(lock));
+        }
+        if attempt < settings.max_attempts {
+            tokio::time::sleep(Duration::from_millis(settings.retry_interval)).await;
+        }
+    }
+
+    logger::warn!(%key, attempts = settings.max_attempts, "Lock is still held by another holder");
+    Ok(None)
+}
+
+async fn next_fencing_token(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+) -> CustomResult<i64, RedisError> {
+    let fencing_key = get_fencing_key(key);
+    let fencing_token = redis_conn
+        .increment_hash_field(&fencing_key, FENCING_TOKEN_FIELD, 1)
+        .await?;
+    redis_conn
+        .set_expiry(&fencing_key, FENCING_TOKEN_TTL)
+        .await?;
+    Ok(fencing_token)
+}
+
+fn get_fencing_key(key: &str) -> String {
+    format!("{key}_fencing")
+}
+
+fn extend_periodically(
+    redis_conn: Arc<RedisConnectionPool>,
+    key: String,
+    holder: String,
+    ttl: i64,
+) -> tokio::task::JoinHandle<()> {
+    let period = Duration::from_secs(u64::try_from(ttl / 3).unwrap_or_default().max(1));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            match redis_conn
+                .set_expiry_if_value_matches(&key, &holder, ttl)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    logger::warn!(%key, "Lock expired before it could be extended");
+                    break;
+                }
+                Err(error) => logger::error!(?error, %key, "Failed to extend lock"),
+            }
+        }
+    })
+}
+
+async fn release_lock(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    holder: &str,
+) -> CustomResult<(), RedisError> {
+    if !redis_conn.delete_key_if_value_matches(key, holder).await? {
+        logger::warn!(%key, "Lock expired before it was released");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    async fn redis_conn() -> Arc<RedisConnectionPool> {
+        let conf = Settings::new().expect("invalid settings");
+        Arc::new(crate::connection::redis_connection(&conf).await)
+    }
+
+    fn lock_key() -> String {
+        format!("test_lock_{}", uuid::Uuid::new_v4())
+    }
+
+    /// Simulate the expiry of the lock, without waiting for its TTL
+    async fn expire(lock: &Lock) {
+        lock.redis_conn.delete_key(&lock.key).await.unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_acquire_assigns_increasing_fencing_tokens() {
+        let redis_conn = redis_conn().await;
+        let key = lock_key();
+
+        let first = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        let first_token = first.fencing_token();
+        first.release().await.unwrap();
+
+        let second = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(second.fencing_token() > first_token);
+        second.release().await.unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_held_lock_is_not_acquired() {
+        let redis_conn = redis_conn().await;
+        let key = lock_key();
+
+        let lock = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .is_none());
+
+        lock.release().await.unwrap();
+        assert!(try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[actix_rt::test]
+    async fn test_lock_is_extended_while_held() {
+        let redis_conn = redis_conn().await;
+        let key = lock_key();
+
+        let lock = try_acquire(redis_conn.clone(), &key, 3)
+            .await
+            .unwrap()
+            .unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        let holder = redis_conn.get_key::<Option<String>>(&key).await.unwrap();
+        assert_eq!(holder.as_deref(), Some(lock.holder.as_str()));
+        lock.release().await.unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_expired_lock_is_not_released_by_its_previous_holder() {
+        let redis_conn = redis_conn().await;
+        let key = lock_key();
+
+        let previous = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        expire(&previous).await;
+        let current = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+
+        previous.release().await.unwrap();
+        let holder = redis_conn.get_key::<Option<String>>(&key).await.unwrap();
+        assert_eq!(holder.as_deref(), Some(current.holder.as_str()));
+        current.release().await.unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn test_writes_of_expired_lock_are_fenced() {
+        let redis_conn = redis_conn().await;
+        let key = lock_key();
+
+        let previous = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(previous.fence_write().await.unwrap());
+        expire(&previous).await;
+        let current = try_acquire(redis_conn.clone(), &key, 10)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(current.fence_write().await.unwrap());
+        assert!(!previous.fence_write().await.unwrap());
+        current.release().await.unwrap();
+    }
+}
@@ -0,0 +1,306 @@
+//! Token bucket rate limits on the requests of each merchant and of each API key, held in Redis so
+//! that they are shared by all application instances.
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use serde::Deserialize;
+
+use crate::{
+    configs::settings::TokenBucketSettings,
+    core::errors::{self, CustomResult, RouterResult},
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    utils::StringExt,
+};
+
+/// Refills every bucket for the time elapsed since it was last refilled, and takes a token from
+/// every bucket if each of them has one left, so that a request rejected by one bucket does not
+/// use up the tokens of the others. Time is taken from the Redis server, so that the buckets are
+/// refilled alike whichever application instance checks them. Buckets expire once they would be
+/// full again, since a missing bucket is considered full.
+///
+/// `ARGV` holds the capacity and the refill rate of each bucket in `KEYS`. Returns 0 if the tokens
+/// were taken, or the position of the first empty bucket otherwise.
+const TOKEN_BUCKETS_SCRIPT: &str = r#"
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local buckets = {}
+local empty_bucket = 0
+for index, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[index * 2 - 1])
+    local refill_rate = tonumber(ARGV[index * 2])
+    local bucket = redis.call("HMGET", key, "tokens", "refilled_at")
+    local tokens = tonumber(bucket[1]) or capacity
+    local refilled_at = tonumber(bucket[2]) or now
+    tokens = math.min(capacity, tokens + math.max(0, now - refilled_at) * refill_rate / 1000)
+    if tokens < 1 and empty_bucket == 0 then
+        empty_bucket = index
+    end
+    buckets[index] = { tokens = tokens, capacity = capacity, refill_rate = refill_rate }
+end
+for index, key in ipairs(KEYS) do
+    local bucket = buckets[index]
+    local tokens = bucket.tokens
+    if empty_bucket == 0 then
+        tokens = tokens - 1
+    end
+    redis.call("HSET", key, "tokens", tostring(tokens), "refilled_at", now)
+    redis.call("PEXPIRE", key, math.ceil(bucket.capacity * 1000 / bucket.refill_rate) + 1000)
+end
+return empty_bucket
+"#;
+
+/// Rate limits of a merchant, overriding the ones configured in the settings
+#[derive(Debug, Default, Deserialize)]
+struct RateLimitOverride {
+    merchant: Option<TokenBucketSettings>,
+    api_key: Option<TokenBucketSettings>,
+}
+
+fn get_rate_limit_key(merchant_id: &str) -> String {
+    format!("rate_limit_{merchant_id}")
+}
+
+/// Take a token from the buckets of the merchant and of the API key the request was authenticated
+/// with, rejecting the request if either of them is empty
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &AppState,
+    merchant_id: &str,
+    api_key: &str,
+) -> RouterResult<()> {
+    let settings = &state.conf.rate_limit;
+    if !settings.enabled {
+        return Ok(());
+    }
+
+    let rate_limit_override = get_rate_limit_override(&*state.store, merchant_id).await?;
+    // Only a hash of the API key is stored in Redis. The keys of the buckets have the merchant ID
+    // as their hash tag, so that both the buckets belong to the same Redis cluster slot.
+    let api_key_id = blake3::hash(api_key.as_bytes()).to_hex();
+    let buckets = [
+        (
+            format!("token_bucket_{{{merchant_id}}}_api_key_{api_key_id}"),
+            rate_limit_override.api_key.unwrap_or(settings.api_key),
+        ),
+        (
+            format!("token_bucket_{{{merchant_id}}}_merchant"),
+            rate_limit_override.merchant.unwrap_or(settings.merchant),
+        ),
+    ];
+
+    match take_tokens(&state.store.get_redis_conn(), &buckets).await {
+        Ok(None) => Ok(()),
+        Ok(Some(key)) => Err(report!(errors::ApiErrorResponse::TooManyRequests))
+            .attach_printable_lazy(|| format!("Token bucket {key} is empty")),
+        // Requests are not rejected because the rate limit could not be checked
+        Err(error) => {
+            logger::error!(?error, "Failed to check rate limit");
+            Ok(())
+        }
+    }
+}
+
+#[instrument(skip_all)]
+async fn get_rate_limit_override(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<RateLimitOverride> {
+    let config = match db
+        .find_config_by_key_cached(&get_rate_limit_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(RateLimitOverride::default())
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the rate limit of the merchant")
+        }
+    };
+
+    parse_rate_limit_override(config.config)
+}
+
+fn parse_rate_limit_override(config: String) -> RouterResult<RateLimitOverride> {
+    let rate_limit_override: RateLimitOverride = config
+        .parse_struct("RateLimitOverride")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid rate limit configured for the merchant")?;
+
+    let is_invalid = |bucket: Option<TokenBucketSettings>| {
+        bucket.map_or(false, |bucket| {
+            bucket.capacity == 0 || bucket.refill_rate == 0
+        })
+    };
+    if is_invalid(rate_limit_override.merchant) || is_invalid(rate_limit_override.api_key) {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+            "Rate limit configured for the merchant must have a non-zero capacity and refill rate",
+        );
+    }
+
+    Ok(rate_limit_override)
+}
+
+/// Take a token from every bucket, returning the key of the first empty bucket if any of them is
+/// empty, in which case no token is taken
+async fn take_tokens(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    buckets: &[(String, TokenBucketSettings)],
+) -> CustomResult<Option<String>, errors::RedisError> {
+    let keys = buckets
+        .iter()
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+    let args = buckets
+        .iter()
+        .flat_map(|(_, bucket)| [bucket.capacity.to_string(), bucket.refill_rate.to_string()])
+        .collect();
+
+    let empty_bucket = redis_conn
+        .evaluate_script::<i64, _>(TOKEN_BUCKETS_SCRIPT, keys, args)
+        .await?;
+
+    Ok(usize::try_from(empty_bucket)
+        .ok()
+        .and_then(|position| position.checked_sub(1))
+        .and_then(|index| buckets.get(index))
+        .map(|(key, _)| key.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::time::Duration;
+
+    use super::*;
+    use crate::configs::settings::Settings;
+
+    fn bucket_key(merchant_id: &str, name: &str) -> String {
+        format!("token_bucket_{{{merchant_id}}}_{name}")
+    }
+
+    #[actix_rt::test]
+    async fn test_bucket_allows_a_burst_up_to_its_capacity() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let merchant_id = uuid::Uuid::new_v4().to_string();
+        let buckets = [(
+            bucket_key(&merchant_id, "merchant"),
+            TokenBucketSettings {
+                capacity: 3,
+                refill_rate: 1,
+            },
+        )];
+
+        for _ in 0..3 {
+            assert_eq!(take_tokens(&redis_conn, &buckets).await.unwrap(), None);
+        }
+        assert_eq!(
+            take_tokens(&redis_conn, &buckets).await.unwrap(),
+            Some(buckets[0].0.clone())
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_bucket_is_refilled_over_time() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let merchant_id = uuid::Uuid::new_v4().to_string();
+        let buckets = [(
+            bucket_key(&merchant_id, "merchant"),
+            TokenBucketSettings {
+                capacity: 1,
+                refill_rate: 10,
+            },
+        )];
+
+        assert_eq!(take_tokens(&redis_conn, &buckets).await.unwrap(), None);
+        assert!(take_tokens(&redis_conn, &buckets).await.unwrap().is_some());
+
+        // A token is refilled every 100 milliseconds
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(take_tokens(&redis_conn, &buckets).await.unwrap(), None);
+    }
+
+    #[actix_rt::test]
+    async fn test_rejected_request_does_not_use_up_other_buckets() {
+        let conf = Settings::new().expect("invalid settings");
+        let redis_conn = crate::connection::redis_connection(&conf).await;
+        let merchant_id = uuid::Uuid::new_v4().to_string();
+        let api_key_bucket = (
+            bucket_key(&merchant_id, "api_key"),
+            TokenBucketSettings {
+                capacity: 1,
+                refill_rate: 1,
+            },
+        );
+        let merchant_bucket = (
+            bucket_key(&merchant_id, "merchant"),
+            TokenBucketSettings {
+                capacity: 1,
+                refill_rate: 1,
+            },
+        );
+
+        // The merchant bucket is emptied by a request made with another API key
+        assert_eq!(
+            take_tokens(&redis_conn, &[merchant_bucket.clone()])
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            take_tokens(
+                &redis_conn,
+                &[api_key_bucket.clone(), merchant_bucket.clone()]
+            )
+            .await
+            .unwrap(),
+            Some(merchant_bucket.0.clone())
+        );
+
+        // The token of the API key bucket was not taken by the rejected request
+        assert_eq!(
+            take_tokens(&redis_conn, &[api_key_bucket]).await.unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_override_of_both_buckets_is_parsed() {
+        let rate_limit_override = parse_rate_limit_override(
+            r#"{"merchant":{"capacity":100,"refill_rate":10},"api_key":{"capacity":20,"refill_rate":2}}"#
+                .to_string(),
+        )
+        .unwrap();
+
+        let merchant = rate_limit_override.merchant.unwrap();
+        assert_eq!((merchant.capacity, merchant.refill_rate), (100, 10));
+        let api_key = rate_limit_override.api_key.unwrap();
+        assert_eq!((api_key.capacity, api_key.refill_rate), (20, 2));
+    }
+
+    #[test]
+    fn test_override_of_one_bucket_keeps_the_other_default() {
+        let rate_limit_override =
+            parse_rate_limit_override(r#"{"merchant":{"capacity":5,"refill_rate":1}}"#.to_string())
+                .unwrap();
+
+        assert!(rate_limit_override.merchant.is_some());
+        assert!(rate_limit_override.api_key.is_none());
+    }
+
+    #[test]
+    fn test_invalid_override_is_rejected() {
+        assert!(parse_rate_limit_override(r#"{"merchant":5}"#.to_string()).is_err());
+        assert!(parse_rate_limit_override(
+            r#"{"api_key":{"capacity":5,"refill_rate":0}}"#.to_string()
+        )
+        .is_err());
+    }
+}
@@ -10,7 +10,7 @@ pub mod api;
 pub mod storage;
 pub mod transformers;
 
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 pub use api_models::enums::Connector;
 use common_utils::pii::Email;
@@ -26,11 +26,22 @@ pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, Payme
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type AcceptDisputeRouterData =
+    RouterData<api::Accept, AcceptDisputeRequestData, DisputeResponseData>;
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, DisputeResponseData>;
+pub type UploadFileRouterData = RouterData<api::Upload, UploadFileRequestData, UploadFileResponse>;
 
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -45,9 +56,20 @@ pub type PaymentsSessionResponseRouterData<R> =
     ResponseRouterData<api::Session, R, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<api::Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    api::IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+pub type DisputesResponseRouterData<F, Req, R> = ResponseRouterData<F, R, Req, DisputeResponseData>;
+pub type UploadFileResponseRouterData<R> =
+    ResponseRouterData<api::Upload, R, UploadFileRequestData, UploadFileResponse>;
 
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -59,12 +81,37 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 
 pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
 
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+pub type PayoutReverseType =
+    dyn services::ConnectorIntegration<api::PoReverse, PayoutsData, PayoutsResponseData>;
+
+pub type AcceptDisputeType =
+    dyn services::ConnectorIntegration<api::Accept, AcceptDisputeRequestData, DisputeResponseData>;
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    DisputeResponseData,
+>;
+
+pub type UploadFileType =
+    dyn services::ConnectorIntegration<api::Upload, UploadFileRequestData, UploadFileResponse>;
+
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -115,6 +162,11 @@ pub struct PaymentsAuthorizeData {
     pub setup_mandate_details: Option<payments::MandateData>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Whether the authorized amount may be increased later on with incremental authorizations
+    pub request_incremental_authorization: bool,
+    /// Result of a 3DS authentication performed outside of the router, to be forwarded to the
+    /// connector
+    pub external_authentication_details: Option<payments::AuthenticationDetails>,
 }
 
 #[derive(Debug, Clone)]
@@ -123,6 +175,23 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    /// Identifies the capture among the captures of the payment
+    pub capture_id: String,
+    /// Whether no more captures will be made from the payment after this one
+    pub final_capture: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// Total amount authorized once the increment succeeds
+    pub total_amount: i64,
+    /// Amount added to the amount authorized so far
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector_transaction_id: String,
+    pub reason: Option<String>,
+    /// Identifies the incremental authorization among the ones of the payment
+    pub authorization_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -131,6 +200,18 @@ pub struct PaymentsSyncData {
     pub connector_transaction_id: ResponseId,
     pub encoded_data: Option<String>,
     pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub sync_type: SyncRequestType,
+}
+
+/// What is synced with the connector by a payment sync
+#[derive(Debug, Clone, Default)]
+pub enum SyncRequestType {
+    /// The captures of the payment, referenced by their connector capture IDs. Only requested
+    /// from connectors which support multiple captures.
+    MultipleCaptureSync(Vec<String>),
+    /// The payment as a whole
+    #[default]
+    SinglePaymentSync,
 }
 
 #[derive(Debug, Clone)]
@@ -184,10 +265,19 @@ pub enum PaymentsResponseData {
         redirect: bool,
         mandate_reference: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        /// Result of the 3DS authentication of the customer, as reported by the connector
+        authentication_details: Option<payments::AuthenticationDetails>,
+        /// Action other than a redirection the customer has to take to complete the payment, such
+        /// as scanning a QR code or completing the payment in the SDK of a wallet
+        next_action_data: Option<payments::NextActionData>,
     },
     SessionResponse {
         session_token: api::SessionToken,
     },
+    /// Status of the captures of the payment, keyed by their connector capture IDs
+    MultipleCaptureResponse {
+        captures: HashMap<String, storage_enums::CaptureStatus>,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -250,6 +340,60 @@ pub struct RefundsResponseData {
     // pub amount_received: Option<i32>, // Calculation for amount received not in place yet
 }
 
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    /// Details of the instrument the payout is made to, only available while confirming
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// Identifier returned by the connector when the payout was created
+    pub connector_payout_id: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    /// Evidence provided by the merchant to challenge the dispute
+    pub evidence: api::SubmitEvidenceRequest,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisputeResponseData {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFileRequestData {
+    pub file_key: String,
+    pub file: Vec<u8>,
+    pub file_type: mime::Mime,
+    pub file_size: i32,
+    pub purpose: api::FilePurpose,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFileResponse {
+    pub provider_file_id: String,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Redirection {
     Redirect,
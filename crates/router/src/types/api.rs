@@ -2,10 +2,13 @@ pub mod admin;
 pub mod api_keys;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod enums;
+pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
 pub mod webhooks;
 
@@ -14,8 +17,8 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
-    webhooks::*,
+    admin::*, api_keys::*, configs::*, customers::*, disputes::*, files::*, payment_methods::*,
+    payments::*, payouts::*, refunds::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -70,6 +73,25 @@ pub trait ConnectorCommon {
             reason: None,
         })
     }
+
+    /// Whether a payment which failed with the given error code may succeed if it is retried
+    /// with another connector, such as when the connector or the issuer is temporarily
+    /// unavailable. The error code is the `code` of the error response built by the connector.
+    fn is_retryable_error_code(&self, _error_code: &str) -> bool {
+        false
+    }
+
+    /// Whether the connector reports the status of each capture of a payment, so that the payment
+    /// can be captured more than once and each capture synced separately
+    fn supports_multiple_captures(&self) -> bool {
+        false
+    }
+
+    /// How long an authorization with the connector remains valid before the held funds are
+    /// released by the issuer, if the payment is not captured
+    fn get_authorization_validity(&self) -> time::Duration {
+        time::Duration::days(7)
+    }
 }
 
 /// Extended trait for connector common to allow functions with generic type
@@ -89,7 +111,16 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook + ConnectorAccessToken
+    Send
+    + Refund
+    + Payment
+    + Payouts
+    + Dispute
+    + FileUpload
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
 {
 }
 
@@ -100,6 +131,9 @@ pub struct Pe;
 impl<
         T: Refund
             + Payment
+            + Payouts
+            + Dispute
+            + FileUpload
             + Debug
             + ConnectorRedirectResponse
             + Send
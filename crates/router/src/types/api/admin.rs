@@ -1,8 +1,8 @@
 pub use api_models::admin::{
     CreateMerchantAccount, DeleteMcaResponse, DeleteMerchantAccountResponse,
     MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
-    PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, ToggleKVRequest, ToggleKVResponse,
-    WebhookDetails,
+    PaymentCapturePolicy, PaymentConnectorCreate, PaymentMethods, PaymentRetryPolicy,
+    RoutingAlgorithm, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
 
 use crate::types::{storage, transformers::Foreign};
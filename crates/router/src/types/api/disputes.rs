@@ -0,0 +1,24 @@
+pub use api_models::disputes::{
+    DisputeListConstraints, DisputeListResponse, DisputePayload, DisputeResponse,
+    SubmitEvidenceRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct Accept;
+#[derive(Debug, Clone)]
+pub struct Evidence;
+
+pub trait AcceptDispute:
+    api::ConnectorIntegration<Accept, types::AcceptDisputeRequestData, types::DisputeResponseData>
+{
+}
+
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::DisputeResponseData>
+{
+}
+
+pub trait Dispute: ConnectorCommon + AcceptDispute + SubmitEvidence {}
@@ -0,0 +1,25 @@
+pub use api_models::{enums::FilePurpose, files::CreateFileResponse};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct CreateFileRequest {
+    pub file: Vec<u8>,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: mime::Mime,
+    pub purpose: FilePurpose,
+    /// The dispute for which the file is submitted as evidence, if any
+    pub dispute_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Upload;
+
+pub trait UploadFile:
+    api::ConnectorIntegration<Upload, types::UploadFileRequestData, types::UploadFileResponse>
+{
+}
+
+pub trait FileUpload: ConnectorCommon + UploadFile {}
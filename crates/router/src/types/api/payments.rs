@@ -1,13 +1,14 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationDetails,
+    AuthenticationForStartResponse, CaptureResponse, Card, CustomerAcceptance, MandateData,
+    MandateTxnType, MandateType, MandateValidationFields, NextAction, NextActionData,
+    NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
     PaymentListResponse, PaymentMethod, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
-    PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsIncrementalAuthorizationRequest,
+    PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse,
+    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
+    PaymentsStartRequest, PgRedirectResponse, PhoneDetails, QrCodeInformation, RedirectionResponse,
+    SdkClientInformation, SessionToken, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -71,6 +72,8 @@ pub struct Capture;
 pub struct PSync;
 #[derive(Debug, Clone)]
 pub struct Void;
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorization;
 
 #[derive(Debug, Clone)]
 pub struct Session;
@@ -154,6 +157,15 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentIncrementalAuthorization:
+    api::ConnectorIntegration<
+    IncrementalAuthorization,
+    types::PaymentsIncrementalAuthorizationData,
+    types::PaymentsResponseData,
+>
+{
+}
+
 pub trait PaymentSession:
     api::ConnectorIntegration<Session, types::PaymentsSessionData, types::PaymentsResponseData>
 {
@@ -170,6 +182,7 @@ pub trait Payment:
     + PaymentSync
     + PaymentCapture
     + PaymentVoid
+    + PaymentIncrementalAuthorization
     + PreVerify
     + PaymentSession
 {
@@ -0,0 +1,42 @@
+pub use api_models::payouts::{
+    BankPayout, CardPayout, ConnectedAccountPayout, PayoutAccount, PayoutAccountsResponse,
+    PayoutConfirmRequest, PayoutCreateRequest, PayoutListConstraints, PayoutListResponse,
+    PayoutMethodData, PayoutResponse, PayoutUpdateRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+#[derive(Debug, Clone)]
+pub struct PoReverse;
+
+pub trait PayoutCreate:
+    api::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutReverse:
+    api::ConnectorIntegration<PoReverse, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait Payouts:
+    ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutCancel + PayoutReverse
+{
+}
@@ -136,6 +136,13 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError>;
 
+    fn get_dispute_details(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<super::disputes::DisputePayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("get_dispute_details method".to_string()).into())
+    }
+
     fn get_webhook_api_response(
         &self,
     ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
@@ -1,11 +1,15 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
+pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod file;
+pub mod incremental_authorization;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,6 +17,7 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod reverse_lookup;
 
@@ -23,8 +28,8 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, api_keys::*, configs::*, connector_response::*, customers::*, events::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    address::*, api_keys::*, capture::*, configs::*, connector_response::*, customers::*,
+    dispute::*, events::*, file::*, incremental_authorization::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
+    payment_method::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
 };
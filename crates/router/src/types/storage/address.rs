@@ -1 +1,4 @@
 pub use storage_models::address::{Address, AddressNew, AddressUpdate, AddressUpdateInternal};
+
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for Address {}
@@ -2,3 +2,6 @@ pub use storage_models::connector_response::{
     ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate,
     ConnectorResponseUpdateInternal,
 };
+
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for ConnectorResponse {}
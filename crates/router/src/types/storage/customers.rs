@@ -1,3 +1,6 @@
 pub use storage_models::customers::{
     Customer, CustomerNew, CustomerUpdate, CustomerUpdateInternal,
 };
+
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for Customer {}
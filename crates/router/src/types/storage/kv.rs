@@ -1,4 +1,5 @@
 pub use storage_models::kv::{
-    DBOperation, Insertable, PaymentAttemptUpdateMems, PaymentIntentUpdateMems, RefundUpdateMems,
-    TypedSql, Updateable,
+    AddressUpdateMems, ConnectorResponseUpdateMems, CustomerDeleteMems, CustomerUpdateMems,
+    DBOperation, Deletable, Insertable, MandateUpdateMems, PaymentAttemptUpdateMems,
+    PaymentIntentUpdateMems, RefundUpdateMems, TypedSql, Updateable,
 };
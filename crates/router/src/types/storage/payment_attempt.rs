@@ -1,5 +1,6 @@
 pub use storage_models::payment_attempt::{
     PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    ScheduledPaymentActionTrackingData,
 };
 
 #[cfg(feature = "kv_store")]
@@ -125,4 +126,67 @@ mod tests {
         // checking it after fetch
         assert_eq!(response.mandate_id, Some("man_121212".to_string()));
     }
+
+    #[actix_rt::test]
+    async fn test_payment_attempt_next_action_data_cleared_on_status_change() {
+        let conf = Settings::new().expect("invalid settings");
+        let state = routes::AppState::with_storage(conf, StorageImpl::Mock).await;
+        let next_action_data = serde_json::json!({
+            "type": "display_qr_code",
+            "image_data_url": null,
+            "qr_code_data": "upi://pay?pa=merchant@bank&pn=Merchant&am=65.40&cu=INR",
+            "expires_at": null
+        });
+
+        let payment_attempt = state
+            .store
+            .insert_payment_attempt(
+                PaymentAttemptNew {
+                    payment_id: Uuid::new_v4().to_string(),
+                    merchant_id: "1".to_string(),
+                    status: enums::AttemptStatus::AuthenticationPending,
+                    next_action_data: Some(next_action_data.clone()),
+                    ..PaymentAttemptNew::default()
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        // A sync which doesn't report the next action again keeps the one of the attempt
+        let payment_attempt = state
+            .store
+            .update_payment_attempt(
+                payment_attempt,
+                PaymentAttemptUpdate::ResponseUpdate {
+                    status: enums::AttemptStatus::AuthenticationPending,
+                    connector: None,
+                    connector_transaction_id: None,
+                    authentication_type: None,
+                    payment_method_id: None,
+                    redirect: None,
+                    mandate_id: None,
+                    connector_metadata: None,
+                    authentication_details: None,
+                    next_action_data: None,
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_attempt.next_action_data, Some(next_action_data));
+
+        let payment_attempt = state
+            .store
+            .update_payment_attempt(
+                payment_attempt,
+                PaymentAttemptUpdate::StatusUpdate {
+                    status: enums::AttemptStatus::Charged,
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(payment_attempt.next_action_data, None);
+    }
 }
@@ -1 +1,4 @@
 pub use storage_models::reverse_lookup::{ReverseLookup, ReverseLookupNew};
+
+#[cfg(feature = "kv_store")]
+impl crate::utils::storage_partitioning::KvStorePartition for ReverseLookup {}
@@ -190,9 +190,11 @@ impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::IntentStatus> {
             storage_enums::AttemptStatus::AuthenticationPending => {
                 storage_enums::IntentStatus::RequiresCustomerAction
             }
+            storage_enums::AttemptStatus::PartialCharged => {
+                storage_enums::IntentStatus::PartiallyCaptured
+            }
 
-            storage_enums::AttemptStatus::PartialCharged
-            | storage_enums::AttemptStatus::Started
+            storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -218,6 +220,12 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     fn try_from(value: F<api_enums::IntentStatus>) -> Result<Self, Self::Error> {
         match value.0 {
             api_enums::IntentStatus::Succeeded => Ok(storage_enums::EventType::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(storage_enums::EventType::PaymentFailed),
+            api_enums::IntentStatus::Processing => Ok(storage_enums::EventType::PaymentProcessing),
+            api_enums::IntentStatus::Cancelled => Ok(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::RequiresCustomerAction => {
+                Ok(storage_enums::EventType::ActionRequired)
+            }
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -226,6 +234,59 @@ impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     }
 }
 
+impl TryFrom<F<storage_enums::RefundStatus>> for F<storage_enums::EventType> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<storage_enums::RefundStatus>) -> Result<Self, Self::Error> {
+        match value.0 {
+            storage_enums::RefundStatus::Success => Ok(storage_enums::EventType::RefundSucceeded),
+            storage_enums::RefundStatus::Failure => Ok(storage_enums::EventType::RefundFailed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "refund_status",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl TryFrom<F<storage_enums::MandateStatus>> for F<storage_enums::EventType> {
+    type Error = errors::ValidationError;
+
+    fn try_from(value: F<storage_enums::MandateStatus>) -> Result<Self, Self::Error> {
+        match value.0 {
+            storage_enums::MandateStatus::Active => Ok(storage_enums::EventType::MandateActive),
+            storage_enums::MandateStatus::Revoked => Ok(storage_enums::EventType::MandateRevoked),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "mandate_status",
+            }),
+        }
+        .map(Into::into)
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<storage_enums::EventType> {
+    fn from(value: F<storage_enums::DisputeStatus>) -> Self {
+        match value.0 {
+            storage_enums::DisputeStatus::DisputeOpened => storage_enums::EventType::DisputeOpened,
+            storage_enums::DisputeStatus::DisputeExpired => {
+                storage_enums::EventType::DisputeExpired
+            }
+            storage_enums::DisputeStatus::DisputeAccepted => {
+                storage_enums::EventType::DisputeAccepted
+            }
+            storage_enums::DisputeStatus::DisputeCancelled => {
+                storage_enums::EventType::DisputeCancelled
+            }
+            storage_enums::DisputeStatus::DisputeChallenged => {
+                storage_enums::EventType::DisputeChallenged
+            }
+            storage_enums::DisputeStatus::DisputeWon => storage_enums::EventType::DisputeWon,
+            storage_enums::DisputeStatus::DisputeLost => storage_enums::EventType::DisputeLost,
+        }
+        .into()
+    }
+}
+
 impl From<F<storage_enums::EventType>> for F<api_enums::EventType> {
     fn from(event_type: F<storage_enums::EventType>) -> Self {
         Self(frunk::labelled_convert_from(event_type.0))
@@ -250,6 +311,121 @@ impl From<F<storage_enums::RefundStatus>> for F<api_enums::RefundStatus> {
     }
 }
 
+impl From<F<storage_enums::PayoutStatus>> for F<api_enums::PayoutStatus> {
+    fn from(status: F<storage_enums::PayoutStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::PayoutType>> for F<storage_enums::PayoutType> {
+    fn from(payout_type: F<api_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutType>> for F<api_enums::PayoutType> {
+    fn from(payout_type: F<storage_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStage>> for F<storage_enums::DisputeStage> {
+    fn from(dispute_stage: F<api_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStage>> for F<api_enums::DisputeStage> {
+    fn from(dispute_stage: F<storage_enums::DisputeStage>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_stage.0))
+    }
+}
+
+impl From<F<api_enums::DisputeStatus>> for F<storage_enums::DisputeStatus> {
+    fn from(dispute_status: F<api_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<api_enums::DisputeStatus> {
+    fn from(dispute_status: F<storage_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(dispute_status.0))
+    }
+}
+
+impl From<F<storage_enums::CaptureStatus>> for F<api_enums::CaptureStatus> {
+    fn from(capture_status: F<storage_enums::CaptureStatus>) -> Self {
+        Self(frunk::labelled_convert_from(capture_status.0))
+    }
+}
+
+/// Status of a capture, from the status of the payment attempt reported by the connector in
+/// response to the capture
+impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::CaptureStatus> {
+    fn from(status: F<storage_enums::AttemptStatus>) -> Self {
+        match status.0 {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::AutoRefunded => storage_enums::CaptureStatus::Charged,
+            storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::VoidFailed => storage_enums::CaptureStatus::Failed,
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::Pending
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited => {
+                storage_enums::CaptureStatus::Pending
+            }
+        }
+        .into()
+    }
+}
+
+/// Status of an incremental authorization, from the status of the payment attempt reported by the
+/// connector in response to the incremental authorization
+impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::AuthorizationStatus> {
+    fn from(status: F<storage_enums::AttemptStatus>) -> Self {
+        match status.0 {
+            storage_enums::AttemptStatus::Authorized => storage_enums::AuthorizationStatus::Success,
+            storage_enums::AttemptStatus::Authorizing | storage_enums::AttemptStatus::Pending => {
+                storage_enums::AuthorizationStatus::Processing
+            }
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited => {
+                storage_enums::AuthorizationStatus::Failure
+            }
+        }
+        .into()
+    }
+}
+
 impl From<F<api_enums::CaptureMethod>> for F<storage_enums::CaptureMethod> {
     fn from(capture_method: F<api_enums::CaptureMethod>) -> Self {
         Self(frunk::labelled_convert_from(capture_method.0))
@@ -316,6 +492,25 @@ impl From<F<storage::Config>> for F<api_types::Config> {
     }
 }
 
+impl From<F<storage::Capture>> for F<api_types::CaptureResponse> {
+    fn from(capture: F<storage::Capture>) -> Self {
+        let capture = capture.0;
+        api_types::CaptureResponse {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.to_string(),
+            connector: capture.connector,
+            connector_capture_id: capture.connector_capture_id,
+            final_capture: capture.final_capture,
+            error_message: capture.error_message,
+            error_code: capture.error_code,
+            created_at: capture.created_at,
+        }
+        .into()
+    }
+}
+
 impl<'a> From<F<&'a api_types::ConfigUpdate>> for F<storage::ConfigUpdate> {
     fn from(config: F<&api_types::ConfigUpdate>) -> Self {
         let config_update = config.0;
@@ -1,3 +1,6 @@
+#[cfg(feature = "kv_store")]
+use error_stack::IntoReport;
+
 use crate::{core::errors, routes::metrics};
 
 #[cfg(feature = "kv_store")]
@@ -33,3 +36,27 @@ where
         },
     }
 }
+
+#[cfg(feature = "kv_store")]
+/// Fails with a duplicate value error if the database call finds the row. Rows written through the
+/// KV store may have been drained to the database without being present in Redis, so inserts into
+/// the KV store check the database as well.
+pub async fn check_database_absence<F, DFut, T>(
+    entity: &'static str,
+    key: &str,
+    database_call_closure: F,
+) -> errors::CustomResult<(), errors::StorageError>
+where
+    F: FnOnce() -> DFut,
+    DFut: futures::Future<Output = errors::CustomResult<T, errors::StorageError>>,
+{
+    match database_call_closure().await {
+        Ok(_) => Err(errors::StorageError::DuplicateValue {
+            entity,
+            key: Some(key.to_owned()),
+        })
+        .into_report(),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
@@ -13,6 +13,17 @@ pub(crate) enum PartitionKey<'a> {
         merchant_id: &'a str,
         payment_id: &'a str,
     },
+    MerchantIdCustomerId {
+        merchant_id: &'a str,
+        customer_id: &'a str,
+    },
+    MerchantIdMandateId {
+        merchant_id: &'a str,
+        mandate_id: &'a str,
+    },
+    LookupId {
+        lookup_id: &'a str,
+    },
 }
 
 impl<'a> std::fmt::Display for PartitionKey<'a> {
@@ -22,6 +33,15 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
                 merchant_id,
                 payment_id,
             } => f.write_str(&format!("mid_{merchant_id}_pid_{payment_id}")),
+            PartitionKey::MerchantIdCustomerId {
+                merchant_id,
+                customer_id,
+            } => f.write_str(&format!("mid_{merchant_id}_cid_{customer_id}")),
+            PartitionKey::MerchantIdMandateId {
+                merchant_id,
+                mandate_id,
+            } => f.write_str(&format!("mid_{merchant_id}_mandate_{mandate_id}")),
+            PartitionKey::LookupId { lookup_id } => f.write_str(&format!("lookup_{lookup_id}")),
         }
     }
 }
@@ -50,6 +50,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             browser_info: None,
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
+            external_authentication_details: None,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
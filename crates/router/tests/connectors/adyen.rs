@@ -78,6 +78,8 @@ impl AdyenTest {
             browser_info: None,
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
+            external_authentication_details: None,
         })
     }
 }
@@ -124,6 +124,7 @@ async fn should_sync_payment() {
                 ),
                 encoded_data: None,
                 capture_method: None,
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
@@ -72,6 +72,7 @@ async fn should_authorize_card_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -103,6 +104,7 @@ async fn should_authorize_gpay_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -137,6 +139,7 @@ async fn should_capture_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -157,6 +160,7 @@ async fn should_capture_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -194,6 +198,7 @@ async fn should_sync_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -238,6 +243,7 @@ async fn should_void_already_authorized_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -273,6 +279,7 @@ async fn should_refund_succeeded_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -295,6 +302,7 @@ async fn should_refund_succeeded_payment() {
                     ),
                     encoded_data: None,
                     capture_method: None,
+                    sync_type: types::SyncRequestType::SinglePaymentSync,
                 }),
                 get_default_payment_info(),
             )
@@ -383,7 +383,8 @@ pub trait ConnectorActions: Connector {
             Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
                 resource_id.get_connector_transaction_id().ok()
             }
-            Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
+            Ok(types::PaymentsResponseData::SessionResponse { .. })
+            | Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
             Err(_) => None,
         }
     }
@@ -469,6 +470,8 @@ impl Default for PaymentAuthorizeType {
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
+            external_authentication_details: None,
         };
         Self(data)
     }
@@ -481,6 +484,8 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            capture_id: "cap_test".to_string(),
+            final_capture: true,
         })
     }
 }
@@ -520,6 +525,7 @@ impl Default for PaymentSyncType {
             ),
             encoded_data: None,
             capture_method: None,
+            sync_type: types::SyncRequestType::SinglePaymentSync,
         };
         Self(data)
     }
@@ -548,7 +554,8 @@ pub fn get_connector_transaction_id(
         Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
             resource_id.get_connector_transaction_id().ok()
         }
-        Ok(types::PaymentsResponseData::SessionResponse { .. }) => None,
+        Ok(types::PaymentsResponseData::SessionResponse { .. })
+        | Ok(types::PaymentsResponseData::MultipleCaptureResponse { .. }) => None,
         Err(_) => None,
     }
 }
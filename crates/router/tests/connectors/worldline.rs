@@ -80,6 +80,8 @@ impl WorldlineTest {
             browser_info: None,
             order_details: None,
             email: None,
+            request_incremental_authorization: false,
+            external_authentication_details: None,
         })
     }
 }
@@ -205,6 +207,7 @@ async fn should_sync_manual_auth_payment() {
                 ),
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Manual),
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
@@ -238,6 +241,7 @@ async fn should_sync_auto_auth_payment() {
                 ),
                 encoded_data: None,
                 capture_method: Some(enums::CaptureMethod::Automatic),
+                sync_type: types::SyncRequestType::SinglePaymentSync,
             }),
             None,
         )
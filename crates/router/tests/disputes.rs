@@ -0,0 +1,193 @@
+#![allow(clippy::unwrap_used)]
+
+use router::{
+    configs::settings::Settings,
+    core::webhooks,
+    db::{
+        dispute::DisputeInterface, payment_attempt::PaymentAttemptInterface, MockDb,
+        StorageInterface,
+    },
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+fn mk_dispute_payload(connector_status: &str) -> api::DisputePayload {
+    api::DisputePayload {
+        amount: "1000".to_string(),
+        currency: "USD".to_string(),
+        dispute_stage: api_models::enums::DisputeStage::Dispute,
+        connector_status: connector_status.to_string(),
+        connector_dispute_id: "du_1MtJUT2eZvKYlo2CNaw2HvEv".to_string(),
+        connector_reason: Some("fraudulent".to_string()),
+        connector_reason_code: None,
+        challenge_required_by: None,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+#[actix_web::test]
+async fn dispute_webhook_events_are_opt_in() {
+    let conf = Settings::new().unwrap();
+    let db = MockDb::new(&conf).await;
+    let merchant_id = format!("merchant_{}", uuid::Uuid::new_v4().simple());
+
+    assert!(
+        webhooks::utils::lookup_webhook_event(
+            &db,
+            "stripe",
+            &merchant_id,
+            &api::IncomingWebhookEvent::PaymentIntentSuccess,
+        )
+        .await
+    );
+    assert!(
+        !webhooks::utils::lookup_webhook_event(
+            &db,
+            "stripe",
+            &merchant_id,
+            &api::IncomingWebhookEvent::DisputeOpened,
+        )
+        .await
+    );
+
+    let redis_key = format!("whconf_{merchant_id}_stripe");
+    let redis_conn = db.get_redis_conn();
+    redis_conn
+        .serialize_and_set_key(&redis_key, serde_json::json!(["dispute_opened"]))
+        .await
+        .unwrap();
+
+    assert!(
+        webhooks::utils::lookup_webhook_event(
+            &db,
+            "stripe",
+            &merchant_id,
+            &api::IncomingWebhookEvent::DisputeOpened,
+        )
+        .await
+    );
+    assert!(
+        !webhooks::utils::lookup_webhook_event(
+            &db,
+            "stripe",
+            &merchant_id,
+            &api::IncomingWebhookEvent::DisputeLost,
+        )
+        .await
+    );
+    redis_conn.delete_key(&redis_key).await.unwrap();
+}
+
+#[actix_web::test]
+async fn dispute_webhook_inserts_then_updates_the_dispute() {
+    let conf = Settings::new().unwrap();
+    let db = MockDb::new(&conf).await;
+    let payment_attempt = db
+        .insert_payment_attempt(
+            storage::PaymentAttemptNew {
+                payment_id: "pay_dispute_webhook".to_string(),
+                merchant_id: "merchant_dispute_webhook".to_string(),
+                attempt_id: "pay_dispute_webhook_1".to_string(),
+                connector_transaction_id: Some("pi_dispute_webhook".to_string()),
+                ..Default::default()
+            },
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .unwrap();
+
+    let (opened, is_status_changed) = webhooks::upsert_dispute(
+        &db,
+        &payment_attempt,
+        mk_dispute_payload("needs_response"),
+        enums::DisputeStatus::DisputeOpened,
+        "stripe",
+    )
+    .await
+    .unwrap();
+    assert!(is_status_changed);
+    assert_eq!(opened.dispute_status, enums::DisputeStatus::DisputeOpened);
+    assert_eq!(opened.payment_id, payment_attempt.payment_id);
+    assert_eq!(opened.attempt_id, payment_attempt.attempt_id);
+    assert_eq!(opened.merchant_id, payment_attempt.merchant_id);
+    assert_eq!(opened.connector, "stripe");
+    assert_eq!(opened.connector_status, "needs_response");
+
+    let (challenged, is_status_changed) = webhooks::upsert_dispute(
+        &db,
+        &payment_attempt,
+        mk_dispute_payload("under_review"),
+        enums::DisputeStatus::DisputeChallenged,
+        "stripe",
+    )
+    .await
+    .unwrap();
+    assert!(is_status_changed);
+    assert_eq!(challenged.dispute_id, opened.dispute_id);
+    assert_eq!(
+        challenged.dispute_status,
+        enums::DisputeStatus::DisputeChallenged
+    );
+    assert_eq!(challenged.connector_status, "under_review");
+
+    // A redelivered webhook updates the dispute without changing its status
+    let (redelivered, is_status_changed) = webhooks::upsert_dispute(
+        &db,
+        &payment_attempt,
+        mk_dispute_payload("under_review"),
+        enums::DisputeStatus::DisputeChallenged,
+        "stripe",
+    )
+    .await
+    .unwrap();
+    assert!(!is_status_changed);
+    assert_eq!(redelivered.dispute_id, opened.dispute_id);
+
+    let stored = db
+        .find_dispute_by_merchant_id_dispute_id(&payment_attempt.merchant_id, &opened.dispute_id)
+        .await
+        .unwrap();
+    assert_eq!(stored, redelivered);
+}
+
+#[test]
+fn dispute_status_from_webhook_event() {
+    let statuses = [
+        (
+            api::IncomingWebhookEvent::DisputeOpened,
+            enums::DisputeStatus::DisputeOpened,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeExpired,
+            enums::DisputeStatus::DisputeExpired,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeAccepted,
+            enums::DisputeStatus::DisputeAccepted,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeCancelled,
+            enums::DisputeStatus::DisputeCancelled,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeChallenged,
+            enums::DisputeStatus::DisputeChallenged,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeWon,
+            enums::DisputeStatus::DisputeWon,
+        ),
+        (
+            api::IncomingWebhookEvent::DisputeLost,
+            enums::DisputeStatus::DisputeLost,
+        ),
+    ];
+
+    for (event, status) in statuses {
+        assert_eq!(webhooks::get_dispute_status(event).unwrap(), status);
+    }
+    assert!(webhooks::get_dispute_status(api::IncomingWebhookEvent::PaymentIntentSuccess).is_err());
+}
@@ -0,0 +1,266 @@
+#![allow(clippy::unwrap_used)]
+
+use actix_http::{body::MessageBody, Request};
+use actix_web::{
+    dev::{Service, ServiceResponse},
+    http::StatusCode,
+    test::{call_service, init_service, read_body, read_body_json, TestRequest},
+};
+use router::{
+    configs::settings::Settings,
+    db::{merchant_account::MerchantAccountInterface, StorageImpl},
+    routes::AppState,
+    types::storage,
+};
+
+const BOUNDARY: &str = "router-files-test-boundary";
+const API_KEY: &str = "files_test_api_key";
+const MAX_FILE_SIZE: usize = 32;
+
+/// A field of a multipart request, along with the file name and content type of file fields
+struct Part<'a> {
+    name: &'a str,
+    file: Option<(&'a str, &'a str)>,
+    data: &'a [u8],
+}
+
+fn text_part<'a>(name: &'a str, value: &'a str) -> Part<'a> {
+    Part {
+        name,
+        file: None,
+        data: value.as_bytes(),
+    }
+}
+
+fn file_part<'a>(file_name: &'a str, content_type: &'a str, data: &'a [u8]) -> Part<'a> {
+    Part {
+        name: "file",
+        file: Some((file_name, content_type)),
+        data,
+    }
+}
+
+fn mk_multipart(parts: &[Part<'_>]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for part in parts {
+        body.extend_from_slice(format!("--{BOUNDARY}\r\n").as_bytes());
+        match part.file {
+            Some((file_name, content_type)) => body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{file_name}\"\r\nContent-Type: {content_type}\r\n\r\n",
+                    part.name
+                )
+                .as_bytes(),
+            ),
+            None => body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                    part.name
+                )
+                .as_bytes(),
+            ),
+        }
+        body.extend_from_slice(part.data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+    body
+}
+
+/// App backed by the mock database, storing the uploaded files in a temporary directory, with a
+/// merchant account authenticated by [`API_KEY`]
+async fn mk_files_service(
+) -> impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = actix_web::Error> {
+    let mut conf = Settings::new().unwrap();
+    let request_body_limit = conf.server.request_body_limit;
+    conf.file_upload_config.file_system_path = std::env::temp_dir()
+        .join(format!(
+            "router_files_test_{}",
+            uuid::Uuid::new_v4().simple()
+        ))
+        .to_string_lossy()
+        .to_string();
+    conf.file_upload_config.max_file_size = MAX_FILE_SIZE;
+
+    let app_state = AppState::with_storage(conf, StorageImpl::Mock).await;
+    app_state
+        .store
+        .insert_merchant(storage::MerchantAccountNew {
+            merchant_id: format!("merchant_{}", uuid::Uuid::new_v4().simple()),
+            api_key: Some(masking::StrongSecret::new(API_KEY.to_string())),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    init_service(router::mk_app(app_state, request_body_limit)).await
+}
+
+async fn upload<S, B>(app: &S, parts: &[Part<'_>]) -> ServiceResponse<B>
+where
+    S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+{
+    let request = TestRequest::post()
+        .uri("/files")
+        .insert_header(("api-key", API_KEY))
+        .insert_header((
+            "Content-Type",
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        ))
+        .set_payload(mk_multipart(parts))
+        .to_request();
+    call_service(app, request).await
+}
+
+/// Validation failures report their cause in the `reason` of the error, other errors in its
+/// `message`
+async fn assert_upload_rejected<S, B>(
+    app: &S,
+    parts: &[Part<'_>],
+    expected_code: &str,
+    expected_cause: &str,
+) where
+    S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+    B: MessageBody,
+{
+    let response = upload(app, parts).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = read_body_json(response).await;
+    assert_eq!(body["error"]["code"], expected_code);
+    let cause = body["error"]["reason"]
+        .as_str()
+        .or_else(|| body["error"]["message"].as_str())
+        .unwrap();
+    assert!(
+        cause.contains(expected_cause),
+        "unexpected error cause: {cause}"
+    );
+}
+
+#[actix_web::test]
+async fn file_upload_retrieve_and_delete() {
+    let app = mk_files_service().await;
+    let png = b"\x89PNG\r\n\x1a\nevidence";
+
+    let response = upload(
+        &app,
+        &[
+            text_part("purpose", "dispute_evidence"),
+            file_part("evidence.png", "image/png", png),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = read_body_json(response).await;
+    let file_id = body["file_id"].as_str().unwrap().to_string();
+
+    let request = TestRequest::get()
+        .uri(&format!("/files/{file_id}"))
+        .insert_header(("api-key", API_KEY))
+        .to_request();
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("content-type").unwrap(), "image/png");
+    assert_eq!(read_body(response).await.as_ref(), png);
+
+    let request = TestRequest::delete()
+        .uri(&format!("/files/{file_id}"))
+        .insert_header(("api-key", API_KEY))
+        .to_request();
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let request = TestRequest::get()
+        .uri(&format!("/files/{file_id}"))
+        .insert_header(("api-key", API_KEY))
+        .to_request();
+    let response = call_service(&app, request).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn file_upload_rejects_invalid_multipart_fields() {
+    let app = mk_files_service().await;
+
+    assert_upload_rejected(
+        &app,
+        &[text_part("purpose", "dispute_evidence")],
+        "IR_04",
+        "Missing required param: file",
+    )
+    .await;
+    assert_upload_rejected(
+        &app,
+        &[file_part("evidence.pdf", "application/pdf", b"%PDF-1.4")],
+        "IR_04",
+        "Missing required param: purpose",
+    )
+    .await;
+    assert_upload_rejected(
+        &app,
+        &[
+            text_part("purpose", "marketing_material"),
+            file_part("evidence.pdf", "application/pdf", b"%PDF-1.4"),
+        ],
+        "IR_07",
+        "Invalid value provided: purpose",
+    )
+    .await;
+}
+
+#[actix_web::test]
+async fn file_upload_validates_file_size_and_type() {
+    let app = mk_files_service().await;
+
+    assert_upload_rejected(
+        &app,
+        &[
+            text_part("purpose", "dispute_evidence"),
+            file_part("evidence.pdf", "application/pdf", &[0; MAX_FILE_SIZE + 1]),
+        ],
+        "HE_03",
+        "file exceeds the maximum size of 32 bytes",
+    )
+    .await;
+    assert_upload_rejected(
+        &app,
+        &[
+            text_part("purpose", "dispute_evidence"),
+            file_part("evidence.pdf", "application/pdf", b""),
+        ],
+        "HE_03",
+        "file must not be empty",
+    )
+    .await;
+    assert_upload_rejected(
+        &app,
+        &[
+            text_part("purpose", "dispute_evidence"),
+            file_part("evidence.txt", "text/plain", b"evidence"),
+        ],
+        "HE_03",
+        "file type text/plain is not supported",
+    )
+    .await;
+    assert_upload_rejected(
+        &app,
+        &[
+            text_part("purpose", "identity_document"),
+            text_part("dispute_id", "dp_123"),
+            file_part("passport.jpg", "image/jpeg", b"passport"),
+        ],
+        "HE_03",
+        "dispute_id can only be provided for files uploaded as dispute evidence",
+    )
+    .await;
+
+    let response = upload(
+        &app,
+        &[
+            text_part("purpose", "identity_document"),
+            file_part("passport.jpg", "image/jpeg", &[0; MAX_FILE_SIZE]),
+        ],
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -325,6 +325,8 @@ async fn payments_create_core() {
         mandate_data: None,
         mandate_id: None,
         off_session: None,
+        request_incremental_authorization: None,
+        external_authentication_details: None,
         client_secret: None,
         browser_info: None,
     };
@@ -480,6 +482,8 @@ async fn payments_create_core_adyen_no_redirect() {
         mandate_data: None,
         mandate_id: None,
         off_session: None,
+        request_incremental_authorization: None,
+        external_authentication_details: None,
         client_secret: None,
         browser_info: None,
     };
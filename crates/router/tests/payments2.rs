@@ -236,6 +236,8 @@ async fn payments_create_core_adyen_no_redirect() {
         metadata: None,
         mandate_data: None,
         off_session: None,
+        request_incremental_authorization: None,
+        external_authentication_details: None,
         mandate_id: None,
         client_secret: None,
         browser_info: None,
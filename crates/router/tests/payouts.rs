@@ -1,7 +1,21 @@
 #![allow(clippy::unwrap_used)]
 
+use utils::{mk_service, AppClient};
+
 mod utils;
 
+#[actix_web::test]
+async fn payout_create_fail_invalid_api_key() {
+    let app = mk_service().await;
+    let client = AppClient::guest();
+
+    let user_client = client.user("321");
+
+    let payout: serde_json::Value = user_client.create_payout(&app, 100).await;
+
+    assert_eq!(payout["error"]["message"], "Access forbidden, invalid API key was used. Please create your new API key from the Dashboard Settings section.");
+}
+
 #[actix_web::test]
 async fn payouts_todo() {
     utils::setup().await;
@@ -9,28 +23,39 @@ async fn payouts_todo() {
     let client = awc::Client::default();
     let mut response;
     let mut response_body;
-    let get_endpoints = vec!["retrieve", "accounts"];
-    let post_endpoints = vec!["create", "update", "reverse", "cancel"];
+    let get_endpoints = vec!["payout_123", "list", "accounts"];
+    let post_endpoints = vec![
+        (
+            "create",
+            serde_json::json!({ "amount": 100, "currency": "EUR" }),
+        ),
+        ("payout_123", serde_json::json!({ "amount": 200 })),
+        ("payout_123/reverse", serde_json::json!({})),
+        ("payout_123/cancel", serde_json::json!({})),
+    ];
 
+    // Every endpoint is served, and rejects requests made with an invalid API key
     for endpoint in get_endpoints {
         response = client
             .get(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
+            .insert_header(("api-key", "invalid_api_key"))
             .send()
             .await
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 
-    for endpoint in post_endpoints {
+    for (endpoint, body) in post_endpoints {
         response = client
             .post(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
-            .send()
+            .insert_header(("api-key", "invalid_api_key"))
+            .send_json(&body)
             .await
             .unwrap();
         response_body = response.body().await;
         println!("{endpoint} =:= {response:?} : {response_body:?}");
-        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
     }
 }
@@ -345,6 +345,9 @@ pub fn api_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// - domain
 /// - update_tracker
 ///
+/// An operation which must not run concurrently on the same payment can also take a lock on it,
+/// with `#[operation(ops = "all", flow = "capture", lock = "payment_id")]`.
+///
 /// ## Example
 /// ```
 /// use router_derive::Operation;
@@ -16,6 +16,8 @@ enum Derives {
     Syncdata,
     Canceldata,
     Capturedata,
+    IncrementalAuthorization,
+    IncrementalAuthorizationData,
     VerifyData,
     Start,
     Verify,
@@ -34,6 +36,8 @@ impl From<String> for Derives {
             "canceldata" => Self::Canceldata,
             "capture" => Self::Capture,
             "capturedata" => Self::Capturedata,
+            "incrementalauthorization" => Self::IncrementalAuthorization,
+            "incrementalauthorizationdata" => Self::IncrementalAuthorizationData,
             "start" => Self::Start,
             "verify" => Self::Verify,
             "verifydata" => Self::VerifyData,
@@ -48,6 +52,7 @@ impl Derives {
     fn to_operation(
         self,
         fns: impl Iterator<Item = TokenStream> + Clone,
+        lock_fn: &TokenStream,
         struct_name: &syn::Ident,
     ) -> TokenStream {
         let req_type = Conversion::get_req_type(self);
@@ -55,6 +60,7 @@ impl Derives {
             #[automatically_derived]
             impl<F:Send+Clone> Operation<F,#req_type> for #struct_name {
                 #(#fns)*
+                #lock_fn
             }
         }
     }
@@ -62,6 +68,7 @@ impl Derives {
     fn to_ref_operation(
         self,
         ref_fns: impl Iterator<Item = TokenStream> + Clone,
+        lock_fn: &TokenStream,
         struct_name: &syn::Ident,
     ) -> TokenStream {
         let req_type = Conversion::get_req_type(self);
@@ -69,6 +76,7 @@ impl Derives {
             #[automatically_derived]
             impl<F:Send+Clone> Operation<F,#req_type> for &#struct_name {
                 #(#ref_fns)*
+                #lock_fn
             }
         }
     }
@@ -110,6 +118,12 @@ impl Conversion {
             Derives::Canceldata => syn::Ident::new("PaymentsCancelData", Span::call_site()),
             Derives::Capture => syn::Ident::new("PaymentsCaptureRequest", Span::call_site()),
             Derives::Capturedata => syn::Ident::new("PaymentsCaptureData", Span::call_site()),
+            Derives::IncrementalAuthorization => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationRequest", Span::call_site())
+            }
+            Derives::IncrementalAuthorizationData => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
+            }
             Derives::Start => syn::Ident::new("PaymentsStartRequest", Span::call_site()),
             Derives::Verify => syn::Ident::new("VerifyRequest", Span::call_site()),
             Derives::VerifyData => syn::Ident::new("VerifyRequestData", Span::call_site()),
@@ -292,6 +306,27 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
             .unwrap_or_else(|| String::from("crate")),
         Span::call_site(),
     );
+    let lock_fn = match prop.get("lock").map(|v| v.join("")) {
+        None => quote! {},
+        Some(lock) => {
+            let lock_on = match lock.as_str() {
+                "payment_id" => quote! { PaymentId },
+                _ => {
+                    return Err(helpers::syn_error(
+                        op.span(),
+                        &format!(
+                            "Invalid property lock = {lock:?}. Expected lock = \"payment_id\""
+                        ),
+                    ))
+                }
+            };
+            quote! {
+                fn lock_on(&self) -> Option<#current_crate::core::payments::operations::LockOn> {
+                    Some(#current_crate::core::payments::operations::LockOn::#lock_on)
+                }
+            }
+        }
+    };
 
     let trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
@@ -299,7 +334,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
             let con: Conversion = t.to_owned().into();
             con.to_function(derive)
         });
-        derive.to_operation(fns, struct_name)
+        derive.to_operation(fns, &lock_fn, struct_name)
     });
     let ref_trait_derive = flow.iter().map(|derive| {
         let derive: Derives = derive.to_owned().into();
@@ -307,7 +342,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
             let con: Conversion = t.to_owned().into();
             con.to_ref_function(derive)
         });
-        derive.to_ref_operation(fns, struct_name)
+        derive.to_ref_operation(fns, &lock_fn, struct_name)
     });
     let trait_derive = quote! {
             #(#ref_trait_derive)* #(#trait_derive)*
@@ -326,12 +361,14 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     PaymentsSyncData,
                     PaymentsCaptureData,
                     PaymentsCancelData,
+                    PaymentsIncrementalAuthorizationData,
                     PaymentsAuthorizeData,
                     PaymentsSessionData,
 
                     api::{
                         PaymentsCaptureRequest,
                         PaymentsCancelRequest,
+                        PaymentsIncrementalAuthorizationRequest,
                         PaymentsRetrieveRequest,
                         PaymentsRequest,
                         PaymentsStartRequest,
@@ -120,6 +120,8 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -134,10 +136,28 @@ pub enum Flow {
     PayoutsUpdate,
     /// Payouts reverse flow.
     PayoutsReverse,
+    /// Payouts confirm flow.
+    PayoutsConfirm,
     /// Payouts cancel flow.
     PayoutsCancel,
+    /// Payouts list flow.
+    PayoutsList,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Disputes retrieve flow.
+    DisputesRetrieve,
+    /// Disputes list flow.
+    DisputesList,
+    /// Disputes accept flow.
+    DisputesAccept,
+    /// Disputes evidence submit flow.
+    DisputesEvidenceSubmit,
+    /// File create flow.
+    CreateFile,
+    /// File retrieve flow.
+    RetrieveFile,
+    /// File delete flow.
+    DeleteFile,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
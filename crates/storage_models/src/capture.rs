@@ -0,0 +1,125 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::captures};
+
+#[derive(
+    Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = captures)]
+pub struct Capture {
+    pub id: i32,
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub connector_capture_id: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub final_capture: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = captures)]
+pub struct CaptureNew {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub final_capture: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum CaptureUpdate {
+    ResponseUpdate {
+        status: storage_enums::CaptureStatus,
+        connector_capture_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::CaptureStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::CaptureStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+pub struct CaptureUpdateInternal {
+    status: Option<storage_enums::CaptureStatus>,
+    connector_capture_id: Option<String>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CaptureUpdate> for CaptureUpdateInternal {
+    fn from(capture_update: CaptureUpdate) -> Self {
+        match capture_update {
+            CaptureUpdate::ResponseUpdate {
+                status,
+                connector_capture_id,
+            } => Self {
+                status: Some(status),
+                connector_capture_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            CaptureUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            CaptureUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl CaptureUpdate {
+    pub fn apply_changeset(self, source: Capture) -> Capture {
+        let capture_update: CaptureUpdateInternal = self.into();
+        Capture {
+            status: capture_update.status.unwrap_or(source.status),
+            connector_capture_id: capture_update
+                .connector_capture_id
+                .or(source.connector_capture_id),
+            error_message: capture_update.error_message.or(source.error_message),
+            error_code: capture_update.error_code.or(source.error_code),
+            modified_at: capture_update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
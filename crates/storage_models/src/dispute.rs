@@ -1 +1,153 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
 
+use crate::{enums as storage_enums, schema::dispute};
+
+#[derive(
+    Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = dispute)]
+pub struct Dispute {
+    pub id: i32,
+    pub dispute_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = dispute)]
+pub struct DisputeNew {
+    pub dispute_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum DisputeUpdate {
+    Update {
+        dispute_stage: storage_enums::DisputeStage,
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: String,
+        connector_reason: Option<String>,
+        connector_reason_code: Option<String>,
+        challenge_required_by: Option<PrimitiveDateTime>,
+        connector_updated_at: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute)]
+pub struct DisputeUpdateInternal {
+    dispute_stage: Option<storage_enums::DisputeStage>,
+    dispute_status: Option<storage_enums::DisputeStatus>,
+    connector_status: Option<String>,
+    connector_reason: Option<String>,
+    connector_reason_code: Option<String>,
+    challenge_required_by: Option<PrimitiveDateTime>,
+    connector_updated_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<DisputeUpdate> for DisputeUpdateInternal {
+    fn from(dispute_update: DisputeUpdate) -> Self {
+        match dispute_update {
+            DisputeUpdate::Update {
+                dispute_stage,
+                dispute_status,
+                connector_status,
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                connector_updated_at,
+            } => Self {
+                dispute_stage: Some(dispute_stage),
+                dispute_status: Some(dispute_status),
+                connector_status: Some(connector_status),
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                connector_updated_at,
+                modified_at: Some(common_utils::date_time::now()),
+            },
+            DisputeUpdate::StatusUpdate {
+                dispute_status,
+                connector_status,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl DisputeUpdate {
+    pub fn apply_changeset(self, source: Dispute) -> Dispute {
+        let dispute_update: DisputeUpdateInternal = self.into();
+        Dispute {
+            dispute_stage: dispute_update.dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: dispute_update
+                .dispute_status
+                .unwrap_or(source.dispute_status),
+            connector_status: dispute_update
+                .connector_status
+                .unwrap_or(source.connector_status),
+            connector_reason: dispute_update.connector_reason.or(source.connector_reason),
+            connector_reason_code: dispute_update
+                .connector_reason_code
+                .or(source.connector_reason_code),
+            challenge_required_by: dispute_update
+                .challenge_required_by
+                .or(source.challenge_required_by),
+            connector_updated_at: dispute_update
+                .connector_updated_at
+                .or(source.connector_updated_at),
+            modified_at: dispute_update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
@@ -2,15 +2,19 @@
 pub mod diesel_exports {
     pub use super::{
         DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
-        DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType, DbCurrency as Currency,
+        DbAuthorizationStatus as AuthorizationStatus, DbCaptureMethod as CaptureMethod,
+        DbCaptureStatus as CaptureStatus, DbConnectorType as ConnectorType, DbCurrency as Currency,
+        DbDisputeStage as DisputeStage, DbDisputeStatus as DisputeStatus,
         DbEventClass as EventClass, DbEventObjectType as EventObjectType, DbEventType as EventType,
         DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
         DbMerchantStorageScheme as MerchantStorageScheme, DbPaymentFlow as PaymentFlow,
         DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
         DbPaymentMethodSubType as PaymentMethodSubType, DbPaymentMethodType as PaymentMethodType,
+        DbPayoutStatus as PayoutStatus, DbPayoutType as PayoutType,
         DbProcessTrackerStatus as ProcessTrackerStatus, DbRefundStatus as RefundStatus,
         DbRefundType as RefundType, DbRoutingAlgorithm as RoutingAlgorithm,
+        DbWebhookDeliveryStatus as WebhookDeliveryStatus,
     };
 }
 
@@ -277,6 +281,9 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventClass {
     Payments,
+    Refunds,
+    Disputes,
+    Mandates,
 }
 
 #[derive(
@@ -296,6 +303,37 @@ pub enum EventClass {
 #[strum(serialize_all = "snake_case")]
 pub enum EventObjectType {
     PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    MandateDetails,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The event has not been sent to the merchant yet
+    #[default]
+    Pending,
+    /// The merchant acknowledged the event
+    Delivered,
+    /// Delivery failed, and will be retried by the process tracker
+    RetryScheduled,
+    /// Delivery failed, and all the retries have been exhausted
+    Failed,
 }
 
 #[derive(
@@ -316,6 +354,21 @@ pub enum EventObjectType {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    PaymentProcessing,
+    PaymentCancelled,
+    ActionRequired,
+    RefundSucceeded,
+    RefundFailed,
+    MandateActive,
+    MandateRevoked,
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
 }
 
 #[derive(
@@ -345,6 +398,57 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    PartiallyCaptured,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationStatus {
+    #[default]
+    Started,
+    Success,
+    Failure,
+    Processing,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    #[default]
+    Started,
+    Charged,
+    Pending,
+    Failed,
 }
 
 #[derive(
@@ -672,3 +776,105 @@ pub enum MandateStatus {
     Pending,
     Revoked,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    #[default]
+    RequiresConfirmation,
+    RequiresFulfillment,
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+    Reversed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    Card,
+    Bank,
+    ConnectedAccount,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStage {
+    PreDispute,
+    #[default]
+    Dispute,
+    PreArbitration,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    DisputeOpened,
+    DisputeExpired,
+    DisputeAccepted,
+    DisputeCancelled,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+}
@@ -1,5 +1,5 @@
 use common_utils::custom_serde;
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -32,4 +32,72 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub delivery_status: storage_enums::WebhookDeliveryStatus,
+    pub delivery_attempts: i16,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub last_delivery_attempted_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum EventUpdate {
+    DeliveryAttemptUpdate {
+        delivery_status: storage_enums::WebhookDeliveryStatus,
+        delivery_attempts: i16,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = events)]
+pub struct EventUpdateInternal {
+    is_webhook_notified: Option<bool>,
+    delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
+    delivery_attempts: Option<i16>,
+    last_delivery_attempted_at: Option<PrimitiveDateTime>,
+}
+
+impl From<EventUpdate> for EventUpdateInternal {
+    fn from(event_update: EventUpdate) -> Self {
+        match event_update {
+            EventUpdate::DeliveryAttemptUpdate {
+                delivery_status,
+                delivery_attempts,
+            } => Self {
+                is_webhook_notified: Some(
+                    delivery_status == storage_enums::WebhookDeliveryStatus::Delivered,
+                ),
+                delivery_status: Some(delivery_status),
+                delivery_attempts: Some(delivery_attempts),
+                last_delivery_attempted_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
+
+impl EventUpdate {
+    pub fn apply_changeset(self, source: Event) -> Event {
+        let event_update: EventUpdateInternal = self.into();
+        Event {
+            is_webhook_notified: event_update
+                .is_webhook_notified
+                .unwrap_or(source.is_webhook_notified),
+            delivery_status: event_update
+                .delivery_status
+                .unwrap_or(source.delivery_status),
+            delivery_attempts: event_update
+                .delivery_attempts
+                .unwrap_or(source.delivery_attempts),
+            last_delivery_attempted_at: event_update
+                .last_delivery_attempted_at
+                .or(source.last_delivery_attempted_at),
+            ..source
+        }
+    }
+}
+
+/// Data needed by the process tracker to retry the delivery of an outgoing webhook
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    pub webhook: serde_json::Value,
 }
@@ -0,0 +1,83 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::file_metadata;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataNew {
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub provider_file_id: Option<String>,
+    pub file_upload_provider: Option<String>,
+    pub available: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Identifiable, Queryable, Serialize)]
+#[diesel(table_name = file_metadata, primary_key(file_id, merchant_id))]
+pub struct FileMetadata {
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub provider_file_id: Option<String>,
+    pub file_upload_provider: Option<String>,
+    pub available: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FileMetadataUpdate {
+    Update {
+        provider_file_id: Option<String>,
+        file_upload_provider: Option<String>,
+        available: bool,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataUpdateInternal {
+    provider_file_id: Option<String>,
+    file_upload_provider: Option<String>,
+    available: Option<bool>,
+}
+
+impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
+    fn from(file_metadata_update: FileMetadataUpdate) -> Self {
+        match file_metadata_update {
+            FileMetadataUpdate::Update {
+                provider_file_id,
+                file_upload_provider,
+                available,
+            } => Self {
+                provider_file_id,
+                file_upload_provider,
+                available: Some(available),
+            },
+        }
+    }
+}
+
+impl FileMetadataUpdate {
+    pub fn apply_changeset(self, source: FileMetadata) -> FileMetadata {
+        let file_metadata_update: FileMetadataUpdateInternal = self.into();
+        FileMetadata {
+            provider_file_id: file_metadata_update
+                .provider_file_id
+                .or(source.provider_file_id),
+            file_upload_provider: file_metadata_update
+                .file_upload_provider
+                .or(source.file_upload_provider),
+            available: file_metadata_update.available.unwrap_or(source.available),
+            ..source
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::incremental_authorizations};
+
+#[derive(
+    Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorization {
+    pub id: i32,
+    pub authorization_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::AuthorizationStatus,
+    /// Total amount authorized once the increment succeeds
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub connector_authorization_id: Option<String>,
+    pub reason: Option<String>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorizationNew {
+    pub authorization_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::AuthorizationStatus,
+    pub amount: i64,
+    pub previously_authorized_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum IncrementalAuthorizationUpdate {
+    ResponseUpdate {
+        status: storage_enums::AuthorizationStatus,
+        connector_authorization_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::AuthorizationStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incremental_authorizations)]
+pub struct IncrementalAuthorizationUpdateInternal {
+    status: Option<storage_enums::AuthorizationStatus>,
+    connector_authorization_id: Option<String>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<IncrementalAuthorizationUpdate> for IncrementalAuthorizationUpdateInternal {
+    fn from(authorization_update: IncrementalAuthorizationUpdate) -> Self {
+        match authorization_update {
+            IncrementalAuthorizationUpdate::ResponseUpdate {
+                status,
+                connector_authorization_id,
+            } => Self {
+                status: Some(status),
+                connector_authorization_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            IncrementalAuthorizationUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl IncrementalAuthorizationUpdate {
+    pub fn apply_changeset(self, source: IncrementalAuthorization) -> IncrementalAuthorization {
+        let authorization_update: IncrementalAuthorizationUpdateInternal = self.into();
+        IncrementalAuthorization {
+            status: authorization_update.status.unwrap_or(source.status),
+            connector_authorization_id: authorization_update
+                .connector_authorization_id
+                .or(source.connector_authorization_id),
+            error_message: authorization_update.error_message.or(source.error_message),
+            error_code: authorization_update.error_code.or(source.error_code),
+            modified_at: authorization_update
+                .modified_at
+                .unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
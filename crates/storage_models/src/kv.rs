@@ -2,10 +2,15 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    address::{Address, AddressNew, AddressUpdate},
+    connector_response::{ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate},
+    customers::{Customer, CustomerNew, CustomerUpdate},
     errors,
+    mandate::{Mandate, MandateNew, MandateUpdate},
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
+    reverse_lookup::ReverseLookupNew,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,19 +18,59 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
-    Delete,
+    Delete { deletable: Deletable },
+}
+
+/// Redis hash holding the versions of the database operations pushed for a row to the drainer
+/// stream, keyed by [`DBOperation::primary_key`]. The hash has the same hash tag as the stream, so
+/// that the version can be assigned and the entry appended atomically in a Redis cluster.
+pub fn version_key(stream_name: &str, primary_key: &str) -> String {
+    format!("{stream_name}_version_{primary_key}")
+}
+
+/// Field of the drainer stream entry holding the serialized database operation
+pub const TYPED_SQL_ENTRY_FIELD: &str = "typed_sql";
+
+/// Field of the drainer stream entry holding the version assigned to the database operation when
+/// the entry was appended
+pub const VERSION_ENTRY_FIELD: &str = "version";
+
+/// Field of the version hash holding the last version assigned to a database operation of the row
+pub const ASSIGNED_VERSION_FIELD: &str = "assigned";
+
+/// Field of the version hash holding the last version applied to the database by the drainer
+pub const APPLIED_VERSION_FIELD: &str = "applied";
+
+/// Time after which the versions of a row are forgotten once the row stops being written, in
+/// seconds. It must exceed the time an entry may spend in the drainer and dead letter streams.
+pub const VERSION_TTL: i64 = 7 * 24 * 60 * 60;
+
+impl DBOperation {
+    /// Identifies the row that the operation writes to, so that the operations of a row can be
+    /// versioned and applied in order
+    pub fn primary_key(&self) -> Option<String> {
+        match self {
+            Self::Insert { insertable } => Some(insertable.primary_key()),
+            Self::Update { updatable } => Some(updatable.primary_key()),
+            Self::Delete { deletable } => Some(deletable.primary_key()),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TypedSql {
     #[serde(flatten)]
     pub op: DBOperation,
+    /// Monotonically increasing version of the operation among the operations of the same row.
+    /// Entries pushed before versioning was introduced have no version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<i64>,
 }
 
 impl TypedSql {
     pub fn to_field_value_pairs(&self) -> crate::StorageResult<Vec<(&str, String)>> {
         Ok(vec![(
-            "typed_sql",
+            TYPED_SQL_ENTRY_FIELD,
             serde_json::to_string(self)
                 .into_report()
                 .change_context(errors::DatabaseError::QueryGenerationFailed)?,
@@ -39,6 +84,31 @@ pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
     PaymentAttempt(PaymentAttemptNew),
     Refund(RefundNew),
+    Address(AddressNew),
+    ConnectorResponse(ConnectorResponseNew),
+    Customer(CustomerNew),
+    Mandate(MandateNew),
+    ReverseLookup(ReverseLookupNew),
+}
+
+impl Insertable {
+    fn primary_key(&self) -> String {
+        match self {
+            Self::PaymentIntent(a) => format!("payment_intent_{}_{}", a.merchant_id, a.payment_id),
+            Self::PaymentAttempt(a) => {
+                format!("payment_attempt_{}_{}", a.merchant_id, a.attempt_id)
+            }
+            Self::Refund(a) => format!("refund_{}_{}", a.merchant_id, a.refund_id),
+            Self::Address(a) => format!("address_{}", a.address_id),
+            Self::ConnectorResponse(a) => format!(
+                "connector_response_{}_{}_{}",
+                a.merchant_id, a.payment_id, a.attempt_id
+            ),
+            Self::Customer(a) => format!("customer_{}_{}", a.merchant_id, a.customer_id),
+            Self::Mandate(a) => format!("mandate_{}_{}", a.merchant_id, a.mandate_id),
+            Self::ReverseLookup(a) => format!("reverse_lookup_{}", a.lookup_id),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +117,51 @@ pub enum Updateable {
     PaymentIntentUpdate(PaymentIntentUpdateMems),
     PaymentAttemptUpdate(PaymentAttemptUpdateMems),
     RefundUpdate(RefundUpdateMems),
+    AddressUpdate(AddressUpdateMems),
+    ConnectorResponseUpdate(ConnectorResponseUpdateMems),
+    CustomerUpdate(CustomerUpdateMems),
+    MandateUpdate(MandateUpdateMems),
+}
+
+impl Updateable {
+    fn primary_key(&self) -> String {
+        match self {
+            Self::PaymentIntentUpdate(a) => format!(
+                "payment_intent_{}_{}",
+                a.orig.merchant_id, a.orig.payment_id
+            ),
+            Self::PaymentAttemptUpdate(a) => format!(
+                "payment_attempt_{}_{}",
+                a.orig.merchant_id, a.orig.attempt_id
+            ),
+            Self::RefundUpdate(a) => format!("refund_{}_{}", a.orig.merchant_id, a.orig.refund_id),
+            Self::AddressUpdate(a) => format!("address_{}", a.orig.address_id),
+            Self::ConnectorResponseUpdate(a) => format!(
+                "connector_response_{}_{}_{}",
+                a.orig.merchant_id, a.orig.payment_id, a.orig.attempt_id
+            ),
+            Self::CustomerUpdate(a) => {
+                format!("customer_{}_{}", a.orig.merchant_id, a.orig.customer_id)
+            }
+            Self::MandateUpdate(a) => {
+                format!("mandate_{}_{}", a.orig.merchant_id, a.orig.mandate_id)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    Customer(CustomerDeleteMems),
+}
+
+impl Deletable {
+    fn primary_key(&self) -> String {
+        match self {
+            Self::Customer(a) => format!("customer_{}_{}", a.merchant_id, a.customer_id),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,3 +181,33 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressUpdateMems {
+    pub orig: Address,
+    pub update_data: AddressUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectorResponseUpdateMems {
+    pub orig: ConnectorResponse,
+    pub update_data: ConnectorResponseUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerUpdateMems {
+    pub orig: Customer,
+    pub update_data: CustomerUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MandateUpdateMems {
+    pub orig: Mandate,
+    pub update_data: MandateUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerDeleteMems {
+    pub customer_id: String,
+    pub merchant_id: String,
+}
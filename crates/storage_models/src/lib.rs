@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
@@ -8,6 +9,8 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod file;
+pub mod incremental_authorization;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -17,6 +20,7 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
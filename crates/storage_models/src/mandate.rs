@@ -1,11 +1,12 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::mandate};
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
 #[diesel(table_name = mandate)]
 pub struct Mandate {
     pub id: i32,
@@ -29,7 +30,14 @@ pub struct Mandate {
 }
 
 #[derive(
-    router_derive::Setter, Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay,
+    router_derive::Setter,
+    Clone,
+    Debug,
+    Default,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
 )]
 #[diesel(table_name = mandate)]
 pub struct MandateNew {
@@ -52,7 +60,7 @@ pub struct MandateNew {
     pub connector_mandate_id: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum MandateUpdate {
     StatusUpdate {
         mandate_status: storage_enums::MandateStatus,
@@ -65,7 +73,7 @@ pub enum MandateUpdate {
     },
 }
 
-#[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Eq, PartialEq, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct SingleUseMandate {
     pub amount: i64,
     pub currency: storage_enums::Currency,
@@ -79,6 +87,22 @@ pub struct MandateUpdateInternal {
     connector_mandate_id: Option<String>,
 }
 
+impl MandateUpdate {
+    pub fn apply_changeset(self, source: Mandate) -> Mandate {
+        let mandate_update: MandateUpdateInternal = self.into();
+        Mandate {
+            mandate_status: mandate_update
+                .mandate_status
+                .unwrap_or(source.mandate_status),
+            amount_captured: mandate_update.amount_captured.or(source.amount_captured),
+            connector_mandate_id: mandate_update
+                .connector_mandate_id
+                .or(source.connector_mandate_id),
+            ..source
+        }
+    }
+}
+
 impl From<MandateUpdate> for MandateUpdateInternal {
     fn from(mandate_update: MandateUpdate) -> Self {
         match mandate_update {
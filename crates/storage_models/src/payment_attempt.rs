@@ -39,6 +39,8 @@ pub struct PaymentAttempt {
     pub error_code: Option<String>,
     pub payment_token: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub next_action_data: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -78,6 +80,8 @@ pub struct PaymentAttemptNew {
     pub payment_token: Option<String>,
     pub error_code: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub authentication_details: Option<serde_json::Value>,
+    pub next_action_data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -120,10 +124,16 @@ pub enum PaymentAttemptUpdate {
         redirect: Option<bool>,
         mandate_id: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        authentication_details: Option<serde_json::Value>,
+        /// `Some(None)` clears the next action of the attempt, `None` keeps it
+        next_action_data: Option<Option<serde_json::Value>>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
     },
+    AmountUpdate {
+        amount: i64,
+    },
     ErrorUpdate {
         connector: Option<String>,
         status: storage_enums::AttemptStatus,
@@ -152,6 +162,8 @@ pub struct PaymentAttemptUpdateInternal {
     payment_token: Option<String>,
     error_code: Option<String>,
     connector_metadata: Option<serde_json::Value>,
+    authentication_details: Option<serde_json::Value>,
+    next_action_data: Option<Option<serde_json::Value>>,
 }
 
 impl PaymentAttemptUpdate {
@@ -174,6 +186,12 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            authentication_details: pa_update
+                .authentication_details
+                .or(source.authentication_details),
+            next_action_data: pa_update
+                .next_action_data
+                .unwrap_or(source.next_action_data),
             ..source
         }
     }
@@ -199,6 +217,12 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method,
                 payment_token,
                 modified_at: Some(common_utils::date_time::now()),
+                next_action_data: Some(None),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
@@ -227,6 +251,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                next_action_data: Some(None),
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -235,6 +260,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
             } => Self {
                 status: Some(status),
                 cancellation_reason,
+                next_action_data: Some(None),
                 ..Default::default()
             },
             PaymentAttemptUpdate::ResponseUpdate {
@@ -246,6 +272,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 redirect,
                 mandate_id,
                 connector_metadata,
+                authentication_details,
+                next_action_data,
             } => Self {
                 status: Some(status),
                 connector,
@@ -256,6 +284,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 redirect,
                 mandate_id,
                 connector_metadata,
+                authentication_details,
+                next_action_data,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -269,10 +299,12 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_message,
                 error_code,
                 modified_at: Some(common_utils::date_time::now()),
+                next_action_data: Some(None),
                 ..Default::default()
             },
             PaymentAttemptUpdate::StatusUpdate { status } => Self {
                 status: Some(status),
+                next_action_data: Some(None),
                 ..Default::default()
             },
             PaymentAttemptUpdate::UpdateTrackers {
@@ -286,3 +318,12 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
         }
     }
 }
+
+/// Data needed by the process tracker to capture or void an authorized payment attempt according
+/// to the capture policy of the merchant
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduledPaymentActionTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+}
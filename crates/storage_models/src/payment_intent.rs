@@ -29,6 +29,8 @@ pub struct PaymentIntent {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
+    pub attempt_count: i16,
+    pub request_incremental_authorization: bool,
 }
 
 #[derive(
@@ -65,6 +67,8 @@ pub struct PaymentIntentNew {
     pub client_secret: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub attempt_count: i16,
+    pub request_incremental_authorization: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +96,12 @@ pub enum PaymentIntentUpdate {
     PGStatusUpdate {
         status: storage_enums::IntentStatus,
     },
+    AttemptCountUpdate {
+        attempt_count: i16,
+    },
+    AmountUpdate {
+        amount: i64,
+    },
     Update {
         amount: i64,
         currency: storage_enums::Currency,
@@ -120,6 +130,7 @@ pub struct PaymentIntentUpdateInternal {
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
     pub modified_at: Option<PrimitiveDateTime>,
+    pub attempt_count: Option<i16>,
 }
 
 impl PaymentIntentUpdate {
@@ -147,6 +158,9 @@ impl PaymentIntentUpdate {
                 .shipping_address_id
                 .or(source.shipping_address_id),
             modified_at: common_utils::date_time::now(),
+            attempt_count: internal_update
+                .attempt_count
+                .unwrap_or(source.attempt_count),
             ..source
         }
     }
@@ -196,6 +210,16 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentIntentUpdate::AttemptCountUpdate { attempt_count } => Self {
+                attempt_count: Some(attempt_count),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentIntentUpdate::PGStatusUpdate { status } => Self {
                 status: Some(status),
                 modified_at: Some(common_utils::date_time::now()),
@@ -0,0 +1,154 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(
+    Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = payouts)]
+pub struct Payout {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = payouts)]
+pub struct PayoutNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: Option<storage_enums::PayoutType>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum PayoutUpdate {
+    Update {
+        amount: Option<i64>,
+        currency: Option<storage_enums::Currency>,
+        payout_type: Option<storage_enums::PayoutType>,
+        description: Option<String>,
+        metadata: Option<serde_json::Value>,
+    },
+    StatusUpdate {
+        connector_payout_id: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutUpdateInternal {
+    amount: Option<i64>,
+    currency: Option<storage_enums::Currency>,
+    payout_type: Option<storage_enums::PayoutType>,
+    description: Option<String>,
+    metadata: Option<serde_json::Value>,
+    connector_payout_id: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutUpdate> for PayoutUpdateInternal {
+    fn from(payout_update: PayoutUpdate) -> Self {
+        match payout_update {
+            PayoutUpdate::Update {
+                amount,
+                currency,
+                payout_type,
+                description,
+                metadata,
+            } => Self {
+                amount,
+                currency,
+                payout_type,
+                description,
+                metadata,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::StatusUpdate {
+                connector_payout_id,
+                status,
+            } => Self {
+                connector_payout_id,
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PayoutUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl PayoutUpdate {
+    pub fn apply_changeset(self, source: Payout) -> Payout {
+        let payout_update: PayoutUpdateInternal = self.into();
+        Payout {
+            amount: payout_update.amount.unwrap_or(source.amount),
+            currency: payout_update.currency.unwrap_or(source.currency),
+            payout_type: payout_update.payout_type.or(source.payout_type),
+            description: payout_update.description.or(source.description),
+            metadata: payout_update.metadata.or(source.metadata),
+            connector_payout_id: payout_update
+                .connector_payout_id
+                .or(source.connector_payout_id),
+            status: payout_update.status.unwrap_or(source.status),
+            error_code: payout_update.error_code.or(source.error_code),
+            error_message: payout_update.error_message.or(source.error_message),
+            modified_at: payout_update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
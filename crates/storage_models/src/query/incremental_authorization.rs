@@ -0,0 +1,86 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    incremental_authorization::{
+        IncrementalAuthorization, IncrementalAuthorizationNew, IncrementalAuthorizationUpdate,
+        IncrementalAuthorizationUpdateInternal,
+    },
+    schema::incremental_authorizations::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncrementalAuthorizationNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncrementalAuthorization> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncrementalAuthorization {
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        authorization: IncrementalAuthorizationUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::authorization_id
+                .eq(self.authorization_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+            IncrementalAuthorizationUpdateInternal::from(authorization),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_authorization_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_authorization_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_authorization_id.eq(connector_authorization_id.to_owned())),
+        )
+        .await
+    }
+
+    /// The incremental authorizations of the payment attempt, in the order they were made
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_merchant_id_payment_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            None,
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+}
@@ -28,8 +28,8 @@ impl PaymentAttempt {
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            dsl::payment_id
-                .eq(self.payment_id.to_owned())
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
             PaymentAttemptUpdateInternal::from(payment_attempt),
         )
@@ -45,19 +45,18 @@ impl PaymentAttempt {
         }
     }
 
+    /// Find the active attempt of a payment. A payment may have multiple attempts if it was
+    /// retried, in which case the latest attempt is the active attempt.
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
-        )
-        .await
+        Self::find_optional_by_payment_id_merchant_id(conn, payment_id, merchant_id)
+            .await?
+            .ok_or(errors::DatabaseError::NotFound)
+            .into_report()
     }
 
     #[instrument(skip(conn))]
@@ -66,11 +65,33 @@ impl PaymentAttempt {
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Option<Self>> {
-        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+        Ok(
+            Self::find_all_by_payment_id_merchant_id(conn, payment_id, merchant_id)
+                .await?
+                .into_iter()
+                .max_by_key(|payment_attempt| (payment_attempt.created_at, payment_attempt.id)),
+        )
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            Self,
+        >(
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
                 .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            None,
         )
         .await
     }
@@ -108,7 +129,10 @@ impl PaymentAttempt {
             dsl::payment_id
                 .eq(payment_id.to_owned())
                 .and(dsl::merchant_id.eq(merchant_id.to_owned()))
-                .and(dsl::status.eq(enums::AttemptStatus::Charged)),
+                .and(dsl::status.eq_any(vec![
+                    enums::AttemptStatus::Charged,
+                    enums::AttemptStatus::PartialCharged,
+                ])),
             None,
             None,
             None,
@@ -43,6 +43,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        connector_capture_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        final_capture -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -90,6 +113,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Varchar,
+        currency -> Varchar,
+        dispute_stage -> DisputeStage,
+        dispute_status -> DisputeStatus,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        connector_created_at -> Nullable<Timestamp>,
+        connector_updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -104,6 +154,50 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        delivery_status -> WebhookDeliveryStatus,
+        delivery_attempts -> Int2,
+        last_delivery_attempted_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    file_metadata (file_id, merchant_id) {
+        file_id -> Varchar,
+        merchant_id -> Varchar,
+        file_name -> Nullable<Varchar>,
+        file_size -> Int4,
+        file_type -> Varchar,
+        provider_file_id -> Nullable<Varchar>,
+        file_upload_provider -> Nullable<Varchar>,
+        available -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incremental_authorizations (id) {
+        id -> Int4,
+        authorization_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> AuthorizationStatus,
+        amount -> Int8,
+        previously_authorized_amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        connector_authorization_id -> Nullable<Varchar>,
+        reason -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -236,6 +330,8 @@ diesel::table! {
         error_code -> Nullable<Varchar>,
         payment_token -> Nullable<Varchar>,
         connector_metadata -> Nullable<Jsonb>,
+        authentication_details -> Nullable<Jsonb>,
+        next_action_data -> Nullable<Jsonb>,
     }
 }
 
@@ -266,6 +362,8 @@ diesel::table! {
         setup_future_usage -> Nullable<FutureUsage>,
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
+        attempt_count -> Int2,
+        request_incremental_authorization -> Bool,
     }
 }
 
@@ -298,6 +396,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        payout_type -> Nullable<PayoutType>,
+        amount -> Int8,
+        currency -> Currency,
+        status -> PayoutStatus,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -366,10 +488,14 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    captures,
     configs,
     connector_response,
     customers,
+    dispute,
     events,
+    file_metadata,
+    incremental_authorizations,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -377,6 +503,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
     refund,
     reverse_lookup,
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE payouts;
+
+DROP TYPE "PayoutType";
+
+DROP TYPE "PayoutStatus";
@@ -0,0 +1,37 @@
+-- Your SQL goes here
+CREATE TYPE "PayoutStatus" AS ENUM (
+    'requires_confirmation',
+    'requires_fulfillment',
+    'pending',
+    'success',
+    'failed',
+    'cancelled',
+    'reversed'
+);
+
+CREATE TYPE "PayoutType" AS ENUM (
+    'card',
+    'bank',
+    'connected_account'
+);
+
+CREATE TABLE payouts (
+    id SERIAL PRIMARY KEY,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    connector VARCHAR(64) NOT NULL,
+    connector_payout_id VARCHAR(128),
+    payout_type "PayoutType",
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    status "PayoutStatus" NOT NULL,
+    description VARCHAR(255),
+    metadata JSON,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payouts_merchant_id_payout_id_index ON payouts (merchant_id, payout_id);
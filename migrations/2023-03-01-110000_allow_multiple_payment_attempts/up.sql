@@ -0,0 +1,8 @@
+-- Payments retried with another connector have multiple payment attempts
+DROP INDEX payment_attempt_payment_id_merchant_id_index;
+
+CREATE INDEX payment_attempt_payment_id_merchant_id_index ON payment_attempt (payment_id, merchant_id);
+
+CREATE UNIQUE INDEX payment_attempt_attempt_id_merchant_id_index ON payment_attempt (attempt_id, merchant_id);
+
+ALTER TABLE payment_intent ADD COLUMN attempt_count SMALLINT NOT NULL DEFAULT 1;
@@ -0,0 +1,33 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM pg_enum
+WHERE enumlabel IN (
+  'payment_failed',
+  'payment_processing',
+  'action_required',
+  'refund_succeeded',
+  'refund_failed',
+  'mandate_active',
+  'mandate_revoked',
+  'dispute_opened',
+  'dispute_expired',
+  'dispute_accepted',
+  'dispute_cancelled',
+  'dispute_challenged',
+  'dispute_won',
+  'dispute_lost'
+)
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('refunds', 'disputes', 'mandates')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('refund_details', 'dispute_details', 'mandate_details')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_processing';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'action_required';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_succeeded';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'mandate_active';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'mandate_revoked';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_opened';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_expired';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_accepted';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_cancelled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_challenged';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_won';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_lost';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'refunds';
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'disputes';
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'mandates';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'refund_details';
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'dispute_details';
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'mandate_details';
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events
+DROP COLUMN delivery_status,
+DROP COLUMN delivery_attempts,
+DROP COLUMN last_delivery_attempted_at;
+
+DROP TYPE "WebhookDeliveryStatus";
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TYPE "WebhookDeliveryStatus" AS ENUM (
+    'pending',
+    'delivered',
+    'retry_scheduled',
+    'failed'
+);
+
+ALTER TABLE events
+ADD COLUMN delivery_status "WebhookDeliveryStatus" NOT NULL DEFAULT 'pending',
+ADD COLUMN delivery_attempts SMALLINT NOT NULL DEFAULT 0,
+ADD COLUMN last_delivery_attempted_at TIMESTAMP;
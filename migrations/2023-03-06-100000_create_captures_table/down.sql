@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE captures;
+
+DROP TYPE "CaptureStatus";
+
+-- Values cannot be removed from an enum type, so `partially_captured` is left in "IntentStatus"
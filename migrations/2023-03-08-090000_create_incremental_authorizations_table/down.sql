@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE incremental_authorizations;
+
+DROP TYPE "AuthorizationStatus";
+
+ALTER TABLE payment_intent DROP COLUMN request_incremental_authorization;
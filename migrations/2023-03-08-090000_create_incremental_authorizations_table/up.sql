@@ -0,0 +1,27 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN request_incremental_authorization BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TYPE "AuthorizationStatus" AS ENUM ('started', 'success', 'failure', 'processing');
+
+CREATE TABLE incremental_authorizations (
+    id SERIAL PRIMARY KEY,
+    authorization_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    status "AuthorizationStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    previously_authorized_amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    connector_authorization_id VARCHAR(128),
+    reason VARCHAR(255),
+    error_message TEXT,
+    error_code VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX incremental_authorizations_merchant_id_authorization_id_index ON incremental_authorizations (merchant_id, authorization_id);
+
+CREATE INDEX incremental_authorizations_merchant_id_payment_id_attempt_id_index ON incremental_authorizations (merchant_id, payment_id, attempt_id);